@@ -91,7 +91,7 @@ yargs(hideBin(process.argv))
 
       let house: House;
       try {
-        house = await House.load(flipAnchorProgram);
+        house = await House.load(flipAnchorProgram, mint.publicKey);
         console.log(
           `${chalk.blue("Info")}: VRF Flip House account (${chalk.yellow(
             house.publicKey.toBase58()
@@ -138,7 +138,7 @@ yargs(hideBin(process.argv))
       });
     },
     async function (argv: any) {
-      const { rpcUrl, cluster, keypair } = argv;
+      const { rpcUrl, cluster, keypair, houseMint } = argv;
 
       const { flipAnchorProgram, switchboardProgram, provider } = await loadCli(
         rpcUrl,
@@ -146,7 +146,9 @@ yargs(hideBin(process.argv))
         keypair
       );
 
-      const flipProgram = await FlipProgram.load(flipAnchorProgram);
+      const flipProgram = await FlipProgram.load(flipAnchorProgram, {
+        mint: loadHouseMint(houseMint),
+      });
 
       // const payerBalance = await checkNativeBalance(
       //   flipProgram.provider.connection,
@@ -222,7 +224,15 @@ yargs(hideBin(process.argv))
         });
     },
     async function (argv: any) {
-      const { rpcUrl, cluster, keypair, gameType, guess, betAmount } = argv;
+      const {
+        rpcUrl,
+        cluster,
+        keypair,
+        gameType,
+        guess,
+        betAmount,
+        houseMint,
+      } = argv;
 
       const userGuess = Math.floor(guess as number);
       let gameTypeEnum: GameTypeValue;
@@ -274,8 +284,9 @@ yargs(hideBin(process.argv))
       //   10000
       // );
 
-      const house = await House.load(flipAnchorProgram);
-      const flipProgram = await FlipProgram.load(flipAnchorProgram);
+      const flipProgram = await FlipProgram.load(flipAnchorProgram, {
+        mint: loadHouseMint(houseMint),
+      });
       const user = await User.load(flipProgram, flipProgram.payerPubkey);
 
       // const flipMint = await house.loadMint();
@@ -362,7 +373,7 @@ yargs(hideBin(process.argv))
       });
     },
     async function (argv: any) {
-      const { rpcUrl, cluster, keypair } = argv;
+      const { rpcUrl, cluster, keypair, houseMint } = argv;
 
       const { flipAnchorProgram, switchboardProgram, provider } = await loadCli(
         rpcUrl,
@@ -370,8 +381,10 @@ yargs(hideBin(process.argv))
         keypair
       );
 
-      const house = await House.load(flipAnchorProgram);
-      const flipProgram = await FlipProgram.load(flipAnchorProgram);
+      const flipProgram = await FlipProgram.load(flipAnchorProgram, {
+        mint: loadHouseMint(houseMint),
+      });
+      const house = flipProgram.house;
       // const flipMint = await house.loadMint();
       // const payerTokenWallet = await spl.getOrCreateAssociatedTokenAccount(
       //   flipProgram.provider.connection,
@@ -426,14 +439,16 @@ yargs(hideBin(process.argv))
       });
     },
     async function (argv: any) {
-      const { rpcUrl, cluster, authority } = argv;
+      const { rpcUrl, cluster, authority, houseMint } = argv;
 
       const { flipAnchorProgram, switchboardProgram, provider } = await loadCli(
         rpcUrl,
         cluster
       );
 
-      const flipProgram = await FlipProgram.load(flipAnchorProgram);
+      const flipProgram = await FlipProgram.load(flipAnchorProgram, {
+        mint: loadHouseMint(houseMint),
+      });
 
       const user = await User.load(flipProgram, new PublicKey(authority));
 
@@ -473,9 +488,21 @@ yargs(hideBin(process.argv))
       alias: "u",
       describe: "Alternative RPC URL",
     },
+    houseMint: {
+      type: "string",
+      alias: "m",
+      describe: "publicKey of the house mint, each mint has its own house",
+    },
   })
   .help().argv;
 
+function loadHouseMint(houseMint?: string): PublicKey {
+  if (!houseMint) {
+    throw new Error(`Must provide houseMint to select the house`);
+  }
+  return new PublicKey(houseMint);
+}
+
 function getRpcUrl(cluster: string): string {
   switch (cluster) {
     case "mainnet-beta":
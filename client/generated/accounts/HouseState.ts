@@ -11,6 +11,14 @@ export interface HouseStateFields {
   houseVault: PublicKey;
   switchboardQueue: PublicKey;
   switchboardMint: PublicKey;
+  feeVault: PublicKey;
+  sponsorVrfFees: number;
+  vrfFee: BN;
+  gameStats: Array<types.GameStatsFields>;
+  betLimits: Array<types.BetLimitsFields>;
+  openLiability: BN;
+  maxLiabilityBps: number;
+  mintSeeded: number;
   ebuf: Array<number>;
 }
 
@@ -21,6 +29,14 @@ export interface HouseStateJSON {
   houseVault: string;
   switchboardQueue: string;
   switchboardMint: string;
+  feeVault: string;
+  sponsorVrfFees: number;
+  vrfFee: string;
+  gameStats: Array<types.GameStatsJSON>;
+  betLimits: Array<types.BetLimitsJSON>;
+  openLiability: string;
+  maxLiabilityBps: number;
+  mintSeeded: number;
   ebuf: Array<number>;
 }
 
@@ -31,6 +47,14 @@ export class HouseState {
   readonly houseVault: PublicKey;
   readonly switchboardQueue: PublicKey;
   readonly switchboardMint: PublicKey;
+  readonly feeVault: PublicKey;
+  readonly sponsorVrfFees: number;
+  readonly vrfFee: BN;
+  readonly gameStats: Array<types.GameStats>;
+  readonly betLimits: Array<types.BetLimits>;
+  readonly openLiability: BN;
+  readonly maxLiabilityBps: number;
+  readonly mintSeeded: number;
   readonly ebuf: Array<number>;
 
   static readonly discriminator = Buffer.from([
@@ -44,7 +68,15 @@ export class HouseState {
     borsh.publicKey("houseVault"),
     borsh.publicKey("switchboardQueue"),
     borsh.publicKey("switchboardMint"),
-    borsh.array(borsh.u8(), 1024, "ebuf"),
+    borsh.publicKey("feeVault"),
+    borsh.u8("sponsorVrfFees"),
+    borsh.u64("vrfFee"),
+    borsh.array(types.GameStats.layout(), 3, "gameStats"),
+    borsh.array(types.BetLimits.layout(), 3, "betLimits"),
+    borsh.u64("openLiability"),
+    borsh.u32("maxLiabilityBps"),
+    borsh.u8("mintSeeded"),
+    borsh.array(borsh.u8(), 766, "ebuf"),
   ]);
 
   constructor(fields: HouseStateFields) {
@@ -54,6 +86,18 @@ export class HouseState {
     this.houseVault = fields.houseVault;
     this.switchboardQueue = fields.switchboardQueue;
     this.switchboardMint = fields.switchboardMint;
+    this.feeVault = fields.feeVault;
+    this.sponsorVrfFees = fields.sponsorVrfFees;
+    this.vrfFee = fields.vrfFee;
+    this.gameStats = fields.gameStats.map(
+      (item) => new types.GameStats({ ...item })
+    );
+    this.betLimits = fields.betLimits.map(
+      (item) => new types.BetLimits({ ...item })
+    );
+    this.openLiability = fields.openLiability;
+    this.maxLiabilityBps = fields.maxLiabilityBps;
+    this.mintSeeded = fields.mintSeeded;
     this.ebuf = fields.ebuf;
   }
 
@@ -105,6 +149,22 @@ export class HouseState {
       houseVault: dec.houseVault,
       switchboardQueue: dec.switchboardQueue,
       switchboardMint: dec.switchboardMint,
+      feeVault: dec.feeVault,
+      sponsorVrfFees: dec.sponsorVrfFees,
+      vrfFee: dec.vrfFee,
+      gameStats: dec.gameStats.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.GameStats.fromDecoded(item)
+      ),
+      betLimits: dec.betLimits.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.BetLimits.fromDecoded(item)
+      ),
+      openLiability: dec.openLiability,
+      maxLiabilityBps: dec.maxLiabilityBps,
+      mintSeeded: dec.mintSeeded,
       ebuf: dec.ebuf,
     });
   }
@@ -117,6 +177,14 @@ export class HouseState {
       houseVault: this.houseVault.toString(),
       switchboardQueue: this.switchboardQueue.toString(),
       switchboardMint: this.switchboardMint.toString(),
+      feeVault: this.feeVault.toString(),
+      sponsorVrfFees: this.sponsorVrfFees,
+      vrfFee: this.vrfFee.toString(),
+      gameStats: this.gameStats.map((item) => item.toJSON()),
+      betLimits: this.betLimits.map((item) => item.toJSON()),
+      openLiability: this.openLiability.toString(),
+      maxLiabilityBps: this.maxLiabilityBps,
+      mintSeeded: this.mintSeeded,
       ebuf: this.ebuf,
     };
   }
@@ -129,6 +197,14 @@ export class HouseState {
       houseVault: new PublicKey(obj.houseVault),
       switchboardQueue: new PublicKey(obj.switchboardQueue),
       switchboardMint: new PublicKey(obj.switchboardMint),
+      feeVault: new PublicKey(obj.feeVault),
+      sponsorVrfFees: obj.sponsorVrfFees,
+      vrfFee: new BN(obj.vrfFee),
+      gameStats: obj.gameStats.map((item) => types.GameStats.fromJSON(item)),
+      betLimits: obj.betLimits.map((item) => types.BetLimits.fromJSON(item)),
+      openLiability: new BN(obj.openLiability),
+      maxLiabilityBps: obj.maxLiabilityBps,
+      mintSeeded: obj.mintSeeded,
       ebuf: obj.ebuf,
     });
   }
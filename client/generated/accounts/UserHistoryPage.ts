@@ -0,0 +1,131 @@
+import { FlipProgram } from "../../program";
+import { PublicKey, Connection } from "@solana/web3.js";
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserHistoryPageFields {
+  bump: number;
+  user: PublicKey;
+  pageIndex: number;
+  len: number;
+  rounds: Array<types.RoundFields>;
+  payouts: Array<BN>;
+}
+
+export interface UserHistoryPageJSON {
+  bump: number;
+  user: string;
+  pageIndex: number;
+  len: number;
+  rounds: Array<types.RoundJSON>;
+  payouts: Array<string>;
+}
+
+export class UserHistoryPage {
+  readonly bump: number;
+  readonly user: PublicKey;
+  readonly pageIndex: number;
+  readonly len: number;
+  readonly rounds: Array<types.Round>;
+  readonly payouts: Array<BN>;
+
+  static readonly discriminator = Buffer.from([
+    101, 15, 65, 103, 198, 255, 126, 125,
+  ]);
+
+  static readonly layout = borsh.struct([
+    borsh.u8("bump"),
+    borsh.publicKey("user"),
+    borsh.u32("pageIndex"),
+    borsh.u32("len"),
+    borsh.array(types.Round.layout(), 64, "rounds"),
+    borsh.array(borsh.u64(), 64, "payouts"),
+  ]);
+
+  constructor(fields: UserHistoryPageFields) {
+    this.bump = fields.bump;
+    this.user = fields.user;
+    this.pageIndex = fields.pageIndex;
+    this.len = fields.len;
+    this.rounds = fields.rounds.map((item) => new types.Round({ ...item }));
+    this.payouts = fields.payouts;
+  }
+
+  static async fetch(
+    program: { connection: Connection; programId: PublicKey },
+    address: PublicKey
+  ): Promise<UserHistoryPage | null> {
+    const info = await program.connection.getAccountInfo(address);
+
+    if (info === null) {
+      return null;
+    }
+    if (!info.owner.equals(program.programId)) {
+      throw new Error("account doesn't belong to this program");
+    }
+
+    return this.decode(info.data);
+  }
+
+  static async fetchMultiple(
+    program: { connection: Connection; programId: PublicKey },
+    addresses: PublicKey[]
+  ): Promise<Array<UserHistoryPage | null>> {
+    const infos = await program.connection.getMultipleAccountsInfo(addresses);
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null;
+      }
+      if (!info.owner.equals(program.programId)) {
+        throw new Error("account doesn't belong to this program");
+      }
+
+      return this.decode(info.data);
+    });
+  }
+
+  static decode(data: Buffer): UserHistoryPage {
+    if (!data.slice(0, 8).equals(UserHistoryPage.discriminator)) {
+      throw new Error("invalid account discriminator");
+    }
+
+    const dec = UserHistoryPage.layout.decode(data.slice(8));
+
+    return new UserHistoryPage({
+      bump: dec.bump,
+      user: dec.user,
+      pageIndex: dec.pageIndex,
+      len: dec.len,
+      rounds: dec.rounds.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.Round.fromDecoded(item)
+      ),
+      payouts: dec.payouts,
+    });
+  }
+
+  toJSON(): UserHistoryPageJSON {
+    return {
+      bump: this.bump,
+      user: this.user.toString(),
+      pageIndex: this.pageIndex,
+      len: this.len,
+      rounds: this.rounds.map((item) => item.toJSON()),
+      payouts: this.payouts.map((item) => item.toString()),
+    };
+  }
+
+  static fromJSON(obj: UserHistoryPageJSON): UserHistoryPage {
+    return new UserHistoryPage({
+      bump: obj.bump,
+      user: new PublicKey(obj.user),
+      pageIndex: obj.pageIndex,
+      len: obj.len,
+      rounds: obj.rounds.map((item) => types.Round.fromJSON(item)),
+      payouts: obj.payouts.map((item) => new BN(item)),
+    });
+  }
+}
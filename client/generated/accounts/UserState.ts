@@ -15,6 +15,16 @@ export interface UserStateFields {
   vrfPermissionBump: number;
   currentRound: types.RoundFields;
   lastAirdropRequestSlot: BN;
+  commitReveal: types.CommitRevealFields;
+  commitRevealRounds: BN;
+  stats: types.UserStatsFields;
+  archivedRounds: BN;
+  version: number;
+  delegate: types.UserDelegateFields;
+  autoplay: types.AutoPlayFields;
+  limits: types.UserLimitsFields;
+  historyPages: number;
+  houseSettlePending: number;
   ebuf: Array<number>;
   history: types.HistoryFields;
 }
@@ -30,6 +40,16 @@ export interface UserStateJSON {
   vrfPermissionBump: number;
   currentRound: types.RoundJSON;
   lastAirdropRequestSlot: string;
+  commitReveal: types.CommitRevealJSON;
+  commitRevealRounds: string;
+  stats: types.UserStatsJSON;
+  archivedRounds: string;
+  version: number;
+  delegate: types.UserDelegateJSON;
+  autoplay: types.AutoPlayJSON;
+  limits: types.UserLimitsJSON;
+  historyPages: number;
+  houseSettlePending: number;
   ebuf: Array<number>;
   history: types.HistoryJSON;
 }
@@ -45,6 +65,16 @@ export class UserState {
   readonly vrfPermissionBump: number;
   readonly currentRound: types.Round;
   readonly lastAirdropRequestSlot: BN;
+  readonly commitReveal: types.CommitReveal;
+  readonly commitRevealRounds: BN;
+  readonly stats: types.UserStats;
+  readonly archivedRounds: BN;
+  readonly version: number;
+  readonly delegate: types.UserDelegate;
+  readonly autoplay: types.AutoPlay;
+  readonly limits: types.UserLimits;
+  readonly historyPages: number;
+  readonly houseSettlePending: number;
   readonly ebuf: Array<number>;
   readonly history: types.History;
 
@@ -63,7 +93,17 @@ export class UserState {
     borsh.u8("vrfPermissionBump"),
     types.Round.layout("currentRound"),
     borsh.u64("lastAirdropRequestSlot"),
-    borsh.array(borsh.u8(), 1024, "ebuf"),
+    types.CommitReveal.layout("commitReveal"),
+    borsh.u64("commitRevealRounds"),
+    types.UserStats.layout("stats"),
+    borsh.u64("archivedRounds"),
+    borsh.u8("version"),
+    types.UserDelegate.layout("delegate"),
+    types.AutoPlay.layout("autoplay"),
+    types.UserLimits.layout("limits"),
+    borsh.u32("historyPages"),
+    borsh.u8("houseSettlePending"),
+    borsh.array(borsh.u8(), 661, "ebuf"),
     types.History.layout("history"),
   ]);
 
@@ -78,6 +118,16 @@ export class UserState {
     this.vrfPermissionBump = fields.vrfPermissionBump;
     this.currentRound = new types.Round({ ...fields.currentRound });
     this.lastAirdropRequestSlot = fields.lastAirdropRequestSlot;
+    this.commitReveal = new types.CommitReveal({ ...fields.commitReveal });
+    this.commitRevealRounds = fields.commitRevealRounds;
+    this.stats = new types.UserStats({ ...fields.stats });
+    this.archivedRounds = fields.archivedRounds;
+    this.version = fields.version;
+    this.delegate = new types.UserDelegate({ ...fields.delegate });
+    this.autoplay = new types.AutoPlay({ ...fields.autoplay });
+    this.limits = new types.UserLimits({ ...fields.limits });
+    this.historyPages = fields.historyPages;
+    this.houseSettlePending = fields.houseSettlePending;
     this.ebuf = fields.ebuf;
     this.history = new types.History({ ...fields.history });
   }
@@ -134,6 +184,16 @@ export class UserState {
       vrfPermissionBump: dec.vrfPermissionBump,
       currentRound: types.Round.fromDecoded(dec.currentRound),
       lastAirdropRequestSlot: dec.lastAirdropRequestSlot,
+      commitReveal: types.CommitReveal.fromDecoded(dec.commitReveal),
+      commitRevealRounds: dec.commitRevealRounds,
+      stats: types.UserStats.fromDecoded(dec.stats),
+      archivedRounds: dec.archivedRounds,
+      version: dec.version,
+      delegate: types.UserDelegate.fromDecoded(dec.delegate),
+      autoplay: types.AutoPlay.fromDecoded(dec.autoplay),
+      limits: types.UserLimits.fromDecoded(dec.limits),
+      historyPages: dec.historyPages,
+      houseSettlePending: dec.houseSettlePending,
       ebuf: dec.ebuf,
       history: types.History.fromDecoded(dec.history),
     });
@@ -151,6 +211,16 @@ export class UserState {
       vrfPermissionBump: this.vrfPermissionBump,
       currentRound: this.currentRound.toJSON(),
       lastAirdropRequestSlot: this.lastAirdropRequestSlot.toString(),
+      commitReveal: this.commitReveal.toJSON(),
+      commitRevealRounds: this.commitRevealRounds.toString(),
+      stats: this.stats.toJSON(),
+      archivedRounds: this.archivedRounds.toString(),
+      version: this.version,
+      delegate: this.delegate.toJSON(),
+      autoplay: this.autoplay.toJSON(),
+      limits: this.limits.toJSON(),
+      historyPages: this.historyPages,
+      houseSettlePending: this.houseSettlePending,
       ebuf: this.ebuf,
       history: this.history.toJSON(),
     };
@@ -168,6 +238,16 @@ export class UserState {
       vrfPermissionBump: obj.vrfPermissionBump,
       currentRound: types.Round.fromJSON(obj.currentRound),
       lastAirdropRequestSlot: new BN(obj.lastAirdropRequestSlot),
+      commitReveal: types.CommitReveal.fromJSON(obj.commitReveal),
+      commitRevealRounds: new BN(obj.commitRevealRounds),
+      stats: types.UserStats.fromJSON(obj.stats),
+      archivedRounds: new BN(obj.archivedRounds),
+      version: obj.version,
+      delegate: types.UserDelegate.fromJSON(obj.delegate),
+      autoplay: types.AutoPlay.fromJSON(obj.autoplay),
+      limits: types.UserLimits.fromJSON(obj.limits),
+      historyPages: obj.historyPages,
+      houseSettlePending: obj.houseSettlePending,
       ebuf: obj.ebuf,
       history: types.History.fromJSON(obj.history),
     });
@@ -2,3 +2,8 @@ export { HouseState } from "./HouseState";
 export type { HouseStateFields, HouseStateJSON } from "./HouseState";
 export { UserState } from "./UserState";
 export type { UserStateFields, UserStateJSON } from "./UserState";
+export { UserHistoryPage } from "./UserHistoryPage";
+export type {
+  UserHistoryPageFields,
+  UserHistoryPageJSON,
+} from "./UserHistoryPage";
@@ -0,0 +1,48 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface HouseCommitArgs {
+  params: types.HouseCommitParamsFields;
+}
+
+export interface HouseCommitAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+}
+
+export const layout = borsh.struct([types.HouseCommitParams.layout("params")]);
+
+export function houseCommit(
+  program: { programId: PublicKey },
+  args: HouseCommitArgs,
+  accounts: HouseCommitAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+  ];
+  const identifier = Buffer.from([129, 7, 5, 118, 241, 220, 251, 128]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.HouseCommitParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
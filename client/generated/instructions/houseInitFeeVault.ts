@@ -0,0 +1,60 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface HouseInitFeeVaultArgs {
+  params: types.HouseInitFeeVaultParamsFields;
+}
+
+export interface HouseInitFeeVaultAccounts {
+  house: PublicKey;
+  authority: PublicKey;
+  switchboardMint: PublicKey;
+  feeVault: PublicKey;
+  payer: PublicKey;
+  systemProgram: PublicKey;
+  tokenProgram: PublicKey;
+  rent: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.HouseInitFeeVaultParams.layout("params"),
+]);
+
+export function houseInitFeeVault(
+  program: { programId: PublicKey },
+  args: HouseInitFeeVaultArgs,
+  accounts: HouseInitFeeVaultAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.switchboardMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([73, 75, 55, 42, 136, 240, 186, 89]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.HouseInitFeeVaultParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
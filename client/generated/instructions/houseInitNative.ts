@@ -0,0 +1,70 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface HouseInitNativeArgs {
+  params: types.HouseInitNativeParamsFields;
+}
+
+export interface HouseInitNativeAccounts {
+  house: PublicKey;
+  authority: PublicKey;
+  switchboardMint: PublicKey;
+  switchboardQueue: PublicKey;
+  mint: PublicKey;
+  houseVault: PublicKey;
+  payer: PublicKey;
+  systemProgram: PublicKey;
+  tokenProgram: PublicKey;
+  associatedTokenProgram: PublicKey;
+  rent: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.HouseInitNativeParams.layout("params"),
+]);
+
+export function houseInitNative(
+  program: { programId: PublicKey },
+  args: HouseInitNativeArgs,
+  accounts: HouseInitNativeAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.switchboardMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.switchboardQueue, isSigner: false, isWritable: true },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.houseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.rent, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([146, 179, 163, 150, 117, 106, 181, 246]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.HouseInitNativeParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,50 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface HouseRevealArgs {
+  params: types.HouseRevealParamsFields;
+}
+
+export interface HouseRevealAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+  slotHashes: PublicKey;
+}
+
+export const layout = borsh.struct([types.HouseRevealParams.layout("params")]);
+
+export function houseReveal(
+  program: { programId: PublicKey },
+  args: HouseRevealArgs,
+  accounts: HouseRevealAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.slotHashes, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([245, 230, 49, 144, 97, 137, 101, 142]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.HouseRevealParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
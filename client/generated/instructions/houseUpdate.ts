@@ -0,0 +1,46 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface HouseUpdateArgs {
+  params: types.HouseUpdateParamsFields;
+}
+
+export interface HouseUpdateAccounts {
+  house: PublicKey;
+  authority: PublicKey;
+}
+
+export const layout = borsh.struct([types.HouseUpdateParams.layout("params")]);
+
+export function houseUpdate(
+  program: { programId: PublicKey },
+  args: HouseUpdateArgs,
+  accounts: HouseUpdateAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+  ];
+  const identifier = Buffer.from([173, 189, 248, 230, 126, 55, 192, 79]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.HouseUpdateParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
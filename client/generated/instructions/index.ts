@@ -1,10 +1,85 @@
 export { houseInit } from "./houseInit";
 export type { HouseInitArgs, HouseInitAccounts } from "./houseInit";
+export { houseInitNative } from "./houseInitNative";
+export { houseUpdate } from "./houseUpdate";
+export type { HouseUpdateArgs, HouseUpdateAccounts } from "./houseUpdate";
+export { houseInitFeeVault } from "./houseInitFeeVault";
+export { houseCommit } from "./houseCommit";
+export type { HouseCommitArgs, HouseCommitAccounts } from "./houseCommit";
+export { houseReveal } from "./houseReveal";
+export type { HouseRevealArgs, HouseRevealAccounts } from "./houseReveal";
+export type {
+  HouseInitFeeVaultArgs,
+  HouseInitFeeVaultAccounts,
+} from "./houseInitFeeVault";
+export type {
+  HouseInitNativeArgs,
+  HouseInitNativeAccounts,
+} from "./houseInitNative";
 export { userInit } from "./userInit";
 export type { UserInitArgs, UserInitAccounts } from "./userInit";
 export { userBet } from "./userBet";
 export type { UserBetArgs, UserBetAccounts } from "./userBet";
+export { userBetCommitReveal } from "./userBetCommitReveal";
+export type {
+  UserBetCommitRevealArgs,
+  UserBetCommitRevealAccounts,
+} from "./userBetCommitReveal";
+export { userBetDelegate } from "./userBetDelegate";
+export type {
+  UserBetDelegateArgs,
+  UserBetDelegateAccounts,
+} from "./userBetDelegate";
 export { userSettle } from "./userSettle";
 export type { UserSettleArgs, UserSettleAccounts } from "./userSettle";
+export { userRecordSettle } from "./userRecordSettle";
+export type {
+  UserRecordSettleArgs,
+  UserRecordSettleAccounts,
+} from "./userRecordSettle";
 export { userAirdrop } from "./userAirdrop";
 export type { UserAirdropArgs, UserAirdropAccounts } from "./userAirdrop";
+export { userHistoryPageInit } from "./userHistoryPageInit";
+export type {
+  UserHistoryPageInitArgs,
+  UserHistoryPageInitAccounts,
+} from "./userHistoryPageInit";
+export { userHistoryPageClose } from "./userHistoryPageClose";
+export type {
+  UserHistoryPageCloseArgs,
+  UserHistoryPageCloseAccounts,
+} from "./userHistoryPageClose";
+export { userMigrate } from "./userMigrate";
+export type { UserMigrateArgs, UserMigrateAccounts } from "./userMigrate";
+export { userClose } from "./userClose";
+export type { UserCloseArgs, UserCloseAccounts } from "./userClose";
+export { userWithdrawEscrow } from "./userWithdrawEscrow";
+export type {
+  UserWithdrawEscrowArgs,
+  UserWithdrawEscrowAccounts,
+} from "./userWithdrawEscrow";
+export { userSetDelegate } from "./userSetDelegate";
+export type {
+  UserSetDelegateArgs,
+  UserSetDelegateAccounts,
+} from "./userSetDelegate";
+export { userAutoplayStart } from "./userAutoplayStart";
+export type {
+  UserAutoplayStartArgs,
+  UserAutoplayStartAccounts,
+} from "./userAutoplayStart";
+export { userAutoplayBet } from "./userAutoplayBet";
+export type {
+  UserAutoplayBetArgs,
+  UserAutoplayBetAccounts,
+} from "./userAutoplayBet";
+export { userAutoplayStop } from "./userAutoplayStop";
+export type {
+  UserAutoplayStopArgs,
+  UserAutoplayStopAccounts,
+} from "./userAutoplayStop";
+export { userSetLimits } from "./userSetLimits";
+export type {
+  UserSetLimitsArgs,
+  UserSetLimitsAccounts,
+} from "./userSetLimits";
@@ -0,0 +1,64 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserAutoplayStartArgs {
+  params: types.UserAutoplayStartParamsFields;
+}
+
+export interface UserAutoplayStartAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  houseVault: PublicKey;
+  authority: PublicKey;
+  escrow: PublicKey;
+  flipPayer: PublicKey;
+  tokenProgram: PublicKey;
+  unwrapAccount: PublicKey;
+  nativeMint: PublicKey;
+  systemProgram: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserAutoplayStartParams.layout("params"),
+]);
+
+export function userAutoplayStart(
+  program: { programId: PublicKey },
+  args: UserAutoplayStartArgs,
+  accounts: UserAutoplayStartAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.houseVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.escrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.flipPayer, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.unwrapAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.nativeMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([22, 140, 121, 110, 160, 151, 98, 124]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserAutoplayStartParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
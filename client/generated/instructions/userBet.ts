@@ -32,6 +32,9 @@ export interface UserBetAccounts {
   recentBlockhashes: PublicKey;
   systemProgram: PublicKey;
   tokenProgram: PublicKey;
+  historyPage: PublicKey;
+  unwrapAccount: PublicKey;
+  nativeMint: PublicKey;
 }
 
 export const layout = borsh.struct([types.UserBetParams.layout("params")]);
@@ -43,8 +46,8 @@ export function userBet(
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.user, isSigner: false, isWritable: true },
-    { pubkey: accounts.house, isSigner: false, isWritable: false },
-    { pubkey: accounts.houseVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.houseVault, isSigner: false, isWritable: true },
     { pubkey: accounts.authority, isSigner: true, isWritable: true },
     { pubkey: accounts.escrow, isSigner: false, isWritable: true },
     { pubkey: accounts.vrf, isSigner: false, isWritable: true },
@@ -65,6 +68,9 @@ export function userBet(
     { pubkey: accounts.recentBlockhashes, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.unwrapAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.nativeMint, isSigner: false, isWritable: false },
   ];
   const identifier = Buffer.from([250, 141, 121, 127, 113, 52, 188, 61]);
   const buffer = Buffer.alloc(1000);
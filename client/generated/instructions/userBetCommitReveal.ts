@@ -0,0 +1,58 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserBetCommitRevealArgs {
+  params: types.UserBetParamsFields;
+}
+
+export interface UserBetCommitRevealAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  houseVault: PublicKey;
+  authority: PublicKey;
+  escrow: PublicKey;
+  flipPayer: PublicKey;
+  tokenProgram: PublicKey;
+  historyPage: PublicKey;
+}
+
+export const layout = borsh.struct([types.UserBetParams.layout("params")]);
+
+export function userBetCommitReveal(
+  program: { programId: PublicKey },
+  args: UserBetCommitRevealArgs,
+  accounts: UserBetCommitRevealAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.houseVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.escrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.flipPayer, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+  ];
+  const identifier = Buffer.from([10, 30, 162, 212, 98, 155, 70, 153]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserBetParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
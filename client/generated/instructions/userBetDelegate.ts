@@ -0,0 +1,84 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserBetDelegateArgs {
+  params: types.UserBetParamsFields;
+}
+
+export interface UserBetDelegateAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  houseVault: PublicKey;
+  authority: PublicKey;
+  escrow: PublicKey;
+  rewardAddress: PublicKey;
+  vrf: PublicKey;
+  oracleQueue: PublicKey;
+  queueAuthority: PublicKey;
+  dataBuffer: PublicKey;
+  permission: PublicKey;
+  vrfEscrow: PublicKey;
+  switchboardProgramState: PublicKey;
+  switchboardProgram: PublicKey;
+  delegate: PublicKey;
+  vrfPayer: PublicKey;
+  recentBlockhashes: PublicKey;
+  tokenProgram: PublicKey;
+  historyPage: PublicKey;
+}
+
+export const layout = borsh.struct([types.UserBetParams.layout("params")]);
+
+export function userBetDelegate(
+  program: { programId: PublicKey },
+  args: UserBetDelegateArgs,
+  accounts: UserBetDelegateAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.houseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: false, isWritable: false },
+    { pubkey: accounts.escrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.rewardAddress, isSigner: false, isWritable: true },
+    { pubkey: accounts.vrf, isSigner: false, isWritable: true },
+    { pubkey: accounts.oracleQueue, isSigner: false, isWritable: true },
+    { pubkey: accounts.queueAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.dataBuffer, isSigner: false, isWritable: true },
+    { pubkey: accounts.permission, isSigner: false, isWritable: true },
+    { pubkey: accounts.vrfEscrow, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.switchboardProgramState,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.switchboardProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.delegate, isSigner: true, isWritable: true },
+    { pubkey: accounts.vrfPayer, isSigner: false, isWritable: true },
+    { pubkey: accounts.recentBlockhashes, isSigner: false, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+  ];
+  const identifier = Buffer.from([71, 230, 242, 98, 77, 19, 114, 142]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserBetParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,56 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserCloseArgs {
+  params: types.UserCloseParamsFields;
+}
+
+export interface UserCloseAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+  escrow: PublicKey;
+  tokenProgram: PublicKey;
+  historyPage: PublicKey;
+  unwrapAccount: PublicKey;
+}
+
+export const layout = borsh.struct([types.UserCloseParams.layout("params")]);
+
+export function userClose(
+  program: { programId: PublicKey },
+  args: UserCloseArgs,
+  accounts: UserCloseAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.escrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.unwrapAccount, isSigner: false, isWritable: true },
+  ];
+  const identifier = Buffer.from([126, 78, 180, 205, 96, 242, 20, 2]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserCloseParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,50 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserHistoryPageCloseArgs {
+  params: types.UserHistoryPageCloseParamsFields;
+}
+
+export interface UserHistoryPageCloseAccounts {
+  historyPage: PublicKey;
+  user: PublicKey;
+  authority: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserHistoryPageCloseParams.layout("params"),
+]);
+
+export function userHistoryPageClose(
+  program: { programId: PublicKey },
+  args: UserHistoryPageCloseArgs,
+  accounts: UserHistoryPageCloseAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.user, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+  ];
+  const identifier = Buffer.from([139, 61, 19, 116, 118, 58, 4, 18]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserHistoryPageCloseParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
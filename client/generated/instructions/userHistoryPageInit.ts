@@ -0,0 +1,54 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserHistoryPageInitArgs {
+  params: types.UserHistoryPageInitParamsFields;
+}
+
+export interface UserHistoryPageInitAccounts {
+  historyPage: PublicKey;
+  user: PublicKey;
+  authority: PublicKey;
+  payer: PublicKey;
+  systemProgram: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserHistoryPageInitParams.layout("params"),
+]);
+
+export function userHistoryPageInit(
+  program: { programId: PublicKey },
+  args: UserHistoryPageInitArgs,
+  accounts: UserHistoryPageInitAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([131, 247, 83, 119, 89, 205, 249, 213]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserHistoryPageInitParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
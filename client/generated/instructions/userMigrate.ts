@@ -0,0 +1,52 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserMigrateArgs {
+  params: types.UserMigrateParamsFields;
+}
+
+export interface UserMigrateAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+  payer: PublicKey;
+  systemProgram: PublicKey;
+}
+
+export const layout = borsh.struct([types.UserMigrateParams.layout("params")]);
+
+export function userMigrate(
+  program: { programId: PublicKey },
+  args: UserMigrateArgs,
+  accounts: UserMigrateAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([28, 226, 232, 233, 124, 189, 104, 94]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserMigrateParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,48 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserRecordSettleArgs {
+  params: types.UserRecordSettleParamsFields;
+}
+
+export interface UserRecordSettleAccounts {
+  user: PublicKey;
+  house: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserRecordSettleParams.layout("params"),
+]);
+
+export function userRecordSettle(
+  program: { programId: PublicKey },
+  args: UserRecordSettleArgs,
+  accounts: UserRecordSettleAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
+  ];
+  const identifier = Buffer.from([61, 163, 46, 150, 177, 243, 57, 22]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserRecordSettleParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,54 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserSetDelegateArgs {
+  params: types.UserSetDelegateParamsFields;
+}
+
+export interface UserSetDelegateAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+  rewardAddress: PublicKey;
+  tokenProgram: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserSetDelegateParams.layout("params"),
+]);
+
+export function userSetDelegate(
+  program: { programId: PublicKey },
+  args: UserSetDelegateArgs,
+  accounts: UserSetDelegateAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.rewardAddress, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([149, 81, 44, 252, 158, 14, 68, 174]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserSetDelegateParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,50 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserSetLimitsArgs {
+  params: types.UserSetLimitsParamsFields;
+}
+
+export interface UserSetLimitsAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserSetLimitsParams.layout("params"),
+]);
+
+export function userSetLimits(
+  program: { programId: PublicKey },
+  args: UserSetLimitsArgs,
+  accounts: UserSetLimitsAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: true },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+  ];
+  const identifier = Buffer.from([103, 169, 79, 215, 218, 133, 136, 115]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserSetLimitsParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
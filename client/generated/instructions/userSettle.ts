@@ -20,6 +20,9 @@ export interface UserSettleAccounts {
   houseVault: PublicKey;
   vrf: PublicKey;
   tokenProgram: PublicKey;
+  historyPage: PublicKey;
+  unwrapAccount: PublicKey;
+  authority: PublicKey;
 }
 
 export const layout = borsh.struct([types.UserSettleParams.layout("params")]);
@@ -31,12 +34,15 @@ export function userSettle(
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.user, isSigner: false, isWritable: true },
-    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.house, isSigner: false, isWritable: true },
     { pubkey: accounts.escrow, isSigner: false, isWritable: true },
     { pubkey: accounts.rewardAddress, isSigner: false, isWritable: true },
     { pubkey: accounts.houseVault, isSigner: false, isWritable: true },
     { pubkey: accounts.vrf, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.historyPage, isSigner: false, isWritable: true },
+    { pubkey: accounts.unwrapAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: false, isWritable: true },
   ];
   const identifier = Buffer.from([184, 56, 135, 64, 228, 26, 152, 183]);
   const buffer = Buffer.alloc(1000);
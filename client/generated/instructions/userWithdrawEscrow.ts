@@ -0,0 +1,62 @@
+import { FlipProgram } from "../../program";
+import {
+  TransactionInstruction,
+  PublicKey,
+  AccountMeta,
+} from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+
+export interface UserWithdrawEscrowArgs {
+  params: types.UserWithdrawEscrowParamsFields;
+}
+
+export interface UserWithdrawEscrowAccounts {
+  user: PublicKey;
+  house: PublicKey;
+  authority: PublicKey;
+  escrow: PublicKey;
+  rewardAddress: PublicKey;
+  tokenProgram: PublicKey;
+  unwrapAccount: PublicKey;
+  nativeMint: PublicKey;
+  systemProgram: PublicKey;
+}
+
+export const layout = borsh.struct([
+  types.UserWithdrawEscrowParams.layout("params"),
+]);
+
+export function userWithdrawEscrow(
+  program: { programId: PublicKey },
+  args: UserWithdrawEscrowArgs,
+  accounts: UserWithdrawEscrowAccounts
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.user, isSigner: false, isWritable: false },
+    { pubkey: accounts.house, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.escrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.rewardAddress, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.unwrapAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.nativeMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ];
+  const identifier = Buffer.from([138, 15, 17, 74, 132, 174, 181, 174]);
+  const buffer = Buffer.alloc(1000);
+  const len = layout.encode(
+    {
+      params: types.UserWithdrawEscrowParams.toEncodable(args.params),
+    },
+    buffer
+  );
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len);
+  const ix = new TransactionInstruction({
+    keys,
+    programId: program.programId,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,131 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface AutoPlayFields {
+  active: number;
+  gameType: number;
+  guess: number;
+  betAmount: BN;
+  remainingBets: number;
+  stopLoss: BN;
+  takeProfit: BN;
+  netPnl: BN;
+  vrfFee: BN;
+}
+
+export interface AutoPlayJSON {
+  active: number;
+  gameType: number;
+  guess: number;
+  betAmount: string;
+  remainingBets: number;
+  stopLoss: string;
+  takeProfit: string;
+  netPnl: string;
+  vrfFee: string;
+}
+
+export class AutoPlay {
+  readonly active: number;
+  readonly gameType: number;
+  readonly guess: number;
+  readonly betAmount: BN;
+  readonly remainingBets: number;
+  readonly stopLoss: BN;
+  readonly takeProfit: BN;
+  readonly netPnl: BN;
+  readonly vrfFee: BN;
+
+  constructor(fields: AutoPlayFields) {
+    this.active = fields.active;
+    this.gameType = fields.gameType;
+    this.guess = fields.guess;
+    this.betAmount = fields.betAmount;
+    this.remainingBets = fields.remainingBets;
+    this.stopLoss = fields.stopLoss;
+    this.takeProfit = fields.takeProfit;
+    this.netPnl = fields.netPnl;
+    this.vrfFee = fields.vrfFee;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u8("active"),
+        borsh.u32("gameType"),
+        borsh.u32("guess"),
+        borsh.u64("betAmount"),
+        borsh.u32("remainingBets"),
+        borsh.u64("stopLoss"),
+        borsh.u64("takeProfit"),
+        borsh.i64("netPnl"),
+        borsh.u64("vrfFee"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AutoPlay({
+      active: obj.active,
+      gameType: obj.gameType,
+      guess: obj.guess,
+      betAmount: obj.betAmount,
+      remainingBets: obj.remainingBets,
+      stopLoss: obj.stopLoss,
+      takeProfit: obj.takeProfit,
+      netPnl: obj.netPnl,
+      vrfFee: obj.vrfFee,
+    });
+  }
+
+  static toEncodable(fields: AutoPlayFields) {
+    return {
+      active: fields.active,
+      gameType: fields.gameType,
+      guess: fields.guess,
+      betAmount: fields.betAmount,
+      remainingBets: fields.remainingBets,
+      stopLoss: fields.stopLoss,
+      takeProfit: fields.takeProfit,
+      netPnl: fields.netPnl,
+      vrfFee: fields.vrfFee,
+    };
+  }
+
+  toJSON(): AutoPlayJSON {
+    return {
+      active: this.active,
+      gameType: this.gameType,
+      guess: this.guess,
+      betAmount: this.betAmount.toString(),
+      remainingBets: this.remainingBets,
+      stopLoss: this.stopLoss.toString(),
+      takeProfit: this.takeProfit.toString(),
+      netPnl: this.netPnl.toString(),
+      vrfFee: this.vrfFee.toString(),
+    };
+  }
+
+  static fromJSON(obj: AutoPlayJSON): AutoPlay {
+    return new AutoPlay({
+      active: obj.active,
+      gameType: obj.gameType,
+      guess: obj.guess,
+      betAmount: new BN(obj.betAmount),
+      remainingBets: obj.remainingBets,
+      stopLoss: new BN(obj.stopLoss),
+      takeProfit: new BN(obj.takeProfit),
+      netPnl: new BN(obj.netPnl),
+      vrfFee: new BN(obj.vrfFee),
+    });
+  }
+
+  toEncodable() {
+    return AutoPlay.toEncodable(this);
+  }
+}
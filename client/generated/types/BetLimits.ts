@@ -0,0 +1,77 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface BetLimitsFields {
+  minBet: BN;
+  maxBet: BN;
+  maxPayoutBps: number;
+}
+
+export interface BetLimitsJSON {
+  minBet: string;
+  maxBet: string;
+  maxPayoutBps: number;
+}
+
+export class BetLimits {
+  readonly minBet: BN;
+  readonly maxBet: BN;
+  readonly maxPayoutBps: number;
+
+  constructor(fields: BetLimitsFields) {
+    this.minBet = fields.minBet;
+    this.maxBet = fields.maxBet;
+    this.maxPayoutBps = fields.maxPayoutBps;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("minBet"),
+        borsh.u64("maxBet"),
+        borsh.u32("maxPayoutBps"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new BetLimits({
+      minBet: obj.minBet,
+      maxBet: obj.maxBet,
+      maxPayoutBps: obj.maxPayoutBps,
+    });
+  }
+
+  static toEncodable(fields: BetLimitsFields) {
+    return {
+      minBet: fields.minBet,
+      maxBet: fields.maxBet,
+      maxPayoutBps: fields.maxPayoutBps,
+    };
+  }
+
+  toJSON(): BetLimitsJSON {
+    return {
+      minBet: this.minBet.toString(),
+      maxBet: this.maxBet.toString(),
+      maxPayoutBps: this.maxPayoutBps,
+    };
+  }
+
+  static fromJSON(obj: BetLimitsJSON): BetLimits {
+    return new BetLimits({
+      minBet: new BN(obj.minBet),
+      maxBet: new BN(obj.maxBet),
+      maxPayoutBps: obj.maxPayoutBps,
+    });
+  }
+
+  toEncodable() {
+    return BetLimits.toEncodable(this);
+  }
+}
@@ -0,0 +1,77 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface CommitRevealFields {
+  roundId: BN;
+  commitment: Array<number>;
+  result: Array<number>;
+}
+
+export interface CommitRevealJSON {
+  roundId: string;
+  commitment: Array<number>;
+  result: Array<number>;
+}
+
+export class CommitReveal {
+  readonly roundId: BN;
+  readonly commitment: Array<number>;
+  readonly result: Array<number>;
+
+  constructor(fields: CommitRevealFields) {
+    this.roundId = fields.roundId;
+    this.commitment = fields.commitment;
+    this.result = fields.result;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u128("roundId"),
+        borsh.array(borsh.u8(), 32, "commitment"),
+        borsh.array(borsh.u8(), 32, "result"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CommitReveal({
+      roundId: obj.roundId,
+      commitment: obj.commitment,
+      result: obj.result,
+    });
+  }
+
+  static toEncodable(fields: CommitRevealFields) {
+    return {
+      roundId: fields.roundId,
+      commitment: fields.commitment,
+      result: fields.result,
+    };
+  }
+
+  toJSON(): CommitRevealJSON {
+    return {
+      roundId: this.roundId.toString(),
+      commitment: this.commitment,
+      result: this.result,
+    };
+  }
+
+  static fromJSON(obj: CommitRevealJSON): CommitReveal {
+    return new CommitReveal({
+      roundId: new BN(obj.roundId),
+      commitment: obj.commitment,
+      result: obj.result,
+    });
+  }
+
+  toEncodable() {
+    return CommitReveal.toEncodable(this);
+  }
+}
@@ -0,0 +1,86 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface GameBetLimitsParamsFields {
+  gameType: number;
+  minBet: BN;
+  maxBet: BN;
+  maxPayoutBps: number;
+}
+
+export interface GameBetLimitsParamsJSON {
+  gameType: number;
+  minBet: string;
+  maxBet: string;
+  maxPayoutBps: number;
+}
+
+export class GameBetLimitsParams {
+  readonly gameType: number;
+  readonly minBet: BN;
+  readonly maxBet: BN;
+  readonly maxPayoutBps: number;
+
+  constructor(fields: GameBetLimitsParamsFields) {
+    this.gameType = fields.gameType;
+    this.minBet = fields.minBet;
+    this.maxBet = fields.maxBet;
+    this.maxPayoutBps = fields.maxPayoutBps;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("gameType"),
+        borsh.u64("minBet"),
+        borsh.u64("maxBet"),
+        borsh.u32("maxPayoutBps"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GameBetLimitsParams({
+      gameType: obj.gameType,
+      minBet: obj.minBet,
+      maxBet: obj.maxBet,
+      maxPayoutBps: obj.maxPayoutBps,
+    });
+  }
+
+  static toEncodable(fields: GameBetLimitsParamsFields) {
+    return {
+      gameType: fields.gameType,
+      minBet: fields.minBet,
+      maxBet: fields.maxBet,
+      maxPayoutBps: fields.maxPayoutBps,
+    };
+  }
+
+  toJSON(): GameBetLimitsParamsJSON {
+    return {
+      gameType: this.gameType,
+      minBet: this.minBet.toString(),
+      maxBet: this.maxBet.toString(),
+      maxPayoutBps: this.maxPayoutBps,
+    };
+  }
+
+  static fromJSON(obj: GameBetLimitsParamsJSON): GameBetLimitsParams {
+    return new GameBetLimitsParams({
+      gameType: obj.gameType,
+      minBet: new BN(obj.minBet),
+      maxBet: new BN(obj.maxBet),
+      maxPayoutBps: obj.maxPayoutBps,
+    });
+  }
+
+  toEncodable() {
+    return GameBetLimitsParams.toEncodable(this);
+  }
+}
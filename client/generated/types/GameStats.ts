@@ -0,0 +1,104 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface GameStatsFields {
+  volume: BN;
+  payouts: BN;
+  netPnl: BN;
+  openExposure: BN;
+  roundsPlaced: BN;
+  roundsSettled: BN;
+}
+
+export interface GameStatsJSON {
+  volume: string;
+  payouts: string;
+  netPnl: string;
+  openExposure: string;
+  roundsPlaced: string;
+  roundsSettled: string;
+}
+
+export class GameStats {
+  readonly volume: BN;
+  readonly payouts: BN;
+  readonly netPnl: BN;
+  readonly openExposure: BN;
+  readonly roundsPlaced: BN;
+  readonly roundsSettled: BN;
+
+  constructor(fields: GameStatsFields) {
+    this.volume = fields.volume;
+    this.payouts = fields.payouts;
+    this.netPnl = fields.netPnl;
+    this.openExposure = fields.openExposure;
+    this.roundsPlaced = fields.roundsPlaced;
+    this.roundsSettled = fields.roundsSettled;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("volume"),
+        borsh.u64("payouts"),
+        borsh.i64("netPnl"),
+        borsh.u64("openExposure"),
+        borsh.u64("roundsPlaced"),
+        borsh.u64("roundsSettled"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GameStats({
+      volume: obj.volume,
+      payouts: obj.payouts,
+      netPnl: obj.netPnl,
+      openExposure: obj.openExposure,
+      roundsPlaced: obj.roundsPlaced,
+      roundsSettled: obj.roundsSettled,
+    });
+  }
+
+  static toEncodable(fields: GameStatsFields) {
+    return {
+      volume: fields.volume,
+      payouts: fields.payouts,
+      netPnl: fields.netPnl,
+      openExposure: fields.openExposure,
+      roundsPlaced: fields.roundsPlaced,
+      roundsSettled: fields.roundsSettled,
+    };
+  }
+
+  toJSON(): GameStatsJSON {
+    return {
+      volume: this.volume.toString(),
+      payouts: this.payouts.toString(),
+      netPnl: this.netPnl.toString(),
+      openExposure: this.openExposure.toString(),
+      roundsPlaced: this.roundsPlaced.toString(),
+      roundsSettled: this.roundsSettled.toString(),
+    };
+  }
+
+  static fromJSON(obj: GameStatsJSON): GameStats {
+    return new GameStats({
+      volume: new BN(obj.volume),
+      payouts: new BN(obj.payouts),
+      netPnl: new BN(obj.netPnl),
+      openExposure: new BN(obj.openExposure),
+      roundsPlaced: new BN(obj.roundsPlaced),
+      roundsSettled: new BN(obj.roundsSettled),
+    });
+  }
+
+  toEncodable() {
+    return GameStats.toEncodable(this);
+  }
+}
@@ -0,0 +1,54 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface HouseCommitParamsFields {
+  commitment: Array<number>;
+}
+
+export interface HouseCommitParamsJSON {
+  commitment: Array<number>;
+}
+
+export class HouseCommitParams {
+  readonly commitment: Array<number>;
+
+  constructor(fields: HouseCommitParamsFields) {
+    this.commitment = fields.commitment;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.array(borsh.u8(), 32, "commitment")], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new HouseCommitParams({
+      commitment: obj.commitment,
+    });
+  }
+
+  static toEncodable(fields: HouseCommitParamsFields) {
+    return {
+      commitment: fields.commitment,
+    };
+  }
+
+  toJSON(): HouseCommitParamsJSON {
+    return {
+      commitment: this.commitment,
+    };
+  }
+
+  static fromJSON(obj: HouseCommitParamsJSON): HouseCommitParams {
+    return new HouseCommitParams({
+      commitment: obj.commitment,
+    });
+  }
+
+  toEncodable() {
+    return HouseCommitParams.toEncodable(this);
+  }
+}
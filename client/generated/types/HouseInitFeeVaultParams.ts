@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface HouseInitFeeVaultParamsFields {}
+
+export interface HouseInitFeeVaultParamsJSON {}
+
+export class HouseInitFeeVaultParams {
+  constructor(fields: HouseInitFeeVaultParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new HouseInitFeeVaultParams({});
+  }
+
+  static toEncodable(fields: HouseInitFeeVaultParamsFields) {
+    return {};
+  }
+
+  toJSON(): HouseInitFeeVaultParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: HouseInitFeeVaultParamsJSON): HouseInitFeeVaultParams {
+    return new HouseInitFeeVaultParams({});
+  }
+
+  toEncodable() {
+    return HouseInitFeeVaultParams.toEncodable(this);
+  }
+}
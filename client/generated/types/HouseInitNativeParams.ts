@@ -0,0 +1,54 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface HouseInitNativeParamsFields {
+  liquidity: BN;
+}
+
+export interface HouseInitNativeParamsJSON {
+  liquidity: string;
+}
+
+export class HouseInitNativeParams {
+  readonly liquidity: BN;
+
+  constructor(fields: HouseInitNativeParamsFields) {
+    this.liquidity = fields.liquidity;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("liquidity")], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new HouseInitNativeParams({
+      liquidity: obj.liquidity,
+    });
+  }
+
+  static toEncodable(fields: HouseInitNativeParamsFields) {
+    return {
+      liquidity: fields.liquidity,
+    };
+  }
+
+  toJSON(): HouseInitNativeParamsJSON {
+    return {
+      liquidity: this.liquidity.toString(),
+    };
+  }
+
+  static fromJSON(obj: HouseInitNativeParamsJSON): HouseInitNativeParams {
+    return new HouseInitNativeParams({
+      liquidity: new BN(obj.liquidity),
+    });
+  }
+
+  toEncodable() {
+    return HouseInitNativeParams.toEncodable(this);
+  }
+}
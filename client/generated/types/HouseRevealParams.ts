@@ -0,0 +1,54 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface HouseRevealParamsFields {
+  secret: Array<number>;
+}
+
+export interface HouseRevealParamsJSON {
+  secret: Array<number>;
+}
+
+export class HouseRevealParams {
+  readonly secret: Array<number>;
+
+  constructor(fields: HouseRevealParamsFields) {
+    this.secret = fields.secret;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.array(borsh.u8(), 32, "secret")], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new HouseRevealParams({
+      secret: obj.secret,
+    });
+  }
+
+  static toEncodable(fields: HouseRevealParamsFields) {
+    return {
+      secret: fields.secret,
+    };
+  }
+
+  toJSON(): HouseRevealParamsJSON {
+    return {
+      secret: this.secret,
+    };
+  }
+
+  static fromJSON(obj: HouseRevealParamsJSON): HouseRevealParams {
+    return new HouseRevealParams({
+      secret: obj.secret,
+    });
+  }
+
+  toEncodable() {
+    return HouseRevealParams.toEncodable(this);
+  }
+}
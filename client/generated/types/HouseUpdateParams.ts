@@ -0,0 +1,97 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface HouseUpdateParamsFields {
+  sponsorVrfFees: boolean | null;
+  vrfFee: BN | null;
+  betLimits: types.GameBetLimitsParamsFields | null;
+  maxLiabilityBps: number | null;
+}
+
+export interface HouseUpdateParamsJSON {
+  sponsorVrfFees: boolean | null;
+  vrfFee: string | null;
+  betLimits: types.GameBetLimitsParamsJSON | null;
+  maxLiabilityBps: number | null;
+}
+
+export class HouseUpdateParams {
+  readonly sponsorVrfFees: boolean | null;
+  readonly vrfFee: BN | null;
+  readonly betLimits: types.GameBetLimitsParams | null;
+  readonly maxLiabilityBps: number | null;
+
+  constructor(fields: HouseUpdateParamsFields) {
+    this.sponsorVrfFees = fields.sponsorVrfFees;
+    this.vrfFee = fields.vrfFee;
+    this.betLimits =
+      (fields.betLimits &&
+        new types.GameBetLimitsParams({ ...fields.betLimits })) ||
+      null;
+    this.maxLiabilityBps = fields.maxLiabilityBps;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.option(borsh.bool(), "sponsorVrfFees"),
+        borsh.option(borsh.u64(), "vrfFee"),
+        borsh.option(types.GameBetLimitsParams.layout(), "betLimits"),
+        borsh.option(borsh.u32(), "maxLiabilityBps"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new HouseUpdateParams({
+      sponsorVrfFees: obj.sponsorVrfFees,
+      vrfFee: obj.vrfFee,
+      betLimits:
+        (obj.betLimits &&
+          types.GameBetLimitsParams.fromDecoded(obj.betLimits)) ||
+        null,
+      maxLiabilityBps: obj.maxLiabilityBps,
+    });
+  }
+
+  static toEncodable(fields: HouseUpdateParamsFields) {
+    return {
+      sponsorVrfFees: fields.sponsorVrfFees,
+      vrfFee: fields.vrfFee,
+      betLimits:
+        (fields.betLimits &&
+          types.GameBetLimitsParams.toEncodable(fields.betLimits)) ||
+        null,
+      maxLiabilityBps: fields.maxLiabilityBps,
+    };
+  }
+
+  toJSON(): HouseUpdateParamsJSON {
+    return {
+      sponsorVrfFees: this.sponsorVrfFees,
+      vrfFee: (this.vrfFee && this.vrfFee.toString()) || null,
+      betLimits: (this.betLimits && this.betLimits.toJSON()) || null,
+      maxLiabilityBps: this.maxLiabilityBps,
+    };
+  }
+
+  static fromJSON(obj: HouseUpdateParamsJSON): HouseUpdateParams {
+    return new HouseUpdateParams({
+      sponsorVrfFees: obj.sponsorVrfFees,
+      vrfFee: (obj.vrfFee && new BN(obj.vrfFee)) || null,
+      betLimits:
+        (obj.betLimits && types.GameBetLimitsParams.fromJSON(obj.betLimits)) ||
+        null,
+      maxLiabilityBps: obj.maxLiabilityBps,
+    });
+  }
+
+  toEncodable() {
+    return HouseUpdateParams.toEncodable(this);
+  }
+}
@@ -0,0 +1,95 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface PendingLimitsFields {
+  dailyWagerLimit: BN;
+  weeklyWagerLimit: BN;
+  dailyLossLimit: BN;
+  cooldown: number;
+  effectiveAt: BN;
+}
+
+export interface PendingLimitsJSON {
+  dailyWagerLimit: string;
+  weeklyWagerLimit: string;
+  dailyLossLimit: string;
+  cooldown: number;
+  effectiveAt: string;
+}
+
+export class PendingLimits {
+  readonly dailyWagerLimit: BN;
+  readonly weeklyWagerLimit: BN;
+  readonly dailyLossLimit: BN;
+  readonly cooldown: number;
+  readonly effectiveAt: BN;
+
+  constructor(fields: PendingLimitsFields) {
+    this.dailyWagerLimit = fields.dailyWagerLimit;
+    this.weeklyWagerLimit = fields.weeklyWagerLimit;
+    this.dailyLossLimit = fields.dailyLossLimit;
+    this.cooldown = fields.cooldown;
+    this.effectiveAt = fields.effectiveAt;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("dailyWagerLimit"),
+        borsh.u64("weeklyWagerLimit"),
+        borsh.u64("dailyLossLimit"),
+        borsh.u32("cooldown"),
+        borsh.i64("effectiveAt"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PendingLimits({
+      dailyWagerLimit: obj.dailyWagerLimit,
+      weeklyWagerLimit: obj.weeklyWagerLimit,
+      dailyLossLimit: obj.dailyLossLimit,
+      cooldown: obj.cooldown,
+      effectiveAt: obj.effectiveAt,
+    });
+  }
+
+  static toEncodable(fields: PendingLimitsFields) {
+    return {
+      dailyWagerLimit: fields.dailyWagerLimit,
+      weeklyWagerLimit: fields.weeklyWagerLimit,
+      dailyLossLimit: fields.dailyLossLimit,
+      cooldown: fields.cooldown,
+      effectiveAt: fields.effectiveAt,
+    };
+  }
+
+  toJSON(): PendingLimitsJSON {
+    return {
+      dailyWagerLimit: this.dailyWagerLimit.toString(),
+      weeklyWagerLimit: this.weeklyWagerLimit.toString(),
+      dailyLossLimit: this.dailyLossLimit.toString(),
+      cooldown: this.cooldown,
+      effectiveAt: this.effectiveAt.toString(),
+    };
+  }
+
+  static fromJSON(obj: PendingLimitsJSON): PendingLimits {
+    return new PendingLimits({
+      dailyWagerLimit: new BN(obj.dailyWagerLimit),
+      weeklyWagerLimit: new BN(obj.weeklyWagerLimit),
+      dailyLossLimit: new BN(obj.dailyLossLimit),
+      cooldown: obj.cooldown,
+      effectiveAt: new BN(obj.effectiveAt),
+    });
+  }
+
+  toEncodable() {
+    return PendingLimits.toEncodable(this);
+  }
+}
@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserAutoplayBetParamsFields {}
+
+export interface UserAutoplayBetParamsJSON {}
+
+export class UserAutoplayBetParams {
+  constructor(fields: UserAutoplayBetParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserAutoplayBetParams({});
+  }
+
+  static toEncodable(fields: UserAutoplayBetParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserAutoplayBetParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: UserAutoplayBetParamsJSON): UserAutoplayBetParams {
+    return new UserAutoplayBetParams({});
+  }
+
+  toEncodable() {
+    return UserAutoplayBetParams.toEncodable(this);
+  }
+}
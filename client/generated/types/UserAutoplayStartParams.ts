@@ -0,0 +1,104 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserAutoplayStartParamsFields {
+  gameType: number;
+  userGuess: number;
+  betAmount: BN;
+  numBets: number;
+  stopLoss: BN;
+  takeProfit: BN;
+}
+
+export interface UserAutoplayStartParamsJSON {
+  gameType: number;
+  userGuess: number;
+  betAmount: string;
+  numBets: number;
+  stopLoss: string;
+  takeProfit: string;
+}
+
+export class UserAutoplayStartParams {
+  readonly gameType: number;
+  readonly userGuess: number;
+  readonly betAmount: BN;
+  readonly numBets: number;
+  readonly stopLoss: BN;
+  readonly takeProfit: BN;
+
+  constructor(fields: UserAutoplayStartParamsFields) {
+    this.gameType = fields.gameType;
+    this.userGuess = fields.userGuess;
+    this.betAmount = fields.betAmount;
+    this.numBets = fields.numBets;
+    this.stopLoss = fields.stopLoss;
+    this.takeProfit = fields.takeProfit;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("gameType"),
+        borsh.u32("userGuess"),
+        borsh.u64("betAmount"),
+        borsh.u32("numBets"),
+        borsh.u64("stopLoss"),
+        borsh.u64("takeProfit"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserAutoplayStartParams({
+      gameType: obj.gameType,
+      userGuess: obj.userGuess,
+      betAmount: obj.betAmount,
+      numBets: obj.numBets,
+      stopLoss: obj.stopLoss,
+      takeProfit: obj.takeProfit,
+    });
+  }
+
+  static toEncodable(fields: UserAutoplayStartParamsFields) {
+    return {
+      gameType: fields.gameType,
+      userGuess: fields.userGuess,
+      betAmount: fields.betAmount,
+      numBets: fields.numBets,
+      stopLoss: fields.stopLoss,
+      takeProfit: fields.takeProfit,
+    };
+  }
+
+  toJSON(): UserAutoplayStartParamsJSON {
+    return {
+      gameType: this.gameType,
+      userGuess: this.userGuess,
+      betAmount: this.betAmount.toString(),
+      numBets: this.numBets,
+      stopLoss: this.stopLoss.toString(),
+      takeProfit: this.takeProfit.toString(),
+    };
+  }
+
+  static fromJSON(obj: UserAutoplayStartParamsJSON): UserAutoplayStartParams {
+    return new UserAutoplayStartParams({
+      gameType: obj.gameType,
+      userGuess: obj.userGuess,
+      betAmount: new BN(obj.betAmount),
+      numBets: obj.numBets,
+      stopLoss: new BN(obj.stopLoss),
+      takeProfit: new BN(obj.takeProfit),
+    });
+  }
+
+  toEncodable() {
+    return UserAutoplayStartParams.toEncodable(this);
+  }
+}
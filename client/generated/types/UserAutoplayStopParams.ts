@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserAutoplayStopParamsFields {}
+
+export interface UserAutoplayStopParamsJSON {}
+
+export class UserAutoplayStopParams {
+  constructor(fields: UserAutoplayStopParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserAutoplayStopParams({});
+  }
+
+  static toEncodable(fields: UserAutoplayStopParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserAutoplayStopParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: UserAutoplayStopParamsJSON): UserAutoplayStopParams {
+    return new UserAutoplayStopParams({});
+  }
+
+  toEncodable() {
+    return UserAutoplayStopParams.toEncodable(this);
+  }
+}
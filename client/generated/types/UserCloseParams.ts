@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserCloseParamsFields {}
+
+export interface UserCloseParamsJSON {}
+
+export class UserCloseParams {
+  constructor(fields: UserCloseParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserCloseParams({});
+  }
+
+  static toEncodable(fields: UserCloseParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserCloseParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: UserCloseParamsJSON): UserCloseParams {
+    return new UserCloseParams({});
+  }
+
+  toEncodable() {
+    return UserCloseParams.toEncodable(this);
+  }
+}
@@ -0,0 +1,77 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserDelegateFields {
+  key: PublicKey;
+  expirySlot: BN;
+  remainingAllowance: BN;
+}
+
+export interface UserDelegateJSON {
+  key: string;
+  expirySlot: string;
+  remainingAllowance: string;
+}
+
+export class UserDelegate {
+  readonly key: PublicKey;
+  readonly expirySlot: BN;
+  readonly remainingAllowance: BN;
+
+  constructor(fields: UserDelegateFields) {
+    this.key = fields.key;
+    this.expirySlot = fields.expirySlot;
+    this.remainingAllowance = fields.remainingAllowance;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("key"),
+        borsh.u64("expirySlot"),
+        borsh.u64("remainingAllowance"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserDelegate({
+      key: obj.key,
+      expirySlot: obj.expirySlot,
+      remainingAllowance: obj.remainingAllowance,
+    });
+  }
+
+  static toEncodable(fields: UserDelegateFields) {
+    return {
+      key: fields.key,
+      expirySlot: fields.expirySlot,
+      remainingAllowance: fields.remainingAllowance,
+    };
+  }
+
+  toJSON(): UserDelegateJSON {
+    return {
+      key: this.key.toString(),
+      expirySlot: this.expirySlot.toString(),
+      remainingAllowance: this.remainingAllowance.toString(),
+    };
+  }
+
+  static fromJSON(obj: UserDelegateJSON): UserDelegate {
+    return new UserDelegate({
+      key: new PublicKey(obj.key),
+      expirySlot: new BN(obj.expirySlot),
+      remainingAllowance: new BN(obj.remainingAllowance),
+    });
+  }
+
+  toEncodable() {
+    return UserDelegate.toEncodable(this);
+  }
+}
@@ -0,0 +1,40 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserHistoryPageCloseParamsFields {}
+
+export interface UserHistoryPageCloseParamsJSON {}
+
+export class UserHistoryPageCloseParams {
+  constructor(fields: UserHistoryPageCloseParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserHistoryPageCloseParams({});
+  }
+
+  static toEncodable(fields: UserHistoryPageCloseParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserHistoryPageCloseParamsJSON {
+    return {};
+  }
+
+  static fromJSON(
+    obj: UserHistoryPageCloseParamsJSON
+  ): UserHistoryPageCloseParams {
+    return new UserHistoryPageCloseParams({});
+  }
+
+  toEncodable() {
+    return UserHistoryPageCloseParams.toEncodable(this);
+  }
+}
@@ -0,0 +1,56 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserHistoryPageInitParamsFields {
+  pageIndex: number;
+}
+
+export interface UserHistoryPageInitParamsJSON {
+  pageIndex: number;
+}
+
+export class UserHistoryPageInitParams {
+  readonly pageIndex: number;
+
+  constructor(fields: UserHistoryPageInitParamsFields) {
+    this.pageIndex = fields.pageIndex;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u32("pageIndex")], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserHistoryPageInitParams({
+      pageIndex: obj.pageIndex,
+    });
+  }
+
+  static toEncodable(fields: UserHistoryPageInitParamsFields) {
+    return {
+      pageIndex: fields.pageIndex,
+    };
+  }
+
+  toJSON(): UserHistoryPageInitParamsJSON {
+    return {
+      pageIndex: this.pageIndex,
+    };
+  }
+
+  static fromJSON(
+    obj: UserHistoryPageInitParamsJSON
+  ): UserHistoryPageInitParams {
+    return new UserHistoryPageInitParams({
+      pageIndex: obj.pageIndex,
+    });
+  }
+
+  toEncodable() {
+    return UserHistoryPageInitParams.toEncodable(this);
+  }
+}
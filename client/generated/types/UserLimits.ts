@@ -0,0 +1,149 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserLimitsFields {
+  dailyWagerLimit: BN;
+  weeklyWagerLimit: BN;
+  dailyLossLimit: BN;
+  cooldown: number;
+  selfExcludedUntil: BN;
+  day: BN;
+  dayWagered: BN;
+  dayNetPnl: BN;
+  week: BN;
+  weekWagered: BN;
+  pending: types.PendingLimitsFields;
+}
+
+export interface UserLimitsJSON {
+  dailyWagerLimit: string;
+  weeklyWagerLimit: string;
+  dailyLossLimit: string;
+  cooldown: number;
+  selfExcludedUntil: string;
+  day: string;
+  dayWagered: string;
+  dayNetPnl: string;
+  week: string;
+  weekWagered: string;
+  pending: types.PendingLimitsJSON;
+}
+
+export class UserLimits {
+  readonly dailyWagerLimit: BN;
+  readonly weeklyWagerLimit: BN;
+  readonly dailyLossLimit: BN;
+  readonly cooldown: number;
+  readonly selfExcludedUntil: BN;
+  readonly day: BN;
+  readonly dayWagered: BN;
+  readonly dayNetPnl: BN;
+  readonly week: BN;
+  readonly weekWagered: BN;
+  readonly pending: types.PendingLimits;
+
+  constructor(fields: UserLimitsFields) {
+    this.dailyWagerLimit = fields.dailyWagerLimit;
+    this.weeklyWagerLimit = fields.weeklyWagerLimit;
+    this.dailyLossLimit = fields.dailyLossLimit;
+    this.cooldown = fields.cooldown;
+    this.selfExcludedUntil = fields.selfExcludedUntil;
+    this.day = fields.day;
+    this.dayWagered = fields.dayWagered;
+    this.dayNetPnl = fields.dayNetPnl;
+    this.week = fields.week;
+    this.weekWagered = fields.weekWagered;
+    this.pending = new types.PendingLimits({ ...fields.pending });
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("dailyWagerLimit"),
+        borsh.u64("weeklyWagerLimit"),
+        borsh.u64("dailyLossLimit"),
+        borsh.u32("cooldown"),
+        borsh.i64("selfExcludedUntil"),
+        borsh.i64("day"),
+        borsh.u64("dayWagered"),
+        borsh.i64("dayNetPnl"),
+        borsh.i64("week"),
+        borsh.u64("weekWagered"),
+        types.PendingLimits.layout("pending"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserLimits({
+      dailyWagerLimit: obj.dailyWagerLimit,
+      weeklyWagerLimit: obj.weeklyWagerLimit,
+      dailyLossLimit: obj.dailyLossLimit,
+      cooldown: obj.cooldown,
+      selfExcludedUntil: obj.selfExcludedUntil,
+      day: obj.day,
+      dayWagered: obj.dayWagered,
+      dayNetPnl: obj.dayNetPnl,
+      week: obj.week,
+      weekWagered: obj.weekWagered,
+      pending: types.PendingLimits.fromDecoded(obj.pending),
+    });
+  }
+
+  static toEncodable(fields: UserLimitsFields) {
+    return {
+      dailyWagerLimit: fields.dailyWagerLimit,
+      weeklyWagerLimit: fields.weeklyWagerLimit,
+      dailyLossLimit: fields.dailyLossLimit,
+      cooldown: fields.cooldown,
+      selfExcludedUntil: fields.selfExcludedUntil,
+      day: fields.day,
+      dayWagered: fields.dayWagered,
+      dayNetPnl: fields.dayNetPnl,
+      week: fields.week,
+      weekWagered: fields.weekWagered,
+      pending: types.PendingLimits.toEncodable(fields.pending),
+    };
+  }
+
+  toJSON(): UserLimitsJSON {
+    return {
+      dailyWagerLimit: this.dailyWagerLimit.toString(),
+      weeklyWagerLimit: this.weeklyWagerLimit.toString(),
+      dailyLossLimit: this.dailyLossLimit.toString(),
+      cooldown: this.cooldown,
+      selfExcludedUntil: this.selfExcludedUntil.toString(),
+      day: this.day.toString(),
+      dayWagered: this.dayWagered.toString(),
+      dayNetPnl: this.dayNetPnl.toString(),
+      week: this.week.toString(),
+      weekWagered: this.weekWagered.toString(),
+      pending: this.pending.toJSON(),
+    };
+  }
+
+  static fromJSON(obj: UserLimitsJSON): UserLimits {
+    return new UserLimits({
+      dailyWagerLimit: new BN(obj.dailyWagerLimit),
+      weeklyWagerLimit: new BN(obj.weeklyWagerLimit),
+      dailyLossLimit: new BN(obj.dailyLossLimit),
+      cooldown: obj.cooldown,
+      selfExcludedUntil: new BN(obj.selfExcludedUntil),
+      day: new BN(obj.day),
+      dayWagered: new BN(obj.dayWagered),
+      dayNetPnl: new BN(obj.dayNetPnl),
+      week: new BN(obj.week),
+      weekWagered: new BN(obj.weekWagered),
+      pending: types.PendingLimits.fromJSON(obj.pending),
+    });
+  }
+
+  toEncodable() {
+    return UserLimits.toEncodable(this);
+  }
+}
@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserMigrateParamsFields {}
+
+export interface UserMigrateParamsJSON {}
+
+export class UserMigrateParams {
+  constructor(fields: UserMigrateParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserMigrateParams({});
+  }
+
+  static toEncodable(fields: UserMigrateParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserMigrateParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: UserMigrateParamsJSON): UserMigrateParams {
+    return new UserMigrateParams({});
+  }
+
+  toEncodable() {
+    return UserMigrateParams.toEncodable(this);
+  }
+}
@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserRecordSettleParamsFields {}
+
+export interface UserRecordSettleParamsJSON {}
+
+export class UserRecordSettleParams {
+  constructor(fields: UserRecordSettleParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserRecordSettleParams({});
+  }
+
+  static toEncodable(fields: UserRecordSettleParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserRecordSettleParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: UserRecordSettleParamsJSON): UserRecordSettleParams {
+    return new UserRecordSettleParams({});
+  }
+
+  toEncodable() {
+    return UserRecordSettleParams.toEncodable(this);
+  }
+}
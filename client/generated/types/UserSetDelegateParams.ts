@@ -0,0 +1,77 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserSetDelegateParamsFields {
+  delegate: PublicKey;
+  expirySlot: BN;
+  allowance: BN;
+}
+
+export interface UserSetDelegateParamsJSON {
+  delegate: string;
+  expirySlot: string;
+  allowance: string;
+}
+
+export class UserSetDelegateParams {
+  readonly delegate: PublicKey;
+  readonly expirySlot: BN;
+  readonly allowance: BN;
+
+  constructor(fields: UserSetDelegateParamsFields) {
+    this.delegate = fields.delegate;
+    this.expirySlot = fields.expirySlot;
+    this.allowance = fields.allowance;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("delegate"),
+        borsh.u64("expirySlot"),
+        borsh.u64("allowance"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserSetDelegateParams({
+      delegate: obj.delegate,
+      expirySlot: obj.expirySlot,
+      allowance: obj.allowance,
+    });
+  }
+
+  static toEncodable(fields: UserSetDelegateParamsFields) {
+    return {
+      delegate: fields.delegate,
+      expirySlot: fields.expirySlot,
+      allowance: fields.allowance,
+    };
+  }
+
+  toJSON(): UserSetDelegateParamsJSON {
+    return {
+      delegate: this.delegate.toString(),
+      expirySlot: this.expirySlot.toString(),
+      allowance: this.allowance.toString(),
+    };
+  }
+
+  static fromJSON(obj: UserSetDelegateParamsJSON): UserSetDelegateParams {
+    return new UserSetDelegateParams({
+      delegate: new PublicKey(obj.delegate),
+      expirySlot: new BN(obj.expirySlot),
+      allowance: new BN(obj.allowance),
+    });
+  }
+
+  toEncodable() {
+    return UserSetDelegateParams.toEncodable(this);
+  }
+}
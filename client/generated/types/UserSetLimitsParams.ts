@@ -0,0 +1,103 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserSetLimitsParamsFields {
+  dailyWagerLimit: BN | null;
+  weeklyWagerLimit: BN | null;
+  dailyLossLimit: BN | null;
+  cooldown: number | null;
+  selfExcludedUntil: BN | null;
+}
+
+export interface UserSetLimitsParamsJSON {
+  dailyWagerLimit: string | null;
+  weeklyWagerLimit: string | null;
+  dailyLossLimit: string | null;
+  cooldown: number | null;
+  selfExcludedUntil: string | null;
+}
+
+export class UserSetLimitsParams {
+  readonly dailyWagerLimit: BN | null;
+  readonly weeklyWagerLimit: BN | null;
+  readonly dailyLossLimit: BN | null;
+  readonly cooldown: number | null;
+  readonly selfExcludedUntil: BN | null;
+
+  constructor(fields: UserSetLimitsParamsFields) {
+    this.dailyWagerLimit = fields.dailyWagerLimit;
+    this.weeklyWagerLimit = fields.weeklyWagerLimit;
+    this.dailyLossLimit = fields.dailyLossLimit;
+    this.cooldown = fields.cooldown;
+    this.selfExcludedUntil = fields.selfExcludedUntil;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.option(borsh.u64(), "dailyWagerLimit"),
+        borsh.option(borsh.u64(), "weeklyWagerLimit"),
+        borsh.option(borsh.u64(), "dailyLossLimit"),
+        borsh.option(borsh.u32(), "cooldown"),
+        borsh.option(borsh.i64(), "selfExcludedUntil"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserSetLimitsParams({
+      dailyWagerLimit: obj.dailyWagerLimit,
+      weeklyWagerLimit: obj.weeklyWagerLimit,
+      dailyLossLimit: obj.dailyLossLimit,
+      cooldown: obj.cooldown,
+      selfExcludedUntil: obj.selfExcludedUntil,
+    });
+  }
+
+  static toEncodable(fields: UserSetLimitsParamsFields) {
+    return {
+      dailyWagerLimit: fields.dailyWagerLimit,
+      weeklyWagerLimit: fields.weeklyWagerLimit,
+      dailyLossLimit: fields.dailyLossLimit,
+      cooldown: fields.cooldown,
+      selfExcludedUntil: fields.selfExcludedUntil,
+    };
+  }
+
+  toJSON(): UserSetLimitsParamsJSON {
+    return {
+      dailyWagerLimit:
+        (this.dailyWagerLimit && this.dailyWagerLimit.toString()) || null,
+      weeklyWagerLimit:
+        (this.weeklyWagerLimit && this.weeklyWagerLimit.toString()) || null,
+      dailyLossLimit:
+        (this.dailyLossLimit && this.dailyLossLimit.toString()) || null,
+      cooldown: this.cooldown,
+      selfExcludedUntil:
+        (this.selfExcludedUntil && this.selfExcludedUntil.toString()) || null,
+    };
+  }
+
+  static fromJSON(obj: UserSetLimitsParamsJSON): UserSetLimitsParams {
+    return new UserSetLimitsParams({
+      dailyWagerLimit:
+        (obj.dailyWagerLimit && new BN(obj.dailyWagerLimit)) || null,
+      weeklyWagerLimit:
+        (obj.weeklyWagerLimit && new BN(obj.weeklyWagerLimit)) || null,
+      dailyLossLimit:
+        (obj.dailyLossLimit && new BN(obj.dailyLossLimit)) || null,
+      cooldown: obj.cooldown,
+      selfExcludedUntil:
+        (obj.selfExcludedUntil && new BN(obj.selfExcludedUntil)) || null,
+    });
+  }
+
+  toEncodable() {
+    return UserSetLimitsParams.toEncodable(this);
+  }
+}
@@ -0,0 +1,113 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserStatsFields {
+  totalWagered: BN;
+  totalWon: BN;
+  netPnl: BN;
+  roundsPlayed: BN;
+  wins: BN;
+  longestWinStreak: number;
+  currentWinStreak: number;
+}
+
+export interface UserStatsJSON {
+  totalWagered: string;
+  totalWon: string;
+  netPnl: string;
+  roundsPlayed: string;
+  wins: string;
+  longestWinStreak: number;
+  currentWinStreak: number;
+}
+
+export class UserStats {
+  readonly totalWagered: BN;
+  readonly totalWon: BN;
+  readonly netPnl: BN;
+  readonly roundsPlayed: BN;
+  readonly wins: BN;
+  readonly longestWinStreak: number;
+  readonly currentWinStreak: number;
+
+  constructor(fields: UserStatsFields) {
+    this.totalWagered = fields.totalWagered;
+    this.totalWon = fields.totalWon;
+    this.netPnl = fields.netPnl;
+    this.roundsPlayed = fields.roundsPlayed;
+    this.wins = fields.wins;
+    this.longestWinStreak = fields.longestWinStreak;
+    this.currentWinStreak = fields.currentWinStreak;
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("totalWagered"),
+        borsh.u64("totalWon"),
+        borsh.i64("netPnl"),
+        borsh.u64("roundsPlayed"),
+        borsh.u64("wins"),
+        borsh.u32("longestWinStreak"),
+        borsh.u32("currentWinStreak"),
+      ],
+      property
+    );
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserStats({
+      totalWagered: obj.totalWagered,
+      totalWon: obj.totalWon,
+      netPnl: obj.netPnl,
+      roundsPlayed: obj.roundsPlayed,
+      wins: obj.wins,
+      longestWinStreak: obj.longestWinStreak,
+      currentWinStreak: obj.currentWinStreak,
+    });
+  }
+
+  static toEncodable(fields: UserStatsFields) {
+    return {
+      totalWagered: fields.totalWagered,
+      totalWon: fields.totalWon,
+      netPnl: fields.netPnl,
+      roundsPlayed: fields.roundsPlayed,
+      wins: fields.wins,
+      longestWinStreak: fields.longestWinStreak,
+      currentWinStreak: fields.currentWinStreak,
+    };
+  }
+
+  toJSON(): UserStatsJSON {
+    return {
+      totalWagered: this.totalWagered.toString(),
+      totalWon: this.totalWon.toString(),
+      netPnl: this.netPnl.toString(),
+      roundsPlayed: this.roundsPlayed.toString(),
+      wins: this.wins.toString(),
+      longestWinStreak: this.longestWinStreak,
+      currentWinStreak: this.currentWinStreak,
+    };
+  }
+
+  static fromJSON(obj: UserStatsJSON): UserStats {
+    return new UserStats({
+      totalWagered: new BN(obj.totalWagered),
+      totalWon: new BN(obj.totalWon),
+      netPnl: new BN(obj.netPnl),
+      roundsPlayed: new BN(obj.roundsPlayed),
+      wins: new BN(obj.wins),
+      longestWinStreak: obj.longestWinStreak,
+      currentWinStreak: obj.currentWinStreak,
+    });
+  }
+
+  toEncodable() {
+    return UserStats.toEncodable(this);
+  }
+}
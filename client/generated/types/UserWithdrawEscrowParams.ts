@@ -0,0 +1,38 @@
+import { FlipProgram } from "../../program";
+import { PublicKey } from "@solana/web3.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types"; // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh";
+
+export interface UserWithdrawEscrowParamsFields {}
+
+export interface UserWithdrawEscrowParamsJSON {}
+
+export class UserWithdrawEscrowParams {
+  constructor(fields: UserWithdrawEscrowParamsFields) {}
+
+  static layout(property?: string) {
+    return borsh.struct([], property);
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserWithdrawEscrowParams({});
+  }
+
+  static toEncodable(fields: UserWithdrawEscrowParamsFields) {
+    return {};
+  }
+
+  toJSON(): UserWithdrawEscrowParamsJSON {
+    return {};
+  }
+
+  static fromJSON(obj: UserWithdrawEscrowParamsJSON): UserWithdrawEscrowParams {
+    return new UserWithdrawEscrowParams({});
+  }
+
+  toEncodable() {
+    return UserWithdrawEscrowParams.toEncodable(this);
+  }
+}
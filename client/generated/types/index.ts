@@ -2,10 +2,45 @@ import * as GameType from "./GameType";
 import * as RoundStatus from "./RoundStatus";
 
 export { HouseInitParams } from "./HouseInitParams";
+export { HouseInitNativeParams } from "./HouseInitNativeParams";
+export type {
+  HouseInitNativeParamsFields,
+  HouseInitNativeParamsJSON,
+} from "./HouseInitNativeParams";
 export type {
   HouseInitParamsFields,
   HouseInitParamsJSON,
 } from "./HouseInitParams";
+export { HouseUpdateParams } from "./HouseUpdateParams";
+export type {
+  HouseUpdateParamsFields,
+  HouseUpdateParamsJSON,
+} from "./HouseUpdateParams";
+export { GameBetLimitsParams } from "./GameBetLimitsParams";
+export type {
+  GameBetLimitsParamsFields,
+  GameBetLimitsParamsJSON,
+} from "./GameBetLimitsParams";
+export { HouseInitFeeVaultParams } from "./HouseInitFeeVaultParams";
+export { HouseCommitParams } from "./HouseCommitParams";
+export type {
+  HouseCommitParamsFields,
+  HouseCommitParamsJSON,
+} from "./HouseCommitParams";
+export { HouseRevealParams } from "./HouseRevealParams";
+export type {
+  HouseRevealParamsFields,
+  HouseRevealParamsJSON,
+} from "./HouseRevealParams";
+export type {
+  HouseInitFeeVaultParamsFields,
+  HouseInitFeeVaultParamsJSON,
+} from "./HouseInitFeeVaultParams";
+export { UserRecordSettleParams } from "./UserRecordSettleParams";
+export type {
+  UserRecordSettleParamsFields,
+  UserRecordSettleParamsJSON,
+} from "./UserRecordSettleParams";
 export { UserAirdropParams } from "./UserAirdropParams";
 export type {
   UserAirdropParamsFields,
@@ -23,12 +58,78 @@ export type {
   UserSettleParamsFields,
   UserSettleParamsJSON,
 } from "./UserSettleParams";
+export { UserHistoryPageInitParams } from "./UserHistoryPageInitParams";
+export type {
+  UserHistoryPageInitParamsFields,
+  UserHistoryPageInitParamsJSON,
+} from "./UserHistoryPageInitParams";
+export { UserHistoryPageCloseParams } from "./UserHistoryPageCloseParams";
+export type {
+  UserHistoryPageCloseParamsFields,
+  UserHistoryPageCloseParamsJSON,
+} from "./UserHistoryPageCloseParams";
+export { UserMigrateParams } from "./UserMigrateParams";
+export type {
+  UserMigrateParamsFields,
+  UserMigrateParamsJSON,
+} from "./UserMigrateParams";
+export { UserCloseParams } from "./UserCloseParams";
+export type {
+  UserCloseParamsFields,
+  UserCloseParamsJSON,
+} from "./UserCloseParams";
+export { UserWithdrawEscrowParams } from "./UserWithdrawEscrowParams";
+export type {
+  UserWithdrawEscrowParamsFields,
+  UserWithdrawEscrowParamsJSON,
+} from "./UserWithdrawEscrowParams";
+export { UserSetDelegateParams } from "./UserSetDelegateParams";
+export type {
+  UserSetDelegateParamsFields,
+  UserSetDelegateParamsJSON,
+} from "./UserSetDelegateParams";
+export { UserAutoplayStartParams } from "./UserAutoplayStartParams";
+export type {
+  UserAutoplayStartParamsFields,
+  UserAutoplayStartParamsJSON,
+} from "./UserAutoplayStartParams";
+export { UserAutoplayBetParams } from "./UserAutoplayBetParams";
+export type {
+  UserAutoplayBetParamsFields,
+  UserAutoplayBetParamsJSON,
+} from "./UserAutoplayBetParams";
+export { UserAutoplayStopParams } from "./UserAutoplayStopParams";
+export type {
+  UserAutoplayStopParamsFields,
+  UserAutoplayStopParamsJSON,
+} from "./UserAutoplayStopParams";
+export { UserSetLimitsParams } from "./UserSetLimitsParams";
+export type {
+  UserSetLimitsParamsFields,
+  UserSetLimitsParamsJSON,
+} from "./UserSetLimitsParams";
 export { GameConfig } from "./GameConfig";
 export type { GameConfigFields, GameConfigJSON } from "./GameConfig";
 export { Round } from "./Round";
 export type { RoundFields, RoundJSON } from "./Round";
 export { History } from "./History";
 export type { HistoryFields, HistoryJSON } from "./History";
+export { CommitReveal } from "./CommitReveal";
+export type { CommitRevealFields, CommitRevealJSON } from "./CommitReveal";
+export { GameStats } from "./GameStats";
+export type { GameStatsFields, GameStatsJSON } from "./GameStats";
+export { BetLimits } from "./BetLimits";
+export type { BetLimitsFields, BetLimitsJSON } from "./BetLimits";
+export { UserStats } from "./UserStats";
+export type { UserStatsFields, UserStatsJSON } from "./UserStats";
+export { UserDelegate } from "./UserDelegate";
+export type { UserDelegateFields, UserDelegateJSON } from "./UserDelegate";
+export { AutoPlay } from "./AutoPlay";
+export type { AutoPlayFields, AutoPlayJSON } from "./AutoPlay";
+export { UserLimits } from "./UserLimits";
+export type { UserLimitsFields, UserLimitsJSON } from "./UserLimits";
+export { PendingLimits } from "./PendingLimits";
+export type { PendingLimitsFields, PendingLimitsJSON } from "./PendingLimits";
 export { GameType };
 
 export type GameTypeKind =
@@ -5,6 +5,7 @@ import {
   createInitializeMintInstruction,
   createMintToInstruction,
   getMint,
+  NATIVE_MINT,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
@@ -16,7 +17,13 @@ import {
   TransactionObject,
 } from "@switchboard-xyz/solana.js";
 import { HouseState, HouseStateJSON } from "./generated/accounts";
-import { houseInit } from "./generated/instructions";
+import {
+  houseInit,
+  houseInitFeeVault,
+  houseInitNative,
+  houseUpdate,
+} from "./generated/instructions";
+import { HouseUpdateParamsFields } from "./generated/types";
 
 export class HouseAccountDoesNotExist extends Error {
   readonly name = "HouseAccountDoesNotExist";
@@ -42,13 +49,55 @@ export class House {
     this.state = state;
   }
 
-  static fromSeeds(programId: PublicKey): [PublicKey, number] {
+  /** Each mint has its own house */
+  static fromSeeds(programId: PublicKey, mint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("HOUSESEED"), mint.toBytes()],
+      programId
+    );
+  }
+
+  /** Houses created before houses were seeded by their mint */
+  static legacyFromSeeds(programId: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
       [Buffer.from("HOUSESEED")],
       programId
     );
   }
 
+  /** The house for a mint, falling back to the legacy house if it uses the mint */
+  static async fetchForMint(
+    program: anchor.Program,
+    mint: PublicKey
+  ): Promise<House | null> {
+    const [houseKey] = House.fromSeeds(program.programId, mint);
+    const [legacyHouseKey] = House.legacyFromSeeds(program.programId);
+    for (const key of [houseKey, legacyHouseKey]) {
+      const houseState = await HouseState.fetch(
+        {
+          connection: program.provider.connection,
+          programId: program.programId,
+        },
+        key
+      );
+      if (houseState !== null && houseState.mint.equals(mint)) {
+        return new House(program, key, houseState);
+      }
+    }
+    return null;
+  }
+
+  /** The token account that pays for sponsored VRF requests */
+  static feeVaultFromSeeds(
+    programId: PublicKey,
+    house: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("FEEVAULTSEED"), house.toBytes()],
+      programId
+    );
+  }
+
   async reload(): Promise<void> {
     const newState = await HouseState.fetch(
       {
@@ -119,10 +168,13 @@ export class House {
   ): Promise<[TransactionObject, PublicKey]> {
     const payer = switchboardQueue.program.walletPubkey;
 
-    const [houseKey, houseBump] = House.fromSeeds(program.programId);
-
     const mintPubkey: PublicKey = mint.publicKey;
 
+    const [houseKey, houseBump] = House.fromSeeds(
+      program.programId,
+      mintPubkey
+    );
+
     const [tokenVault] = PublicKey.findProgramAddressSync(
       [houseKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mintPubkey.toBuffer()],
       ASSOCIATED_TOKEN_PROGRAM_ID
@@ -151,19 +203,92 @@ export class House {
     return [new TransactionObject(payer, [initHouse], [mint]), houseKey];
   }
 
-  static async load(program: anchor.Program): Promise<House> {
-    const connection = program.provider.connection;
-    const [houseKey, houseBump] = House.fromSeeds(program.programId);
+  static async createNative(
+    program: anchor.Program,
+    switchboardQueue: QueueAccount,
+    liquidity = new anchor.BN(0)
+  ): Promise<House> {
+    const [initHouse, houseKey] = await House.createNativeReq(
+      program,
+      switchboardQueue,
+      liquidity
+    );
+
+    const signature = await switchboardQueue.program.signAndSend(initHouse, {
+      skipPreflight: true,
+    });
+
+    let retryCount = 5;
+    while (retryCount) {
+      const houseState = await HouseState.fetch(
+        {
+          connection: program.provider.connection,
+          programId: program.programId,
+        },
+        houseKey
+      );
+      if (houseState !== null) {
+        return new House(program, houseKey, houseState);
+      }
+      await sleep(1000);
+      --retryCount;
+    }
+
+    throw new Error(`Failed to create new HouseAccount`);
+  }
+
+  /** Creates a house that takes bets in native SOL via the wrapped SOL mint */
+  static async createNativeReq(
+    program: anchor.Program,
+    switchboardQueue: QueueAccount,
+    liquidity = new anchor.BN(0)
+  ): Promise<[TransactionObject, PublicKey]> {
+    const payer = switchboardQueue.program.walletPubkey;
 
-    let houseState = await HouseState.fetch(
+    const [houseKey, houseBump] = House.fromSeeds(
+      program.programId,
+      NATIVE_MINT
+    );
+
+    const [tokenVault] = PublicKey.findProgramAddressSync(
+      [
+        houseKey.toBuffer(),
+        TOKEN_PROGRAM_ID.toBuffer(),
+        NATIVE_MINT.toBuffer(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const initHouse = houseInitNative(
       {
-        connection: program.provider.connection,
         programId: program.programId,
       },
-      houseKey
+      { params: { liquidity } },
+      {
+        house: houseKey,
+        authority: payer,
+        switchboardMint: switchboardQueue.program.mint.address,
+        switchboardQueue: switchboardQueue.publicKey,
+        mint: NATIVE_MINT,
+        houseVault: tokenVault,
+        payer: payer,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      }
     );
-    if (houseState !== null) {
-      return new House(program, houseKey, houseState);
+
+    return [new TransactionObject(payer, [initHouse], []), houseKey];
+  }
+
+  static async load(
+    program: anchor.Program,
+    mint: PublicKey
+  ): Promise<House> {
+    const house = await House.fetchForMint(program, mint);
+    if (house !== null) {
+      return house;
     }
 
     throw new Error(`House account has not been created yet`);
@@ -174,14 +299,16 @@ export class House {
     switchboardQueue?: QueueAccount,
     mint?: Keypair
   ): Promise<House> {
-    try {
-      const house = await House.load(program);
-      return house;
-    } catch (error: any) {
-      if (
-        !error.toString().includes("House account has not been created yet")
-      ) {
-        throw error;
+    if (mint) {
+      try {
+        const house = await House.load(program, mint.publicKey);
+        return house;
+      } catch (error: any) {
+        if (
+          !error.toString().includes("House account has not been created yet")
+        ) {
+          throw error;
+        }
       }
     }
 
@@ -194,6 +321,36 @@ export class House {
     return House.create(program, switchboardQueue, mint ?? Keypair.generate());
   }
 
+  /** Creates the fee vault, needed before the house can sponsor VRF fees */
+  initFeeVaultReq(payer: PublicKey): TransactionInstruction {
+    const [feeVault] = House.feeVaultFromSeeds(
+      this.program.programId,
+      this.publicKey
+    );
+    return houseInitFeeVault(
+      { programId: this.program.programId },
+      { params: {} },
+      {
+        house: this.publicKey,
+        authority: this.state.authority,
+        switchboardMint: this.state.switchboardMint,
+        feeVault,
+        payer,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      }
+    );
+  }
+
+  updateReq(params: HouseUpdateParamsFields): TransactionInstruction {
+    return houseUpdate(
+      { programId: this.program.programId },
+      { params },
+      { house: this.publicKey, authority: this.state.authority }
+    );
+  }
+
   async loadMint(): Promise<Mint> {
     const mint = await Mint.load(
       this.program.provider as anchor.AnchorProvider,
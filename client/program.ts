@@ -16,7 +16,6 @@ import {
   DEFAULT_SEND_TRANSACTION_OPTIONS,
   TransactionOptions,
 } from "@switchboard-xyz/solana.js";
-import { HouseState } from "./generated/accounts";
 import { House } from "./house";
 
 export class FlipProgram {
@@ -70,43 +69,37 @@ export class FlipProgram {
     params?: {
       queuePubkey?: PublicKey;
       mintKeypair?: Keypair;
+      // the mint of an existing house
+      mint?: PublicKey;
     }
   ): Promise<FlipProgram> {
     const switchboard = await SwitchboardProgram.fromProvider(
       program.provider as anchor.AnchorProvider
     );
 
-    const [houseKey] = House.fromSeeds(program.programId);
-    const houseState = await HouseState.fetch(
-      {
-        connection: program.provider.connection,
-        programId: program.programId,
-      },
-      houseKey
-    );
-
-    // create the house if not created yet
-    if (houseState === null) {
-      if (!params?.queuePubkey) {
-        throw new Error(
-          `Must provide queuePubkey to create a new house account`
+    const houseMint = params?.mint ?? params?.mintKeypair?.publicKey;
+    if (houseMint) {
+      const house = await House.fetchForMint(program, houseMint);
+      if (house !== null) {
+        const mint = await house.loadMint();
+        const queueAccount = new QueueAccount(
+          switchboard,
+          house.state.switchboardQueue
         );
+        return new FlipProgram(program, house, mint, queueAccount);
       }
-      const queueAccount = new QueueAccount(switchboard, params.queuePubkey);
-      return await FlipProgram.init(
-        program,
-        queueAccount,
-        params?.mintKeypair ? params.mintKeypair : Keypair.generate()
-      );
-    } else {
-      const house = new House(program, houseKey, houseState);
-      const mint = await house.loadMint();
-      const queueAccount = new QueueAccount(
-        switchboard,
-        house.state.switchboardQueue
-      );
-      return new FlipProgram(program, house, mint, queueAccount);
     }
+
+    // create the house if not created yet
+    if (!params?.queuePubkey) {
+      throw new Error(`Must provide queuePubkey to create a new house account`);
+    }
+    const queueAccount = new QueueAccount(switchboard, params.queuePubkey);
+    return await FlipProgram.init(
+      program,
+      queueAccount,
+      params?.mintKeypair ? params.mintKeypair : Keypair.generate()
+    );
   }
 
   public async signAndSendAll(
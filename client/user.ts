@@ -18,12 +18,29 @@ import {
   VrfAccount,
 } from "@switchboard-xyz/solana.js";
 import { UserState, UserStateJSON } from "./generated/accounts";
-import { userAirdrop, userBet, userInit } from "./generated/instructions";
-import { House } from "./house";
+import {
+  userAirdrop,
+  userAutoplayBet,
+  userAutoplayStart,
+  userAutoplayStop,
+  userBet,
+  userClose,
+  userHistoryPageInit,
+  userInit,
+  userMigrate,
+  userSetDelegate,
+  userSetLimits,
+  userWithdrawEscrow,
+} from "./generated/instructions";
+import { UserSetLimitsParamsFields } from "./generated/types";
 import { FlipProgram } from "./program";
 import { convertGameType, GameTypeEnum, GameTypeValue } from "./types";
 import { verifyPayerBalance } from "./utils";
 
+export const USER_STATE_VERSION = 6;
+export const MAX_HISTORY = 48;
+export const HISTORY_PAGE_SIZE = 64;
+
 export interface UserBetPlaced {
   roundId: anchor.BN;
   user: PublicKey;
@@ -66,7 +83,6 @@ export class User {
   }
 
   static async load(program: FlipProgram, authority: PublicKey): Promise<User> {
-    const [houseKey] = House.fromSeeds(program.programId);
     const [userKey] = User.fromSeeds(program, authority);
     const userState = await UserState.fetch(program, userKey);
     if (!userState) {
@@ -107,6 +123,57 @@ export class User {
     );
   }
 
+  static historyPageFromSeeds(
+    program: FlipProgram,
+    user: PublicKey,
+    pageIndex: number
+  ): [PublicKey, number] {
+    const pageIndexBytes = Buffer.alloc(4);
+    pageIndexBytes.writeUInt32LE(pageIndex);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("HISTORYPAGESEED"), user.toBytes(), pageIndexBytes],
+      program.programId
+    );
+  }
+
+  // the native account a native house unwraps the user's payouts through
+  static unwrapFromSeeds(
+    program: FlipProgram,
+    user: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("UNWRAPSEED"), user.toBytes()],
+      program.programId
+    );
+  }
+
+  // the page the next bet archives a round into, once the history ring is full
+  historyPageForBet(): [PublicKey, number] | undefined {
+    const { currentRound, history } = this.state;
+    const idx = history.idx;
+    const newest = history.rounds[(idx + MAX_HISTORY - 1) % MAX_HISTORY];
+    const settleIdx = (idx + 1) % MAX_HISTORY;
+    // rounds are archived when they settle, or by the next bet otherwise
+    const currentRoundArchived =
+      currentRound.roundId.isZero() || newest.roundId.eq(currentRound.roundId);
+    const needsPage = currentRoundArchived
+      ? !history.rounds[idx].roundId.isZero()
+      : !history.rounds[idx].roundId.isZero() ||
+        !history.rounds[settleIdx].roundId.isZero();
+    if (!needsPage) {
+      return undefined;
+    }
+    const pageIndex = this.state.archivedRounds
+      .divn(HISTORY_PAGE_SIZE)
+      .toNumber();
+    const [historyPage] = User.historyPageFromSeeds(
+      this.program,
+      this.publicKey,
+      pageIndex
+    );
+    return [historyPage, pageIndex];
+  }
+
   async reload(): Promise<void> {
     const newState = await UserState.fetch(this.program, this.publicKey);
     if (newState === null) {
@@ -127,7 +194,8 @@ export class User {
     user: PublicKey,
     escrow: PublicKey,
     vrf: PublicKey,
-    rewardAddress: PublicKey
+    rewardAddress: PublicKey,
+    authority: PublicKey
   ): Promise<Callback> {
     const ixnCoder = new anchor.BorshInstructionCoder(program.idl);
     const callback: Callback = {
@@ -139,6 +207,8 @@ export class User {
           isWritable: true,
           isSigner: false,
         },
+        // the house stays read-only, the settled round is recorded in it by
+        // userRecordSettle, the next bet or userClose
         {
           pubkey: program.house.publicKey,
           isWritable: false,
@@ -169,6 +239,22 @@ export class User {
           isWritable: false,
           isSigner: false,
         },
+        // the optional history page is left empty
+        {
+          pubkey: program.programId,
+          isWritable: false,
+          isSigner: false,
+        },
+        {
+          pubkey: User.unwrapFromSeeds(program, user)[0],
+          isWritable: true,
+          isSigner: false,
+        },
+        {
+          pubkey: authority,
+          isWritable: true,
+          isSigner: false,
+        },
       ],
     };
     return callback;
@@ -224,7 +310,8 @@ export class User {
       userKey,
       escrowKeypair.publicKey,
       vrfSecret.publicKey,
-      rewardAddress
+      rewardAddress,
+      payerPubkey
     );
 
     const [vrfAccount, vrfInit] = await program.queue.createVrfInstructions(
@@ -313,6 +400,47 @@ export class User {
         }
       );
 
+    const ixns: Array<anchor.web3.TransactionInstruction> = [];
+    if (this.state.version < USER_STATE_VERSION) {
+      ixns.push(
+        userMigrate(
+          this.program,
+          { params: {} },
+          {
+            user: this.publicKey,
+            house: this.state.house,
+            authority: this.state.authority,
+            payer: payerPubkey,
+            systemProgram: SystemProgram.programId,
+          }
+        )
+      );
+    }
+
+    // once the history ring is full the oldest round moves to a history page
+    const historyPage = this.historyPageForBet();
+    if (historyPage) {
+      const [historyPageKey, pageIndex] = historyPage;
+      const pageInfo = await this.program.provider.connection.getAccountInfo(
+        historyPageKey
+      );
+      if (pageInfo === null) {
+        ixns.push(
+          userHistoryPageInit(
+            this.program,
+            { params: { pageIndex } },
+            {
+              historyPage: historyPageKey,
+              user: this.publicKey,
+              authority: this.state.authority,
+              payer: payerPubkey,
+              systemProgram: SystemProgram.programId,
+            }
+          )
+        );
+      }
+    }
+
     const betIxn = userBet(
       this.program,
       {
@@ -342,12 +470,160 @@ export class User {
         recentBlockhashes: SYSVAR_RECENT_BLOCKHASHES_PUBKEY,
         systemProgram: SystemProgram.programId,
         tokenProgram: spl.TOKEN_PROGRAM_ID,
+        // optional accounts are passed as the program id when unused
+        historyPage: historyPage ? historyPage[0] : this.program.programId,
+        // only used by native houses, to unwrap the payout when it settles
+        unwrapAccount: User.unwrapFromSeeds(this.program, this.publicKey)[0],
+        nativeMint: spl.NATIVE_MINT,
       }
     );
+    ixns.push(betIxn);
 
     return wrapTxn
-      ? wrapTxn.add(betIxn)
-      : new TransactionObject(payerPubkey, [betIxn], []);
+      ? wrapTxn.add(ixns)
+      : new TransactionObject(payerPubkey, ixns, []);
+  }
+
+  autoplayStartReq(
+    gameType: GameTypeValue,
+    userGuess: number,
+    betAmount: anchor.BN,
+    numBets: number,
+    stopLoss = new anchor.BN(0),
+    takeProfit = new anchor.BN(0)
+  ): TransactionObject {
+    const startIxn = userAutoplayStart(
+      this.program,
+      {
+        params: {
+          gameType,
+          userGuess,
+          betAmount,
+          numBets,
+          stopLoss,
+          takeProfit,
+        },
+      },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        houseVault: this.program.house.state.houseVault,
+        authority: this.state.authority,
+        escrow: this.state.escrow,
+        flipPayer: this.state.rewardAddress,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        unwrapAccount: User.unwrapFromSeeds(this.program, this.publicKey)[0],
+        nativeMint: spl.NATIVE_MINT,
+        systemProgram: SystemProgram.programId,
+      }
+    );
+    return new TransactionObject(this.state.authority, [startIxn], []);
+  }
+
+  async autoplayStart(
+    gameType: GameTypeValue,
+    userGuess: number,
+    betAmount: anchor.BN,
+    numBets: number,
+    stopLoss = new anchor.BN(0),
+    takeProfit = new anchor.BN(0)
+  ): Promise<TransactionSignature> {
+    const signature = await this.program.signAndSend(
+      this.autoplayStartReq(
+        gameType,
+        userGuess,
+        betAmount,
+        numBets,
+        stopLoss,
+        takeProfit
+      )
+    );
+    return signature;
+  }
+
+  // any keeper can place the next autoplay bet, it only pays for randomness
+  async autoplayBetReq(
+    keeperPubkey = this.program.payerPubkey
+  ): Promise<TransactionObject> {
+    const vrfAccount = new VrfAccount(this.program.switchboard, this.state.vrf);
+    const vrfAccounts = await vrfAccount.fetchAccounts();
+
+    const ixns: Array<anchor.web3.TransactionInstruction> = [];
+    const historyPage = this.historyPageForBet();
+    if (historyPage) {
+      const [historyPageKey, pageIndex] = historyPage;
+      const pageInfo = await this.program.provider.connection.getAccountInfo(
+        historyPageKey
+      );
+      if (pageInfo === null) {
+        // only the user's authority can open history pages
+        if (!keeperPubkey.equals(this.state.authority)) {
+          throw new Error(
+            `history page ${historyPageKey.toBase58()} has to be created by the user's authority`
+          );
+        }
+        ixns.push(
+          userHistoryPageInit(
+            this.program,
+            { params: { pageIndex } },
+            {
+              historyPage: historyPageKey,
+              user: this.publicKey,
+              authority: this.state.authority,
+              payer: keeperPubkey,
+              systemProgram: SystemProgram.programId,
+            }
+          )
+        );
+      }
+    }
+
+    const betIxn = userAutoplayBet(
+      this.program,
+      { params: {} },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        houseVault: this.program.house.state.houseVault,
+        authority: this.state.authority,
+        escrow: this.state.escrow,
+        vrf: vrfAccount.publicKey,
+        oracleQueue: vrfAccounts.queue.publicKey,
+        queueAuthority: vrfAccounts.queue.data.authority,
+        dataBuffer: vrfAccounts.queue.data.dataBuffer,
+        permission: vrfAccounts.permission.publicKey,
+        vrfEscrow: vrfAccounts.escrow.publicKey,
+        switchboardProgramState: vrfAccount.program.programState.publicKey,
+        switchboardProgram: vrfAccount.program.programId,
+        // the house fee vault pays for autoplay randomness
+        vrfPayer: this.program.house.state.feeVault,
+        recentBlockhashes: SYSVAR_RECENT_BLOCKHASHES_PUBKEY,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        // optional accounts are passed as the program id when unused
+        historyPage: historyPage ? historyPage[0] : this.program.programId,
+      }
+    );
+    ixns.push(betIxn);
+
+    return new TransactionObject(keeperPubkey, ixns, []);
+  }
+
+  autoplayStopReq(): TransactionObject {
+    const stopIxn = userAutoplayStop(
+      this.program,
+      { params: {} },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        authority: this.state.authority,
+      }
+    );
+    return new TransactionObject(this.state.authority, [stopIxn], []);
+  }
+
+  async autoplayStop(): Promise<TransactionSignature> {
+    const signature = await this.program.signAndSend(this.autoplayStopReq());
+    return signature;
   }
 
   async awaitFlip(
@@ -468,6 +744,131 @@ export class User {
     return signature;
   }
 
+  // the escrow has to be empty and the current round settled
+  setDelegateReq(
+    delegate: PublicKey,
+    expirySlot: anchor.BN,
+    allowance: anchor.BN
+  ): TransactionObject {
+    const setDelegateIxn = userSetDelegate(
+      this.program,
+      { params: { delegate, expirySlot, allowance } },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        authority: this.state.authority,
+        // the allowance is approved to the user PDA, delegate bets are paid from it
+        rewardAddress: this.state.rewardAddress,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+      }
+    );
+    return new TransactionObject(this.state.authority, [setDelegateIxn], []);
+  }
+
+  async setDelegate(
+    delegate: PublicKey,
+    expirySlot: anchor.BN,
+    allowance: anchor.BN
+  ): Promise<TransactionSignature> {
+    const signature = await this.program.signAndSend(
+      this.setDelegateReq(delegate, expirySlot, allowance)
+    );
+    return signature;
+  }
+
+  async revokeDelegate(): Promise<TransactionSignature> {
+    return this.setDelegate(
+      PublicKey.default,
+      new anchor.BN(0),
+      new anchor.BN(0)
+    );
+  }
+
+  setLimitsReq(limits: Partial<UserSetLimitsParamsFields>): TransactionObject {
+    const setLimitsIxn = userSetLimits(
+      this.program,
+      {
+        params: {
+          dailyWagerLimit: limits.dailyWagerLimit ?? null,
+          weeklyWagerLimit: limits.weeklyWagerLimit ?? null,
+          dailyLossLimit: limits.dailyLossLimit ?? null,
+          cooldown: limits.cooldown ?? null,
+          selfExcludedUntil: limits.selfExcludedUntil ?? null,
+        },
+      },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        authority: this.state.authority,
+      }
+    );
+    return new TransactionObject(this.state.authority, [setLimitsIxn], []);
+  }
+
+  async setLimits(
+    limits: Partial<UserSetLimitsParamsFields>
+  ): Promise<TransactionSignature> {
+    const signature = await this.program.signAndSend(this.setLimitsReq(limits));
+    return signature;
+  }
+
+  // filled history pages have to be closed first, the page still being
+  // written to closes with the user
+  closeReq(): TransactionObject {
+    const pageIndex = this.state.archivedRounds
+      .divn(HISTORY_PAGE_SIZE)
+      .toNumber();
+    const [historyPage] = User.historyPageFromSeeds(
+      this.program,
+      this.publicKey,
+      pageIndex
+    );
+    const closeIxn = userClose(
+      this.program,
+      { params: {} },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        authority: this.state.authority,
+        escrow: this.state.escrow,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        historyPage:
+          this.state.historyPages === 0 ? this.program.programId : historyPage,
+        unwrapAccount: User.unwrapFromSeeds(this.program, this.publicKey)[0],
+      }
+    );
+    return new TransactionObject(this.state.authority, [closeIxn], []);
+  }
+
+  withdrawEscrowReq(): TransactionObject {
+    const withdrawIxn = userWithdrawEscrow(
+      this.program,
+      { params: {} },
+      {
+        user: this.publicKey,
+        house: this.state.house,
+        authority: this.state.authority,
+        escrow: this.state.escrow,
+        rewardAddress: this.state.rewardAddress,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        unwrapAccount: User.unwrapFromSeeds(this.program, this.publicKey)[0],
+        nativeMint: spl.NATIVE_MINT,
+        systemProgram: SystemProgram.programId,
+      }
+    );
+    return new TransactionObject(this.state.authority, [withdrawIxn], []);
+  }
+
+  async withdrawEscrow(): Promise<TransactionSignature> {
+    const signature = await this.program.signAndSend(this.withdrawEscrowReq());
+    return signature;
+  }
+
+  async close(): Promise<TransactionSignature> {
+    const signature = await this.program.signAndSend(this.closeReq());
+    return signature;
+  }
+
   watch(
     betPlaced: (event: UserBetPlaced) => Promise<void> | void,
     betSettled: (event: UserBetSettled) => Promise<void> | void
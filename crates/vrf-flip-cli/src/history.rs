@@ -0,0 +1,106 @@
+use serde_json::{json, Value};
+use vrf_flip_client::{GameType, History, Round, RoundStatus};
+
+/// Rounds stored in the user's history, oldest first
+pub fn rounds(history: &History) -> Vec<Round> {
+    history.iter_recent().rev().copied().collect()
+}
+
+fn game_name(game_type: GameType) -> &'static str {
+    match game_type {
+        GameType::None => "none",
+        GameType::CoinFlip => "coin-flip",
+        GameType::SixSidedDiceRoll => "dice-roll",
+        GameType::TwentySidedDiceRoll => "twenty-sided-dice-roll",
+    }
+}
+
+fn status_name(status: RoundStatus) -> &'static str {
+    match status {
+        RoundStatus::None => "none",
+        RoundStatus::Awaiting => "awaiting",
+        RoundStatus::Settled => "settled",
+    }
+}
+
+fn outcome(round: &Round) -> &'static str {
+    let (status, guess, result) = (round.status, round.guess, round.result);
+    match status {
+        RoundStatus::Settled if guess == result => "won",
+        RoundStatus::Settled => "lost",
+        _ => "-",
+    }
+}
+
+pub fn round_summary(round: &Round) -> String {
+    let (round_id, game_type, guess, result) =
+        (round.round_id, round.game_type, round.guess, round.result);
+    format!(
+        "Round {}: {} guess {} result {} ({})",
+        round_id,
+        game_name(game_type),
+        guess,
+        result,
+        outcome(round)
+    )
+}
+
+pub fn to_json(rounds: &[Round]) -> Value {
+    Value::Array(
+        rounds
+            .iter()
+            .map(|round| {
+                let round = *round;
+                let (round_id, bet_amount, guess, result) =
+                    (round.round_id, round.bet_amount, round.guess, round.result);
+                let (request_slot, request_timestamp, settle_slot, settle_timestamp) = (
+                    round.request_slot,
+                    round.request_timestamp,
+                    round.settle_slot,
+                    round.settle_timestamp,
+                );
+                json!({
+                    "roundId": round_id.to_string(),
+                    "status": status_name(round.status),
+                    "gameType": game_name(round.game_type),
+                    "betAmount": bet_amount,
+                    "guess": guess,
+                    "result": result,
+                    "outcome": outcome(&round),
+                    "requestSlot": request_slot,
+                    "requestTimestamp": request_timestamp,
+                    "settleSlot": settle_slot,
+                    "settleTimestamp": settle_timestamp,
+                })
+            })
+            .collect(),
+    )
+}
+
+pub fn print_table(rounds: &[Round]) {
+    println!(
+        "{:>8}  {:<22}  {:<8}  {:>16}  {:>5}  {:>6}  {:<7}  {:>12}",
+        "ROUND", "GAME", "STATUS", "BET", "GUESS", "RESULT", "OUTCOME", "SETTLE SLOT"
+    );
+    for round in rounds {
+        let round = *round;
+        let (round_id, bet_amount, guess, result, settle_slot) = (
+            round.round_id,
+            round.bet_amount,
+            round.guess,
+            round.result,
+            round.settle_slot,
+        );
+        println!(
+            "{:>8}  {:<22}  {:<8}  {:>16}  {:>5}  {:>6}  {:<7}  {:>12}",
+            round_id,
+            game_name(round.game_type),
+            status_name(round.status),
+            bet_amount,
+            guess,
+            result,
+            outcome(&round),
+            settle_slot
+        );
+    }
+}
@@ -0,0 +1,706 @@
+//! Rust counterpart to `cli.ts` for operators and minimal containers.
+//!
+//! ```bash
+//! vrf-flip-cli --cluster devnet house-init --queue <QUEUE>
+//! vrf-flip-cli --house-mint <MINT> user-init --vrf <VRF>
+//! vrf-flip-cli --house-mint <MINT> bet --game coin-flip --guess 2 --amount 1000000000
+//! vrf-flip-cli --house-mint <MINT> history --json
+//! ```
+mod history;
+
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+use anyhow::{anyhow, Context};
+use clap::{Parser, Subcommand, ValueEnum};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use vrf_flip_client::{
+    switchboard_vrf_flip::{
+        GameBetLimitsParams, HouseUpdateParams, UserAutoplayStartParams, UserBetParams,
+        UserSetLimitsParams,
+    },
+    *,
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Cluster {
+    Devnet,
+    MainnetBeta,
+    Localnet,
+}
+
+impl Cluster {
+    fn rpc_url(self) -> &'static str {
+        match self {
+            Cluster::Devnet => "https://api.devnet.solana.com",
+            Cluster::MainnetBeta => "https://api.mainnet-beta.solana.com",
+            Cluster::Localnet => "http://localhost:8899",
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Game {
+    CoinFlip,
+    DiceRoll,
+    TwentySidedDiceRoll,
+}
+
+impl Game {
+    fn game_type(self) -> GameType {
+        match self {
+            Game::CoinFlip => GameType::CoinFlip,
+            Game::DiceRoll => GameType::SixSidedDiceRoll,
+            Game::TwentySidedDiceRoll => GameType::TwentySidedDiceRoll,
+        }
+    }
+}
+
+#[derive(Parser)]
+#[command(
+    name = "vrf-flip-cli",
+    about = "Operate the Switchboard VRF Flip program"
+)]
+struct Cli {
+    /// Solana cluster to interact with
+    #[arg(long, short, value_enum, default_value = "devnet", global = true)]
+    cluster: Cluster,
+    /// Alternative RPC URL
+    #[arg(long, short = 'u', global = true)]
+    rpc_url: Option<String>,
+    /// Filesystem path to the Solana keypair that signs and pays
+    #[arg(long, short, default_value = "~/.config/solana/id.json", global = true)]
+    keypair: String,
+    /// Mint of the house to operate on, each mint has its own house. A legacy house
+    /// seeded without its mint is found by its mint too.
+    #[arg(long, global = true)]
+    house_mint: Option<Pubkey>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initiate the house with the keypair as its authority
+    HouseInit {
+        /// Oracle queue to target for VRF requests
+        #[arg(long, default_value = "uPeRMdfPmrPqgRWSrjAnAkH78RqAhe5kXoW6vBYRqFX")]
+        queue: Pubkey,
+        /// Keypair file for the house mint, a new mint is generated if omitted
+        #[arg(long)]
+        mint_keypair: Option<String>,
+    },
+    /// Initiate a house that takes bets in native SOL with the keypair as its authority
+    HouseInitNative {
+        /// Oracle queue to target for VRF requests
+        #[arg(long, default_value = "uPeRMdfPmrPqgRWSrjAnAkH78RqAhe5kXoW6vBYRqFX")]
+        queue: Pubkey,
+        /// Lamports to wrap into the house vault
+        #[arg(long, default_value_t = 0)]
+        liquidity: u64,
+    },
+    /// Create the fee vault the house uses to sponsor VRF requests
+    HouseInitFeeVault,
+    /// Update the house settings
+    HouseUpdate {
+        /// Whether the house fee vault pays for user VRF requests
+        #[arg(long)]
+        sponsor_vrf_fees: Option<bool>,
+        /// Fee in house tokens charged for a sponsored VRF request
+        #[arg(long)]
+        vrf_fee: Option<u64>,
+        /// Game to set the bet limits for
+        #[arg(long, short, value_enum)]
+        game: Option<Game>,
+        /// Smallest bet the game accepts
+        #[arg(long, default_value_t = 0, requires = "game")]
+        min_bet: u64,
+        /// Largest bet the game accepts, 0 uses the program default
+        #[arg(long, default_value_t = 0, requires = "game")]
+        max_bet: u64,
+        /// Largest payout as basis points of the house vault, 0 limits bets to a tenth of the vault
+        #[arg(long, default_value_t = 0, requires = "game")]
+        max_payout_bps: u32,
+        /// Cap on the payouts open rounds can owe, as basis points of the house vault
+        #[arg(long)]
+        max_liability_bps: Option<u32>,
+    },
+    /// Create a user account for an existing VRF owned by the user PDA
+    UserInit {
+        /// VRF account with the user PDA as its authority
+        #[arg(long)]
+        vrf: Pubkey,
+    },
+    /// Place a bet and request randomness for the round
+    Bet {
+        #[arg(long, short, value_enum)]
+        game: Game,
+        /// Guess for the game's outcome
+        #[arg(long, short)]
+        guess: u32,
+        /// Number of house tokens to wager
+        #[arg(long, short, default_value_t = 0)]
+        amount: u64,
+        /// Have the house fee vault pay for the VRF request
+        #[arg(long)]
+        sponsored: bool,
+        /// Bet for this user authority as its delegate, defaults to the keypair
+        #[arg(long)]
+        authority: Option<Pubkey>,
+    },
+    /// Settle the current round once the VRF result is available
+    Settle {
+        /// User authority, defaults to the keypair
+        #[arg(long)]
+        authority: Option<Pubkey>,
+    },
+    /// Request an airdrop of house tokens
+    Airdrop,
+    /// Fund a session of bets a keeper places with autoplay-bet
+    AutoplayStart {
+        #[arg(long, short, value_enum)]
+        game: Game,
+        /// Guess for every round's outcome
+        #[arg(long, short)]
+        guess: u32,
+        /// Number of house tokens to wager each round
+        #[arg(long, short)]
+        amount: u64,
+        /// Number of bets in the session
+        #[arg(long)]
+        bets: u32,
+        /// Stop once the session has lost this many tokens, 0 disables it
+        #[arg(long, default_value_t = 0)]
+        stop_loss: u64,
+        /// Stop once the session has won this many tokens, 0 disables it
+        #[arg(long, default_value_t = 0)]
+        take_profit: u64,
+    },
+    /// Place the next bet of a user's autoplay session as a keeper
+    AutoplayBet {
+        /// User authority, defaults to the keypair
+        #[arg(long)]
+        authority: Option<Pubkey>,
+    },
+    /// Stop the user's autoplay session
+    AutoplayStop,
+    /// Withdraw escrowed tokens that aren't backing an unsettled bet
+    WithdrawEscrow,
+    /// Let another key sign bets for the user, up to an allowance
+    SetDelegate {
+        /// Delegate to sign bets, the default pubkey revokes the current one
+        #[arg(long)]
+        delegate: Pubkey,
+        /// Last slot the delegate can sign bets in
+        #[arg(long)]
+        expiry_slot: u64,
+        /// Number of house tokens the delegate can spend from the reward address
+        #[arg(long)]
+        allowance: u64,
+    },
+    /// Set the user's wager, loss and cooldown limits, 0 removes a limit. Loosening
+    /// a limit only applies after a day
+    SetLimits {
+        /// Number of house tokens the user can wager per day
+        #[arg(long)]
+        daily_wager: Option<u64>,
+        /// Number of house tokens the user can wager per week
+        #[arg(long)]
+        weekly_wager: Option<u64>,
+        /// Number of house tokens the user can lose per day
+        #[arg(long)]
+        daily_loss: Option<u64>,
+        /// Seconds to wait between bets
+        #[arg(long)]
+        cooldown: Option<u32>,
+        /// Block bets until this unix timestamp, it can't be shortened once set
+        #[arg(long)]
+        self_exclude_until: Option<i64>,
+    },
+    /// Close the user account and escrow, returning their rent
+    UserClose,
+    /// Print the user's round history
+    History {
+        /// User authority, defaults to the keypair
+        #[arg(long)]
+        authority: Option<Pubkey>,
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Recompute a settled round's result from its randomness
+    Verify {
+        #[arg(long)]
+        round: u128,
+        /// User authority, defaults to the keypair
+        #[arg(long)]
+        authority: Option<Pubkey>,
+    },
+}
+
+fn load_keypair(path: &str) -> anyhow::Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{}", std::env::var("HOME")?, rest),
+        None => path.to_string(),
+    };
+    read_keypair_file(&path).map_err(|e| anyhow!("failed to read keypair {}: {}", path, e))
+}
+
+fn send(
+    rpc: &RpcClient,
+    payer: &Keypair,
+    ixs: &[Instruction],
+    signers: &[&Keypair],
+) -> anyhow::Result<Signature> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&payer.pubkey()),
+        &all_signers,
+        rpc.get_latest_blockhash()?,
+    );
+    Ok(rpc.send_and_confirm_transaction(&tx)?)
+}
+
+// opens the history page a bet or settle needs if it's missing, which only the user's
+// authority can do
+fn history_page_init_if_missing(
+    rpc: &RpcClient,
+    user: &UserAccounts,
+    payer: &Keypair,
+    history_page: Option<(Pubkey, u32)>,
+) -> anyhow::Result<Option<Instruction>> {
+    let (history_page, page_index) = match history_page {
+        Some(history_page) => history_page,
+        None => return Ok(None),
+    };
+    if rpc.get_account(&history_page).is_ok() {
+        return Ok(None);
+    }
+    if user.authority != payer.pubkey() {
+        return Err(anyhow!(
+            "history page {} has to be created by the user's authority {}",
+            history_page,
+            user.authority
+        ));
+    }
+    Ok(Some(user_history_page_init(
+        user,
+        &payer.pubkey(),
+        page_index,
+    )))
+}
+
+fn user_address(house: &Pubkey, authority: Option<Pubkey>, payer: &Keypair) -> Pubkey {
+    find_user_address(house, &authority.unwrap_or_else(|| payer.pubkey())).0
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let rpc_url = cli
+        .rpc_url
+        .clone()
+        .unwrap_or_else(|| cli.cluster.rpc_url().to_string());
+    let rpc = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+    let payer = load_keypair(&cli.keypair)?;
+    // the init commands derive the house from the mint they create it for
+    let house = match (&cli.command, cli.house_mint) {
+        (Command::HouseInit { .. } | Command::HouseInitNative { .. }, _) => Pubkey::default(),
+        (_, Some(mint)) => fetch_house_for_mint(&rpc, &mint)?.0,
+        (_, None) => return Err(anyhow!("--house-mint is required to select the house")),
+    };
+
+    match cli.command {
+        Command::HouseInit {
+            queue,
+            mint_keypair,
+        } => {
+            let mint = match mint_keypair {
+                Some(path) => load_keypair(&path)?,
+                None => Keypair::new(),
+            };
+            let queue_state = fetch_oracle_queue(&rpc, &queue)?;
+            let switchboard_mint = if queue_state.mint == Pubkey::default() {
+                spl_token::native_mint::ID
+            } else {
+                queue_state.mint
+            };
+
+            let (house, _) = find_house_address(&mint.pubkey());
+            let ix = house_init(
+                &payer.pubkey(),
+                &payer.pubkey(),
+                &mint.pubkey(),
+                &queue,
+                &switchboard_mint,
+            );
+            let signature = send(&rpc, &payer, &[ix], &[&mint])?;
+            println!("House {} created: {}", house, signature);
+        }
+        Command::HouseInitNative { queue, liquidity } => {
+            let queue_state = fetch_oracle_queue(&rpc, &queue)?;
+            let switchboard_mint = if queue_state.mint == Pubkey::default() {
+                spl_token::native_mint::ID
+            } else {
+                queue_state.mint
+            };
+
+            let (house, _) = find_house_address(&spl_token::native_mint::ID);
+            let ix = house_init_native(
+                &payer.pubkey(),
+                &payer.pubkey(),
+                &queue,
+                &switchboard_mint,
+                liquidity,
+            );
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!("House {} created: {}", house, signature);
+        }
+        Command::HouseInitFeeVault => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let ix = house_init_fee_vault(
+                &house,
+                &payer.pubkey(),
+                &payer.pubkey(),
+                &house_state.switchboard_mint,
+            );
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!(
+                "Fee vault {} created: {}",
+                find_fee_vault_address(&house).0,
+                signature
+            );
+        }
+        Command::HouseUpdate {
+            sponsor_vrf_fees,
+            vrf_fee,
+            game,
+            min_bet,
+            max_bet,
+            max_payout_bps,
+            max_liability_bps,
+        } => {
+            let ix = house_update(
+                &house,
+                &payer.pubkey(),
+                HouseUpdateParams {
+                    sponsor_vrf_fees,
+                    vrf_fee,
+                    bet_limits: game.map(|game| GameBetLimitsParams {
+                        game_type: game.game_type() as u32,
+                        min_bet,
+                        max_bet,
+                        max_payout_bps,
+                    }),
+                    max_liability_bps,
+                },
+            );
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!("House {} updated: {}", house, signature);
+        }
+        Command::UserInit { vrf } => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let switchboard = fetch_switchboard_accounts(&rpc, &vrf)
+                .context("the VRF must exist with the user PDA as its authority")?;
+            let escrow = Keypair::new();
+
+            let ix = user_init(
+                &payer.pubkey(),
+                &payer.pubkey(),
+                &escrow.pubkey(),
+                &vrf,
+                &house,
+                &house_state.mint,
+                switchboard.program_state_bump,
+                switchboard.permission_bump,
+            );
+            let signature = send(&rpc, &payer, &[ix], &[&escrow])?;
+            println!(
+                "User {} created: {}",
+                user_address(&house, None, &payer),
+                signature
+            );
+        }
+        Command::Bet {
+            game,
+            guess,
+            amount,
+            sponsored,
+            authority,
+        } => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let user = user_address(&house, authority, &payer);
+            let (user_accounts, switchboard) = fetch_user_accounts(&rpc, &user)?;
+            let vrf_payer = if sponsored {
+                house_state.fee_vault
+            } else {
+                get_associated_token_address(&payer.pubkey(), &house_state.switchboard_mint)
+            };
+
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let mut ixs = Vec::new();
+            if user_state.version < USER_STATE_VERSION {
+                ixs.push(user_migrate(&user_accounts, &payer.pubkey()));
+            }
+
+            // once the history ring is full the oldest round moves to a history page
+            let history_page = history_page_for_bet(&user, &user_state);
+            ixs.extend(history_page_init_if_missing(
+                &rpc,
+                &user_accounts,
+                &payer,
+                history_page,
+            )?);
+
+            let history_page = history_page.map(|(history_page, _)| history_page);
+            let params = UserBetParams {
+                game_type: game.game_type() as u32,
+                user_guess: guess,
+                bet_amount: amount,
+            };
+            if user_accounts.authority == payer.pubkey() {
+                ixs.push(user_bet(
+                    &user_accounts,
+                    &house_state.house_vault,
+                    &switchboard,
+                    &payer.pubkey(),
+                    &vrf_payer,
+                    history_page,
+                    params,
+                ));
+            } else {
+                let delegate = DelegateAccounts {
+                    delegate: payer.pubkey(),
+                    vrf_payer,
+                };
+                ixs.push(user_bet_delegate(
+                    &user_accounts,
+                    &house_state.house_vault,
+                    &switchboard,
+                    &delegate,
+                    history_page,
+                    params,
+                ));
+            }
+            let signature = send(&rpc, &payer, &ixs, &[])?;
+            let round_id = fetch_user_state(&rpc, &user)?.current_round.round_id;
+            println!("Round {} placed: {}", round_id, signature);
+        }
+        Command::Settle { authority } => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let user = user_address(&house, authority, &payer);
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let user_accounts = UserAccounts::from_state(&user, &user_state);
+
+            let mut ixs = Vec::new();
+            let history_page = history_page_for_settle(&user, &user_state);
+            ixs.extend(history_page_init_if_missing(
+                &rpc,
+                &user_accounts,
+                &payer,
+                history_page,
+            )?);
+
+            ixs.push(user_settle(
+                &user_accounts,
+                &house_state.house_vault,
+                history_page.map(|(history_page, _)| history_page),
+            ));
+            let signature = send(&rpc, &payer, &ixs, &[])?;
+            let round = fetch_user_state(&rpc, &user)?.current_round;
+            println!("{}", history::round_summary(&round));
+            println!("Settled: {}", signature);
+        }
+        Command::Airdrop => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let user = user_address(&house, None, &payer);
+            let user_accounts = UserAccounts::from_state(&user, &fetch_user_state(&rpc, &user)?);
+
+            let ix = user_airdrop(&user_accounts, &house_state.house_vault, &house_state.mint);
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            let wallet = get_associated_token_address(&payer.pubkey(), &house_state.mint);
+            let balance = rpc.get_token_account_balance(&wallet)?;
+            println!("Airdrop requested: {}", signature);
+            println!("Balance: {}", balance.ui_amount_string);
+        }
+        Command::AutoplayStart {
+            game,
+            guess,
+            amount,
+            bets,
+            stop_loss,
+            take_profit,
+        } => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let user = user_address(&house, None, &payer);
+            let user_accounts = UserAccounts::from_state(&user, &fetch_user_state(&rpc, &user)?);
+
+            let ix = user_autoplay_start(
+                &user_accounts,
+                &house_state.house_vault,
+                UserAutoplayStartParams {
+                    game_type: game.game_type() as u32,
+                    user_guess: guess,
+                    bet_amount: amount,
+                    num_bets: bets,
+                    stop_loss,
+                    take_profit,
+                },
+            );
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!("Autoplay started: {}", signature);
+        }
+        Command::AutoplayBet { authority } => {
+            let house_state = fetch_house_state(&rpc, &house)?;
+            let user = user_address(&house, authority, &payer);
+            let (user_accounts, switchboard) = fetch_user_accounts(&rpc, &user)?;
+
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let mut ixs = Vec::new();
+            let history_page = history_page_for_bet(&user, &user_state);
+            ixs.extend(history_page_init_if_missing(
+                &rpc,
+                &user_accounts,
+                &payer,
+                history_page,
+            )?);
+
+            ixs.push(user_autoplay_bet(
+                &user_accounts,
+                &house_state.house_vault,
+                &house_state.fee_vault,
+                &switchboard,
+                history_page.map(|(history_page, _)| history_page),
+            ));
+            let signature = send(&rpc, &payer, &ixs, &[])?;
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let round_id = user_state.current_round.round_id;
+            let remaining_bets = user_state.autoplay.remaining_bets;
+            println!(
+                "Round {} placed, {} bets left: {}",
+                round_id, remaining_bets, signature
+            );
+        }
+        Command::AutoplayStop => {
+            let user = user_address(&house, None, &payer);
+            let user_accounts = UserAccounts::from_state(&user, &fetch_user_state(&rpc, &user)?);
+
+            let ix = user_autoplay_stop(&user_accounts);
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!("Autoplay stopped: {}", signature);
+        }
+        Command::WithdrawEscrow => {
+            let user = user_address(&house, None, &payer);
+            let user_accounts = UserAccounts::from_state(&user, &fetch_user_state(&rpc, &user)?);
+
+            let ix = user_withdraw_escrow(&user_accounts);
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            let balance = rpc.get_token_account_balance(&user_accounts.reward_address)?;
+            println!("Escrow withdrawn: {}", signature);
+            println!("Balance: {}", balance.ui_amount_string);
+        }
+        Command::SetDelegate {
+            delegate,
+            expiry_slot,
+            allowance,
+        } => {
+            let user = user_address(&house, None, &payer);
+            let user_accounts = UserAccounts::from_state(&user, &fetch_user_state(&rpc, &user)?);
+
+            let ix = user_set_delegate(&user_accounts, &delegate, expiry_slot, allowance);
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!("Delegate set: {}", signature);
+        }
+        Command::SetLimits {
+            daily_wager,
+            weekly_wager,
+            daily_loss,
+            cooldown,
+            self_exclude_until,
+        } => {
+            let user = user_address(&house, None, &payer);
+            let user_accounts = UserAccounts::from_state(&user, &fetch_user_state(&rpc, &user)?);
+
+            let ix = user_set_limits(
+                &user_accounts,
+                UserSetLimitsParams {
+                    daily_wager_limit: daily_wager,
+                    weekly_wager_limit: weekly_wager,
+                    daily_loss_limit: daily_loss,
+                    cooldown,
+                    self_excluded_until: self_exclude_until,
+                },
+            );
+            let signature = send(&rpc, &payer, &[ix], &[])?;
+            println!("Limits set: {}", signature);
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let effective_at = user_state.limits.pending.effective_at;
+            if effective_at != 0 {
+                println!("Loosened limits apply at unix timestamp {}", effective_at);
+            }
+        }
+        Command::UserClose => {
+            let user = user_address(&house, None, &payer);
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let user_accounts = UserAccounts::from_state(&user, &user_state);
+
+            // filled pages that are still open have to be closed before the user
+            let mut ixs = Vec::new();
+            for page_index in 0..user_state.history_page_index() {
+                let history_page = find_history_page_address(&user, page_index).0;
+                if rpc.get_account(&history_page).is_ok() {
+                    ixs.push(user_history_page_close(&user_accounts, page_index));
+                }
+            }
+
+            ixs.push(user_close(
+                &user_accounts,
+                history_page_for_close(&user, &user_state),
+            ));
+            let signature = send(&rpc, &payer, &ixs, &[])?;
+            println!("User {} closed: {}", user, signature);
+        }
+        Command::History { authority, json } => {
+            let user = user_address(&house, authority, &payer);
+            let user_state = fetch_user_state(&rpc, &user)?;
+            let rounds = history::rounds(&user_state.history);
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&history::to_json(&rounds))?
+                );
+            } else {
+                history::print_table(&rounds);
+            }
+        }
+        Command::Verify { round, authority } => {
+            let user = user_address(&house, authority, &payer);
+            let verification = fetch_round_verification(&rpc, &user, round)?;
+            println!("Round {}", verification.round_id);
+            println!("Randomness source: {:?}", verification.source);
+            println!(
+                "Randomness: {}",
+                verification
+                    .randomness
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<String>()
+            );
+            println!("Guess: {}", verification.guess);
+            println!("Expected result: {}", verification.expected_result);
+            println!("Recorded result: {}", verification.recorded_result);
+            if !verification.is_match() {
+                return Err(anyhow!("round {} does not match its randomness", round));
+            }
+            println!("Match");
+        }
+    }
+
+    Ok(())
+}
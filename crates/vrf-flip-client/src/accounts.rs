@@ -0,0 +1,40 @@
+use crate::*;
+use anchor_lang::{error::ErrorCode, AccountDeserialize, Discriminator};
+use switchboard_v2::{OracleQueueAccountData, VrfAccountData};
+
+pub fn decode_house_state(data: &[u8]) -> Result<HouseState> {
+    Ok(HouseState::try_deserialize(&mut &data[..])?)
+}
+
+pub fn decode_user_state(data: &[u8]) -> Result<UserState> {
+    Ok(UserState::try_deserialize(&mut &data[..])?)
+}
+
+pub fn decode_history(data: &[u8]) -> Result<History> {
+    Ok(decode_user_state(data)?.history)
+}
+
+pub fn decode_history_page(data: &[u8]) -> Result<UserHistoryPage> {
+    Ok(UserHistoryPage::try_deserialize(&mut &data[..])?)
+}
+
+pub fn decode_vrf(data: &[u8]) -> Result<VrfAccountData> {
+    decode_switchboard_account(data)
+}
+
+pub fn decode_oracle_queue(data: &[u8]) -> Result<OracleQueueAccountData> {
+    decode_switchboard_account(data)
+}
+
+fn decode_switchboard_account<T: Discriminator + bytemuck::Pod>(data: &[u8]) -> Result<T> {
+    let size = std::mem::size_of::<T>();
+    if data.len() < 8 + size {
+        return Err(anchor_lang::error::Error::from(ErrorCode::AccountDidNotDeserialize).into());
+    }
+    if data[..8] != T::discriminator() {
+        return Err(
+            anchor_lang::error::Error::from(ErrorCode::AccountDiscriminatorMismatch).into(),
+        );
+    }
+    Ok(bytemuck::pod_read_unaligned(&data[8..8 + size]))
+}
@@ -0,0 +1,29 @@
+use solana_program::pubkey::Pubkey;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("account {0} does not exist")]
+    AccountNotFound(Pubkey),
+    #[error("account {0} is not owned by {1}")]
+    InvalidOwner(Pubkey, Pubkey),
+    #[error("failed to decode account: {0}")]
+    Decode(#[from] anchor_lang::error::Error),
+    #[error("round {0} is not in the user's history or history pages")]
+    RoundNotFound(u128),
+    #[error("round {0} has not been settled")]
+    RoundNotSettled(u128),
+    #[error("no randomness for round {0} is stored on-chain or in its transaction logs")]
+    RandomnessUnavailable(u128),
+    #[error("round {0} was settled with randomness no oracle had verified")]
+    UnverifiedRandomness(u128),
+    #[error("the house's reveal for round {0} does not match its commitment")]
+    InvalidReveal(u128),
+    #[error("failed to decode event: {0}")]
+    Event(#[from] std::io::Error),
+    #[error("invalid transaction signature: {0}")]
+    Signature(#[from] solana_sdk::signature::ParseSignatureError),
+    #[error(transparent)]
+    Rpc(#[from] solana_client::client_error::ClientError),
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
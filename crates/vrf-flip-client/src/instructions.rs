@@ -0,0 +1,692 @@
+use crate::*;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token, associated_token::get_associated_token_address, token};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+use switchboard_v2::SWITCHBOARD_PROGRAM_ID;
+use switchboard_vrf_flip::{
+    accounts, instruction, HouseCommitParams, HouseInitFeeVaultParams, HouseInitNativeParams,
+    HouseInitParams, HouseRevealParams, HouseUpdateParams, UserAirdropParams,
+    UserAutoplayBetParams, UserAutoplayStartParams, UserAutoplayStopParams, UserBetParams,
+    UserCloseParams, UserHistoryPageCloseParams, UserHistoryPageInitParams, UserInitParams,
+    UserMigrateParams, UserRecordSettleParams, UserSetDelegateParams, UserSetLimitsParams,
+    UserSettleParams, UserWithdrawEscrowParams,
+};
+
+/// The accounts tied to a `UserState`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserAccounts {
+    pub user: Pubkey,
+    pub house: Pubkey,
+    pub authority: Pubkey,
+    pub escrow: Pubkey,
+    pub reward_address: Pubkey,
+    pub vrf: Pubkey,
+}
+
+impl UserAccounts {
+    pub fn from_state(user: &Pubkey, state: &UserState) -> Self {
+        Self {
+            user: *user,
+            house: state.house,
+            authority: state.authority,
+            escrow: state.escrow,
+            reward_address: state.reward_address,
+            vrf: state.vrf,
+        }
+    }
+}
+
+pub fn house_init(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    switchboard_queue: &Pubkey,
+    switchboard_mint: &Pubkey,
+) -> Instruction {
+    let (house, _) = find_house_address(mint);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::HouseInit {
+            house,
+            authority: *authority,
+            switchboard_mint: *switchboard_mint,
+            switchboard_queue: *switchboard_queue,
+            mint: *mint,
+            house_vault: get_associated_token_address(&house, mint),
+            payer: *payer,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::HouseInit {
+            params: HouseInitParams {},
+        }
+        .data(),
+    }
+}
+
+/// Creates a house that takes bets in native SOL, wrapping `liquidity` lamports from the payer
+/// into the house vault
+pub fn house_init_native(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    switchboard_queue: &Pubkey,
+    switchboard_mint: &Pubkey,
+    liquidity: u64,
+) -> Instruction {
+    let (house, _) = find_house_address(&token::spl_token::native_mint::ID);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::HouseInitNative {
+            house,
+            authority: *authority,
+            switchboard_mint: *switchboard_mint,
+            switchboard_queue: *switchboard_queue,
+            mint: token::spl_token::native_mint::ID,
+            house_vault: get_associated_token_address(&house, &token::spl_token::native_mint::ID),
+            payer: *payer,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::HouseInitNative {
+            params: HouseInitNativeParams { liquidity },
+        }
+        .data(),
+    }
+}
+
+pub fn house_update(house: &Pubkey, authority: &Pubkey, params: HouseUpdateParams) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::HouseUpdate {
+            house: *house,
+            authority: *authority,
+        }
+        .to_account_metas(None),
+        data: instruction::HouseUpdate { params }.data(),
+    }
+}
+
+pub fn house_init_fee_vault(
+    house: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    switchboard_mint: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::HouseInitFeeVault {
+            house: *house,
+            authority: *authority,
+            switchboard_mint: *switchboard_mint,
+            fee_vault: find_fee_vault_address(house).0,
+            payer: *payer,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::HouseInitFeeVault {
+            params: HouseInitFeeVaultParams {},
+        }
+        .data(),
+    }
+}
+
+pub fn house_commit(
+    house: &Pubkey,
+    authority: &Pubkey,
+    user: &Pubkey,
+    commitment: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::HouseCommit {
+            user: *user,
+            house: *house,
+            authority: *authority,
+        }
+        .to_account_metas(None),
+        data: instruction::HouseCommit {
+            params: HouseCommitParams { commitment },
+        }
+        .data(),
+    }
+}
+
+pub fn house_reveal(
+    house: &Pubkey,
+    authority: &Pubkey,
+    user: &Pubkey,
+    secret: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::HouseReveal {
+            user: *user,
+            house: *house,
+            authority: *authority,
+            slot_hashes: sysvar::slot_hashes::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::HouseReveal {
+            params: HouseRevealParams { secret },
+        }
+        .data(),
+    }
+}
+
+/// Creates the user account for an existing VRF. The VRF must be created with
+/// the user PDA as its authority and `user_settle_callback` as its callback.
+/// `house` is `find_house_address(mint)`, or `find_legacy_house_address()` for
+/// a house created before houses were seeded by their mint.
+#[allow(clippy::too_many_arguments)]
+pub fn user_init(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    escrow: &Pubkey,
+    vrf: &Pubkey,
+    house: &Pubkey,
+    mint: &Pubkey,
+    switchboard_state_bump: u8,
+    vrf_permission_bump: u8,
+) -> Instruction {
+    let (user, _) = find_user_address(house, authority);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserInit {
+            user,
+            house: *house,
+            mint: *mint,
+            authority: *authority,
+            escrow: *escrow,
+            reward_address: get_associated_token_address(authority, mint),
+            vrf: *vrf,
+            payer: *payer,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::UserInit {
+            params: UserInitParams {
+                switchboard_state_bump,
+                vrf_permission_bump,
+            },
+        }
+        .data(),
+    }
+}
+
+/// The history page a bet has to archive into, once the user's history ring is
+/// full. Pass it to `user_bet`, after creating it with `user_history_page_init`.
+pub fn history_page_for_bet(user: &Pubkey, state: &UserState) -> Option<(Pubkey, u32)> {
+    if !state.needs_history_page() {
+        return None;
+    }
+    let page_index = state.history_page_index();
+    Some((find_history_page_address(user, page_index).0, page_index))
+}
+
+/// The history page settling the user's current round has to archive into, when
+/// the bet that started it couldn't free its slot in the history ring. Pass it to
+/// `user_settle`, after creating it with `user_history_page_init`.
+pub fn history_page_for_settle(user: &Pubkey, state: &UserState) -> Option<(Pubkey, u32)> {
+    if !state.needs_history_page_to_settle() {
+        return None;
+    }
+    let page_index = state.history_page_index();
+    Some((find_history_page_address(user, page_index).0, page_index))
+}
+
+/// `payer` signs for the `vrf_payer` switchboard token account. Pass the house
+/// fee vault as `vrf_payer` to have the house sponsor the request.
+pub fn user_bet(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    switchboard: &SwitchboardAccounts,
+    payer: &Pubkey,
+    vrf_payer: &Pubkey,
+    history_page: Option<Pubkey>,
+    params: UserBetParams,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserBet {
+            user: user.user,
+            house: user.house,
+            house_vault: *house_vault,
+            authority: user.authority,
+            escrow: user.escrow,
+            vrf: user.vrf,
+            oracle_queue: switchboard.oracle_queue,
+            queue_authority: switchboard.queue_authority,
+            data_buffer: switchboard.data_buffer,
+            permission: switchboard.permission,
+            vrf_escrow: switchboard.vrf_escrow,
+            switchboard_program_state: switchboard.program_state,
+            switchboard_program: SWITCHBOARD_PROGRAM_ID,
+            payer: *payer,
+            vrf_payer: *vrf_payer,
+            flip_payer: user.reward_address,
+            recent_blockhashes: sysvar::recent_blockhashes::ID,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            history_page,
+            unwrap_account: Some(find_unwrap_address(&user.user).0),
+            native_mint: Some(token::spl_token::native_mint::ID),
+        }
+        .to_account_metas(None),
+        data: instruction::UserBet { params }.data(),
+    }
+}
+
+/// The delegate signing a bet and the switchboard wallet paying for its randomness
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DelegateAccounts {
+    pub delegate: Pubkey,
+    pub vrf_payer: Pubkey,
+}
+
+impl DelegateAccounts {
+    /// Uses the delegate's associated switchboard token account
+    pub fn new(delegate: &Pubkey, switchboard_mint: &Pubkey) -> Self {
+        Self {
+            delegate: *delegate,
+            vrf_payer: get_associated_token_address(delegate, switchboard_mint),
+        }
+    }
+}
+
+/// A bet signed by the user's delegate, paid from the user's reward address
+/// through the token approval `user_set_delegate` gave the user PDA
+pub fn user_bet_delegate(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    switchboard: &SwitchboardAccounts,
+    delegate: &DelegateAccounts,
+    history_page: Option<Pubkey>,
+    params: UserBetParams,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserBetDelegate {
+            user: user.user,
+            house: user.house,
+            house_vault: *house_vault,
+            authority: user.authority,
+            escrow: user.escrow,
+            reward_address: user.reward_address,
+            vrf: user.vrf,
+            oracle_queue: switchboard.oracle_queue,
+            queue_authority: switchboard.queue_authority,
+            data_buffer: switchboard.data_buffer,
+            permission: switchboard.permission,
+            vrf_escrow: switchboard.vrf_escrow,
+            switchboard_program_state: switchboard.program_state,
+            switchboard_program: SWITCHBOARD_PROGRAM_ID,
+            delegate: delegate.delegate,
+            vrf_payer: delegate.vrf_payer,
+            recent_blockhashes: sysvar::recent_blockhashes::ID,
+            token_program: token::ID,
+            history_page,
+        }
+        .to_account_metas(None),
+        data: instruction::UserBetDelegate { params }.data(),
+    }
+}
+
+pub fn user_bet_commit_reveal(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    history_page: Option<Pubkey>,
+    params: UserBetParams,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserBetCommitReveal {
+            user: user.user,
+            house: user.house,
+            house_vault: *house_vault,
+            authority: user.authority,
+            escrow: user.escrow,
+            flip_payer: user.reward_address,
+            token_program: token::ID,
+            history_page,
+        }
+        .to_account_metas(None),
+        data: instruction::UserBetCommitReveal { params }.data(),
+    }
+}
+
+/// Settles the user's current round. The house is writable so the round is recorded
+/// in it right away, unlike in `user_settle_callback`.
+pub fn user_settle(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    history_page: Option<Pubkey>,
+) -> Instruction {
+    let mut ix = user_settle_read_only_house(user, house_vault, history_page);
+    let house = ix
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == user.house);
+    house.unwrap().is_writable = true;
+    ix
+}
+
+fn user_settle_read_only_house(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    history_page: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserSettle {
+            user: user.user,
+            house: user.house,
+            escrow: user.escrow,
+            reward_address: user.reward_address,
+            house_vault: *house_vault,
+            vrf: user.vrf,
+            token_program: token::ID,
+            history_page,
+            unwrap_account: Some(find_unwrap_address(&user.user).0),
+            authority: Some(user.authority),
+        }
+        .to_account_metas(None),
+        data: instruction::UserSettle {
+            params: UserSettleParams {},
+        }
+        .data(),
+    }
+}
+
+/// The accounts Switchboard passes to `user_settle` when the VRF callback fires,
+/// the optional history page is left empty. The house is read-only so callbacks
+/// don't lock it, `user_record_settle` records the round in it afterwards.
+pub fn user_settle_callback(user: &UserAccounts, house_vault: &Pubkey) -> Vec<AccountMeta> {
+    user_settle_read_only_house(user, house_vault, None).accounts
+}
+
+/// Records a round the VRF callback settled in the house stats and releases its
+/// liability, anyone can send it once `UserState::house_settle_pending` is set
+pub fn user_record_settle(user: &UserAccounts) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserRecordSettle {
+            user: user.user,
+            house: user.house,
+        }
+        .to_account_metas(None),
+        data: instruction::UserRecordSettle {
+            params: UserRecordSettleParams {},
+        }
+        .data(),
+    }
+}
+
+pub fn user_airdrop(user: &UserAccounts, house_vault: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserAirdrop {
+            user: user.user,
+            house: user.house,
+            house_vault: *house_vault,
+            mint: *mint,
+            authority: user.authority,
+            airdrop_token_wallet: get_associated_token_address(&user.authority, mint),
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::UserAirdrop {
+            params: UserAirdropParams {},
+        }
+        .data(),
+    }
+}
+
+/// Opens the user's next history page, the user's authority has to sign
+pub fn user_history_page_init(user: &UserAccounts, payer: &Pubkey, page_index: u32) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserHistoryPageInit {
+            history_page: find_history_page_address(&user.user, page_index).0,
+            user: user.user,
+            authority: user.authority,
+            payer: *payer,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::UserHistoryPageInit {
+            params: UserHistoryPageInitParams { page_index },
+        }
+        .data(),
+    }
+}
+
+/// Closes a filled history page and returns its rent to the user's authority
+pub fn user_history_page_close(user: &UserAccounts, page_index: u32) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserHistoryPageClose {
+            history_page: find_history_page_address(&user.user, page_index).0,
+            user: user.user,
+            authority: user.authority,
+        }
+        .to_account_metas(None),
+        data: instruction::UserHistoryPageClose {
+            params: UserHistoryPageCloseParams {},
+        }
+        .data(),
+    }
+}
+
+/// Rewrites a user account created with an older `UserState` layout, `payer`
+/// covers any extra rent
+pub fn user_migrate(user: &UserAccounts, payer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserMigrate {
+            user: user.user,
+            house: user.house,
+            authority: user.authority,
+            payer: *payer,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::UserMigrate {
+            params: UserMigrateParams {},
+        }
+        .data(),
+    }
+}
+
+/// The history page `user_close` closes along with the user, once every filled
+/// page has been closed with `user_history_page_close`
+pub fn history_page_for_close(user: &Pubkey, state: &UserState) -> Option<Pubkey> {
+    if state.history_pages == 0 {
+        return None;
+    }
+    Some(find_history_page_address(user, state.history_page_index()).0)
+}
+
+/// Closes the user account, its escrow and a native house's unwrap account, the
+/// escrow has to be empty. Pass `history_page_for_close` as `history_page`.
+pub fn user_close(user: &UserAccounts, history_page: Option<Pubkey>) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserClose {
+            user: user.user,
+            house: user.house,
+            authority: user.authority,
+            escrow: user.escrow,
+            token_program: token::ID,
+            history_page,
+            unwrap_account: Some(find_unwrap_address(&user.user).0),
+        }
+        .to_account_metas(None),
+        data: instruction::UserClose {
+            params: UserCloseParams {},
+        }
+        .data(),
+    }
+}
+
+/// Sends any escrow not backing an unsettled bet back to the user's reward address,
+/// or to the authority's wallet as SOL for a native house
+pub fn user_withdraw_escrow(user: &UserAccounts) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserWithdrawEscrow {
+            user: user.user,
+            house: user.house,
+            authority: user.authority,
+            escrow: user.escrow,
+            reward_address: user.reward_address,
+            token_program: token::ID,
+            unwrap_account: Some(find_unwrap_address(&user.user).0),
+            native_mint: Some(token::spl_token::native_mint::ID),
+            system_program: Some(system_program::ID),
+        }
+        .to_account_metas(None),
+        data: instruction::UserWithdrawEscrow {
+            params: UserWithdrawEscrowParams {},
+        }
+        .data(),
+    }
+}
+
+/// Lets `delegate` sign bets until `expiry_slot`, spending up to `allowance` from the
+/// user's reward address through an approval on the user PDA. Pass the default pubkey
+/// to revoke it.
+pub fn user_set_delegate(
+    user: &UserAccounts,
+    delegate: &Pubkey,
+    expiry_slot: u64,
+    allowance: u64,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserSetDelegate {
+            user: user.user,
+            house: user.house,
+            authority: user.authority,
+            reward_address: user.reward_address,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::UserSetDelegate {
+            params: UserSetDelegateParams {
+                delegate: *delegate,
+                expiry_slot,
+                allowance,
+            },
+        }
+        .data(),
+    }
+}
+
+/// Funds the escrow for a session of bets the keeper places with `user_autoplay_bet`
+pub fn user_autoplay_start(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    params: UserAutoplayStartParams,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserAutoplayStart {
+            user: user.user,
+            house: user.house,
+            house_vault: *house_vault,
+            authority: user.authority,
+            escrow: user.escrow,
+            flip_payer: user.reward_address,
+            token_program: token::ID,
+            unwrap_account: Some(find_unwrap_address(&user.user).0),
+            native_mint: Some(token::spl_token::native_mint::ID),
+            system_program: Some(system_program::ID),
+        }
+        .to_account_metas(None),
+        data: instruction::UserAutoplayStart { params }.data(),
+    }
+}
+
+/// Places the next bet of an autoplay session, the house fee vault pays for the
+/// randomness so any keeper can send it
+pub fn user_autoplay_bet(
+    user: &UserAccounts,
+    house_vault: &Pubkey,
+    fee_vault: &Pubkey,
+    switchboard: &SwitchboardAccounts,
+    history_page: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserAutoplayBet {
+            user: user.user,
+            house: user.house,
+            house_vault: *house_vault,
+            authority: user.authority,
+            escrow: user.escrow,
+            vrf: user.vrf,
+            oracle_queue: switchboard.oracle_queue,
+            queue_authority: switchboard.queue_authority,
+            data_buffer: switchboard.data_buffer,
+            permission: switchboard.permission,
+            vrf_escrow: switchboard.vrf_escrow,
+            switchboard_program_state: switchboard.program_state,
+            switchboard_program: SWITCHBOARD_PROGRAM_ID,
+            vrf_payer: *fee_vault,
+            recent_blockhashes: sysvar::recent_blockhashes::ID,
+            token_program: token::ID,
+            history_page,
+        }
+        .to_account_metas(None),
+        data: instruction::UserAutoplayBet {
+            params: UserAutoplayBetParams {},
+        }
+        .data(),
+    }
+}
+
+/// Ends an autoplay session, unplayed bets stay in escrow until withdrawn or settled
+pub fn user_autoplay_stop(user: &UserAccounts) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserAutoplayStop {
+            user: user.user,
+            house: user.house,
+            authority: user.authority,
+        }
+        .to_account_metas(None),
+        data: instruction::UserAutoplayStop {
+            params: UserAutoplayStopParams {},
+        }
+        .data(),
+    }
+}
+
+/// Updates the user's responsible-gaming limits, `None` leaves a limit unchanged
+pub fn user_set_limits(user: &UserAccounts, params: UserSetLimitsParams) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts::UserSetLimits {
+            user: user.user,
+            house: user.house,
+            authority: user.authority,
+        }
+        .to_account_metas(None),
+        data: instruction::UserSetLimits { params }.data(),
+    }
+}
@@ -0,0 +1,36 @@
+//! Rust client for the Switchboard VRF Flip program.
+//!
+//! Mirrors the TypeScript client in `client/`: PDA derivation, instruction
+//! builders that resolve the Switchboard accounts a bet needs, and decoders for
+//! the program's accounts.
+pub mod accounts;
+pub use accounts::*;
+
+pub mod error;
+pub use error::*;
+
+pub mod instructions;
+pub use instructions::*;
+
+pub mod pda;
+pub use pda::*;
+
+pub mod rpc;
+pub use rpc::*;
+
+pub mod settle;
+pub use settle::*;
+
+pub mod switchboard;
+pub use switchboard::*;
+
+pub mod verify;
+pub use verify::*;
+
+pub use switchboard_v2::{OracleQueueAccountData, VrfAccountData};
+pub use switchboard_vrf_flip::{
+    self, AutoPlay, BetLimits, FlipEvent, GameStats, GameType, History, HouseRevealed, HouseState,
+    PendingLimits, Round, RoundStatus, UserDelegate, UserHistoryPage, UserLimits,
+    UserRoundRandomness, UserState, UserStats, HISTORY_PAGE_SIZE, ID as PROGRAM_ID,
+    LIMIT_LOOSEN_DELAY, REVEAL_TIMEOUT_SLOTS, USER_STATE_VERSION,
+};
@@ -0,0 +1,34 @@
+use solana_program::pubkey::Pubkey;
+use switchboard_vrf_flip::{
+    FEE_VAULT_SEED, HISTORY_PAGE_SEED, HOUSE_SEED, ID, UNWRAP_SEED, USER_SEED,
+};
+
+/// Each mint has its own house
+pub fn find_house_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOUSE_SEED, mint.as_ref()], &ID)
+}
+
+/// Houses created before houses were seeded by their mint only use `HOUSE_SEED`
+pub fn find_legacy_house_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOUSE_SEED], &ID)
+}
+
+pub fn find_user_address(house: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_SEED, house.as_ref(), authority.as_ref()], &ID)
+}
+
+pub fn find_fee_vault_address(house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_SEED, house.as_ref()], &ID)
+}
+
+pub fn find_history_page_address(user: &Pubkey, page_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[HISTORY_PAGE_SEED, user.as_ref(), &page_index.to_le_bytes()],
+        &ID,
+    )
+}
+
+/// The native account a native house unwraps the user's payouts through
+pub fn find_unwrap_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UNWRAP_SEED, user.as_ref()], &ID)
+}
@@ -0,0 +1,201 @@
+use crate::*;
+use anchor_lang::Discriminator;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+use switchboard_v2::{OracleQueueAccountData, VrfAccountData, SWITCHBOARD_PROGRAM_ID};
+use switchboard_vrf_flip::events::parse_logs;
+
+fn fetch_data(rpc: &RpcClient, address: &Pubkey, owner: &Pubkey) -> Result<Vec<u8>> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())?
+        .value
+        .ok_or(ClientError::AccountNotFound(*address))?;
+    if account.owner != *owner {
+        return Err(ClientError::InvalidOwner(*address, *owner));
+    }
+    Ok(account.data)
+}
+
+pub fn fetch_house_state(rpc: &RpcClient, house: &Pubkey) -> Result<HouseState> {
+    decode_house_state(&fetch_data(rpc, house, &PROGRAM_ID)?)
+}
+
+/// Finds the house for a mint, falling back to the legacy house if it uses the mint
+pub fn fetch_house_for_mint(rpc: &RpcClient, mint: &Pubkey) -> Result<(Pubkey, HouseState)> {
+    let (house, _) = find_house_address(mint);
+    match fetch_house_state(rpc, &house) {
+        Ok(state) => return Ok((house, state)),
+        Err(ClientError::AccountNotFound(_)) => {}
+        Err(err) => return Err(err),
+    }
+    let (legacy_house, _) = find_legacy_house_address();
+    match fetch_house_state(rpc, &legacy_house) {
+        Ok(state) if state.mint == *mint => Ok((legacy_house, state)),
+        Ok(_) | Err(ClientError::AccountNotFound(_)) => Err(ClientError::AccountNotFound(house)),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn fetch_user_state(rpc: &RpcClient, user: &Pubkey) -> Result<UserState> {
+    decode_user_state(&fetch_data(rpc, user, &PROGRAM_ID)?)
+}
+
+/// Fetches every user account owned by the program. Each account is decoded on
+/// its own, so one that fails to decode comes back with its error instead of
+/// failing the whole scan.
+pub fn fetch_all_user_states(rpc: &RpcClient) -> Result<Vec<(Pubkey, Result<UserState>)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize((8 + std::mem::size_of::<UserState>()) as u64),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &UserState::discriminator())),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    Ok(rpc
+        .get_program_accounts_with_config(&PROGRAM_ID, config)?
+        .into_iter()
+        .map(|(address, account)| (address, decode_user_state(&account.data)))
+        .collect())
+}
+
+pub fn fetch_vrf(rpc: &RpcClient, vrf: &Pubkey) -> Result<VrfAccountData> {
+    decode_vrf(&fetch_data(rpc, vrf, &SWITCHBOARD_PROGRAM_ID)?)
+}
+
+pub fn fetch_oracle_queue(rpc: &RpcClient, queue: &Pubkey) -> Result<OracleQueueAccountData> {
+    decode_oracle_queue(&fetch_data(rpc, queue, &SWITCHBOARD_PROGRAM_ID)?)
+}
+
+/// Fetches the VRF and its queue to find the accounts `user_bet` needs
+pub fn fetch_switchboard_accounts(rpc: &RpcClient, vrf: &Pubkey) -> Result<SwitchboardAccounts> {
+    let vrf_state = fetch_vrf(rpc, vrf)?;
+    let oracle_queue = vrf_state.oracle_queue;
+    let queue_state = fetch_oracle_queue(rpc, &oracle_queue)?;
+    Ok(SwitchboardAccounts::resolve(vrf, &vrf_state, &queue_state))
+}
+
+/// Fetches a user account along with everything needed to place a bet
+pub fn fetch_user_accounts(
+    rpc: &RpcClient,
+    user: &Pubkey,
+) -> Result<(UserAccounts, SwitchboardAccounts)> {
+    let state = fetch_user_state(rpc, user)?;
+    let user_accounts = UserAccounts::from_state(user, &state);
+    let switchboard = fetch_switchboard_accounts(rpc, &user_accounts.vrf)?;
+    Ok((user_accounts, switchboard))
+}
+
+/// Fetches the history pages the user's archived rounds were written to,
+/// skipping pages that have been closed
+pub fn fetch_history_pages(
+    rpc: &RpcClient,
+    user: &Pubkey,
+    user_state: &UserState,
+) -> Result<Vec<UserHistoryPage>> {
+    // the last archived round sits on the last page
+    let archived_rounds = user_state.archived_rounds;
+    let num_pages = match archived_rounds.checked_sub(1) {
+        Some(last) => (last / HISTORY_PAGE_SIZE as u64) as u32 + 1,
+        None => 0,
+    };
+    let addresses: Vec<Pubkey> = (0..num_pages)
+        .map(|page_index| find_history_page_address(user, page_index).0)
+        .collect();
+
+    let mut pages = Vec::new();
+    // getMultipleAccounts accepts at most 100 addresses
+    for batch in addresses.chunks(100) {
+        for account in rpc.get_multiple_accounts(batch)?.into_iter().flatten() {
+            if account.owner == PROGRAM_ID {
+                pages.push(decode_history_page(&account.data)?);
+            }
+        }
+    }
+    Ok(pages)
+}
+
+/// Decodes the program's events from the user's successful transactions
+/// between the round's request and settle slots, which include its reveal and
+/// the settle that logged its randomness
+pub fn fetch_round_events(rpc: &RpcClient, user: &Pubkey, round: &Round) -> Result<Vec<FlipEvent>> {
+    let (request_slot, settle_slot) = (round.request_slot, round.settle_slot);
+    let mut events = Vec::new();
+    let mut before = None;
+    loop {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until: None,
+            limit: None,
+            commitment: Some(rpc.commitment()),
+        };
+        let signatures = rpc.get_signatures_for_address_with_config(user, config)?;
+        let Some(oldest) = signatures.last() else {
+            break;
+        };
+        before = Some(oldest.signature.parse::<Signature>()?);
+
+        for status in signatures.iter() {
+            if status.err.is_some() || status.slot < request_slot || status.slot > settle_slot {
+                continue;
+            }
+            let config = RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(rpc.commitment()),
+                max_supported_transaction_version: Some(0),
+            };
+            let signature = status.signature.parse::<Signature>()?;
+            let transaction = rpc.get_transaction_with_config(&signature, config)?;
+            let logs: Option<Vec<String>> = transaction
+                .transaction
+                .meta
+                .and_then(|meta| meta.log_messages.into());
+            events.extend(parse_logs(&logs.unwrap_or_default())?);
+        }
+
+        // signatures come newest first, anything older was sent before the round
+        if oldest.slot < request_slot {
+            break;
+        }
+    }
+    Ok(events)
+}
+
+/// Verifies a round against the randomness still on-chain, falling back to
+/// the randomness the program logged when it settled the round
+pub fn fetch_round_verification(
+    rpc: &RpcClient,
+    user: &Pubkey,
+    round_id: u128,
+) -> Result<RoundVerification> {
+    let user_state = fetch_user_state(rpc, user)?;
+    let pages = fetch_history_pages(rpc, user, &user_state)?;
+    let round =
+        find_round(&user_state, &pages, round_id).ok_or(ClientError::RoundNotFound(round_id))?;
+    if round.status != RoundStatus::Settled {
+        return Err(ClientError::RoundNotSettled(round_id));
+    }
+
+    let vrf = fetch_vrf(rpc, &user_state.vrf)?;
+    let (source, randomness) = match round_randomness(&user_state, &vrf, &round) {
+        Some(randomness) => randomness,
+        None => {
+            let events = fetch_round_events(rpc, user, &round)?;
+            round_randomness_from_events(user, &round, &events)?
+                .ok_or(ClientError::RandomnessUnavailable(round_id))?
+        }
+    };
+
+    Ok(verify_round(&round, source, randomness))
+}
@@ -0,0 +1,34 @@
+use crate::*;
+use solana_program::clock::Clock;
+use switchboard_v2::VrfAccountData;
+
+/// Whether `user_settle` has the randomness it needs to close the user's
+/// current round at `slot`, either a revealed commitment, a commitment the
+/// house failed to reveal in time, or a VRF request an oracle fulfilled and
+/// verified.
+pub fn ready_to_settle(user: &UserState, vrf: &VrfAccountData, slot: u64) -> bool {
+    if user.current_round.status != RoundStatus::Awaiting {
+        return false;
+    }
+    if user.is_commit_reveal_round() {
+        let clock = Clock {
+            slot,
+            ..Clock::default()
+        };
+        return user.commit_reveal.result != [0u8; 32]
+            || user.current_round.reveal_timed_out(&clock);
+    }
+    let counter = vrf.counter;
+    counter == user.expected_vrf_counter()
+        && vrf.current_round.num_verified > 0
+        && vrf.get_result().is_ok()
+}
+
+/// Whether `user_autoplay_bet` can place the next bet of the user's autoplay
+/// session, which waits for the previous round to settle.
+pub fn ready_to_autoplay(user: &UserState) -> bool {
+    let remaining_bets = user.autoplay.remaining_bets;
+    user.autoplay.is_active()
+        && remaining_bets > 0
+        && user.current_round.status != RoundStatus::Awaiting
+}
@@ -0,0 +1,65 @@
+use solana_program::pubkey::Pubkey;
+use switchboard_v2::{OracleQueueAccountData, VrfAccountData, SWITCHBOARD_PROGRAM_ID};
+
+const PERMISSION_SEED: &[u8] = b"PermissionAccountData";
+const STATE_SEED: &[u8] = b"STATE";
+
+/// The Switchboard accounts `user_bet` needs to request randomness for a VRF
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwitchboardAccounts {
+    pub vrf: Pubkey,
+    pub oracle_queue: Pubkey,
+    pub queue_authority: Pubkey,
+    pub data_buffer: Pubkey,
+    pub permission: Pubkey,
+    pub permission_bump: u8,
+    pub vrf_escrow: Pubkey,
+    pub program_state: Pubkey,
+    pub program_state_bump: u8,
+}
+
+impl SwitchboardAccounts {
+    pub fn resolve(
+        vrf: &Pubkey,
+        vrf_state: &VrfAccountData,
+        queue_state: &OracleQueueAccountData,
+    ) -> Self {
+        let oracle_queue = vrf_state.oracle_queue;
+        let queue_authority = queue_state.authority;
+        let (permission, permission_bump) =
+            find_permission_address(&queue_authority, &oracle_queue, vrf);
+        let (program_state, program_state_bump) = find_program_state_address();
+
+        Self {
+            vrf: *vrf,
+            oracle_queue,
+            queue_authority,
+            data_buffer: queue_state.data_buffer,
+            permission,
+            permission_bump,
+            vrf_escrow: vrf_state.escrow,
+            program_state,
+            program_state_bump,
+        }
+    }
+}
+
+pub fn find_permission_address(
+    authority: &Pubkey,
+    granter: &Pubkey,
+    grantee: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PERMISSION_SEED,
+            authority.as_ref(),
+            granter.as_ref(),
+            grantee.as_ref(),
+        ],
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+pub fn find_program_state_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATE_SEED], &SWITCHBOARD_PROGRAM_ID)
+}
@@ -0,0 +1,169 @@
+//! Recomputes a settled round's outcome from its randomness so players can
+//! check the house paid out what `Round::settle` would have.
+use crate::*;
+use solana_program::{
+    hash::{hash, hashv},
+    pubkey::Pubkey,
+};
+use switchboard_v2::VrfAccountData;
+
+/// Where the randomness for a round came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RandomnessSource {
+    /// A VRF result whose proof Switchboard oracles verified
+    Vrf {
+        counter: u128,
+        request_slot: u64,
+        num_verified: u32,
+    },
+    /// The house's revealed secret, checked against its commitment
+    CommitReveal {
+        commitment: [u8; 32],
+        secret: [u8; 32],
+        slot_hash: [u8; 32],
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundVerification {
+    pub round_id: u128,
+    pub source: RandomnessSource,
+    pub randomness: [u8; 32],
+    pub guess: u32,
+    pub expected_result: u32,
+    pub recorded_result: u32,
+}
+
+impl RoundVerification {
+    pub fn is_match(&self) -> bool {
+        self.expected_result == self.recorded_result
+    }
+
+    pub fn user_won(&self) -> bool {
+        self.expected_result == self.guess
+    }
+}
+
+/// Finds a round in the user's current round, history ring or history pages
+pub fn find_round(user: &UserState, pages: &[UserHistoryPage], round_id: u128) -> Option<Round> {
+    let current_round = user.current_round;
+    let history = user.history;
+    let archived = pages
+        .iter()
+        .flat_map(|page| page.rounds.into_iter().take(page.len as usize));
+    std::iter::once(current_round)
+        .chain(history.rounds)
+        .chain(archived)
+        .find(|round| round_id != 0 && { round.round_id } == round_id)
+}
+
+/// The randomness the program used to settle a VRF `round`, if it is still
+/// on-chain. Only the VRF's latest result is kept, and it only counts once an
+/// oracle has verified its proof. The house's secret is never stored on-chain,
+/// so commit-reveal rounds can only be verified from their logs.
+pub fn round_randomness(
+    user: &UserState,
+    vrf: &VrfAccountData,
+    round: &Round,
+) -> Option<(RandomnessSource, [u8; 32])> {
+    let (round_id, request_slot) = (round.round_id, round.request_slot);
+
+    let commit_reveal = user.commit_reveal;
+    if { commit_reveal.round_id } == round_id {
+        return None;
+    }
+
+    let vrf_round = vrf.current_round;
+    if { vrf_round.request_slot } != request_slot
+        || vrf_round.result == [0u8; 32]
+        || vrf_round.num_verified == 0
+    {
+        return None;
+    }
+    let source = RandomnessSource::Vrf {
+        counter: vrf.counter,
+        request_slot,
+        num_verified: vrf_round.num_verified,
+    };
+    Some((source, vrf_round.result))
+}
+
+/// The randomness the program used to settle `round`, from the events it
+/// emitted while the round was open. Commit-reveal randomness is only returned
+/// once the revealed secret matches the commitment and hashes to it.
+pub fn round_randomness_from_events(
+    user: &Pubkey,
+    round: &Round,
+    events: &[FlipEvent],
+) -> Result<Option<(RandomnessSource, [u8; 32])>> {
+    let round_id = round.round_id;
+    let used = events.iter().find_map(|event| match event {
+        FlipEvent::UserRoundRandomness(used) if used.user == *user && used.round_id == round_id => {
+            Some(used)
+        }
+        _ => None,
+    });
+    let Some(used) = used else {
+        return Ok(None);
+    };
+
+    if used.vrf_counter != 0 {
+        if used.num_verified == 0 {
+            return Err(ClientError::UnverifiedRandomness(round_id));
+        }
+        let source = RandomnessSource::Vrf {
+            counter: used.vrf_counter,
+            request_slot: round.request_slot,
+            num_verified: used.num_verified,
+        };
+        return Ok(Some((source, used.randomness)));
+    }
+
+    let reveal = events.iter().find_map(|event| match event {
+        FlipEvent::HouseRevealed(reveal) if reveal.user == *user && reveal.round_id == round_id => {
+            Some(reveal)
+        }
+        _ => None,
+    });
+    let Some(reveal) = reveal else {
+        return Ok(None);
+    };
+    if !reveal_is_valid(reveal) || reveal.result != used.randomness {
+        return Err(ClientError::InvalidReveal(round_id));
+    }
+    let source = RandomnessSource::CommitReveal {
+        commitment: reveal.commitment,
+        secret: reveal.secret,
+        slot_hash: reveal.slot_hash,
+    };
+    Ok(Some((source, used.randomness)))
+}
+
+/// Whether the secret hashes to the commitment and, the way `house_reveal`
+/// combines it, to the revealed result
+pub fn reveal_is_valid(reveal: &HouseRevealed) -> bool {
+    let result = hashv(&[
+        &reveal.secret,
+        &reveal.slot_hash,
+        &reveal.round_id.to_le_bytes(),
+        reveal.user.as_ref(),
+    ]);
+    hash(&reveal.secret).to_bytes() == reveal.commitment && result.to_bytes() == reveal.result
+}
+
+/// Recomputes the round's result with the program's own `Round::outcome`
+pub fn verify_round(
+    round: &Round,
+    source: RandomnessSource,
+    randomness: [u8; 32],
+) -> RoundVerification {
+    let vrf_value: [u32; 8] = bytemuck::cast(randomness);
+    RoundVerification {
+        round_id: round.round_id,
+        source,
+        randomness,
+        guess: round.guess,
+        expected_result: round.outcome(&vrf_value),
+        recorded_result: round.result,
+    }
+}
@@ -0,0 +1,23 @@
+use solana_program::pubkey::Pubkey;
+use vrf_flip_client::{find_house_address, find_user_address};
+
+#[test]
+fn test_house_address_per_mint() {
+    let mint = Pubkey::new_unique();
+    assert_eq!(find_house_address(&mint), find_house_address(&mint));
+    assert_ne!(
+        find_house_address(&mint).0,
+        find_house_address(&Pubkey::new_unique()).0
+    );
+}
+
+#[test]
+fn test_user_address_per_house() {
+    let authority = Pubkey::new_unique();
+    let (first_house, _) = find_house_address(&Pubkey::new_unique());
+    let (second_house, _) = find_house_address(&Pubkey::new_unique());
+    assert_ne!(
+        find_user_address(&first_house, &authority).0,
+        find_user_address(&second_house, &authority).0
+    );
+}
@@ -0,0 +1,90 @@
+use bytemuck::Zeroable;
+use switchboard_v2::VrfAccountData;
+use vrf_flip_client::{ready_to_settle, RoundStatus, UserState, REVEAL_TIMEOUT_SLOTS};
+
+const SLOT: u64 = 1_000;
+
+fn awaiting_user(round_id: u128) -> UserState {
+    let mut user = UserState::default();
+    user.current_round.round_id = round_id;
+    user.current_round.status = RoundStatus::Awaiting;
+    user.current_round.request_slot = SLOT - 1;
+    user
+}
+
+fn fulfilled_vrf(counter: u128) -> VrfAccountData {
+    let mut vrf = VrfAccountData::zeroed();
+    vrf.counter = counter;
+    vrf.current_round.result = [7u8; 32];
+    vrf.current_round.num_verified = 1;
+    vrf
+}
+
+#[test]
+fn test_ready_when_vrf_counter_matches() {
+    let user = awaiting_user(3);
+    assert!(ready_to_settle(&user, &fulfilled_vrf(3), SLOT));
+}
+
+#[test]
+fn test_not_ready_before_vrf_result() {
+    let user = awaiting_user(3);
+    let mut vrf = fulfilled_vrf(3);
+    vrf.current_round.result = [0u8; 32];
+    assert!(!ready_to_settle(&user, &vrf, SLOT));
+}
+
+#[test]
+fn test_not_ready_before_vrf_proof_is_verified() {
+    let user = awaiting_user(3);
+    let mut vrf = fulfilled_vrf(3);
+    vrf.current_round.num_verified = 0;
+    assert!(!ready_to_settle(&user, &vrf, SLOT));
+}
+
+#[test]
+fn test_not_ready_for_stale_vrf_counter() {
+    let user = awaiting_user(3);
+    assert!(!ready_to_settle(&user, &fulfilled_vrf(2), SLOT));
+}
+
+#[test]
+fn test_not_ready_once_settled() {
+    let mut user = awaiting_user(3);
+    user.current_round.status = RoundStatus::Settled;
+    assert!(!ready_to_settle(&user, &fulfilled_vrf(3), SLOT));
+}
+
+#[test]
+fn test_commit_reveal_rounds_wait_for_reveal() {
+    // the second round used commit-reveal, so the vrf counter is one behind
+    let mut user = awaiting_user(2);
+    user.commit_reveal.round_id = 2;
+    user.commit_reveal_rounds = 1;
+    let vrf = fulfilled_vrf(1);
+    assert!(!ready_to_settle(&user, &vrf, SLOT));
+
+    user.commit_reveal.result = [9u8; 32];
+    assert!(ready_to_settle(&user, &vrf, SLOT));
+}
+
+#[test]
+fn test_vrf_counter_skips_commit_reveal_rounds() {
+    let mut user = awaiting_user(4);
+    user.commit_reveal.round_id = 2;
+    user.commit_reveal_rounds = 1;
+    assert!(!ready_to_settle(&user, &fulfilled_vrf(4), SLOT));
+    assert!(ready_to_settle(&user, &fulfilled_vrf(3), SLOT));
+}
+
+#[test]
+fn test_commit_reveal_rounds_settle_once_reveal_times_out() {
+    let mut user = awaiting_user(2);
+    user.commit_reveal.round_id = 2;
+    user.commit_reveal_rounds = 1;
+    let vrf = fulfilled_vrf(1);
+
+    let deadline = user.current_round.request_slot + REVEAL_TIMEOUT_SLOTS;
+    assert!(!ready_to_settle(&user, &vrf, deadline));
+    assert!(ready_to_settle(&user, &vrf, deadline + 1));
+}
@@ -0,0 +1,247 @@
+use bytemuck::Zeroable;
+use solana_program::{
+    clock::Clock,
+    hash::{hash, hashv},
+    pubkey::Pubkey,
+};
+use switchboard_v2::VrfAccountData;
+use vrf_flip_client::{
+    find_round, reveal_is_valid, round_randomness, round_randomness_from_events, verify_round,
+    ClientError, FlipEvent, GameType, HouseRevealed, RandomnessSource, Round, RoundStatus,
+    UserHistoryPage, UserRoundRandomness, UserState,
+};
+
+const RANDOMNESS: [u8; 32] = [0x35; 32];
+const SECRET: [u8; 32] = [0x53; 32];
+const SOURCE: RandomnessSource = RandomnessSource::Vrf {
+    counter: 1,
+    request_slot: 101,
+    num_verified: 1,
+};
+
+fn settled_round(round_id: u128, randomness: [u8; 32]) -> Round {
+    let game_type = GameType::SixSidedDiceRoll;
+    let mut round = Round {
+        round_id,
+        status: RoundStatus::Awaiting,
+        bet_amount: 1,
+        game_type,
+        game_config: game_type.get_game_config().unwrap(),
+        guess: 3,
+        result: 0,
+        request_slot: 100 + round_id as u64,
+        request_timestamp: 1,
+        settle_slot: 0,
+        settle_timestamp: 0,
+    };
+    let vrf_value: [u32; 8] = bytemuck::cast(randomness);
+    round.settle(&vrf_value, &Clock::default()).unwrap();
+    round
+}
+
+#[test]
+fn test_verify_matches_settle() {
+    let round = settled_round(1, RANDOMNESS);
+
+    let verification = verify_round(&round, SOURCE, RANDOMNESS);
+
+    let result = round.result;
+    assert!(verification.is_match());
+    assert_eq!(verification.expected_result, result);
+    assert_eq!(verification.user_won(), result == 3);
+}
+
+#[test]
+fn test_verify_detects_tampered_result() {
+    let mut round = settled_round(1, RANDOMNESS);
+    round.result = round.result % 6 + 1;
+
+    let verification = verify_round(&round, SOURCE, RANDOMNESS);
+
+    assert!(!verification.is_match());
+}
+
+#[test]
+fn test_find_round_searches_history() {
+    let mut user = UserState::default();
+    user.history.rounds[5] = settled_round(4, RANDOMNESS);
+    user.current_round = settled_round(5, RANDOMNESS);
+
+    assert_eq!({ find_round(&user, &[], 4).unwrap().round_id }, 4);
+    assert_eq!({ find_round(&user, &[], 5).unwrap().round_id }, 5);
+    assert!(find_round(&user, &[], 6).is_none());
+    assert!(find_round(&user, &[], 0).is_none());
+}
+
+#[test]
+fn test_find_round_searches_history_pages() {
+    let user = UserState::default();
+    let mut page = UserHistoryPage::zeroed();
+    page.rounds[0] = settled_round(1, RANDOMNESS);
+    page.rounds[1] = settled_round(2, RANDOMNESS);
+    page.len = 1;
+
+    assert_eq!({ find_round(&user, &[page], 1).unwrap().round_id }, 1);
+    // only the filled part of a page holds archived rounds
+    assert!(find_round(&user, &[page], 2).is_none());
+}
+
+#[test]
+fn test_vrf_randomness_only_for_latest_request() {
+    let user = UserState::default();
+    let round = settled_round(2, RANDOMNESS);
+    let request_slot = round.request_slot;
+    let mut vrf = VrfAccountData::zeroed();
+    vrf.counter = 2;
+    vrf.current_round.request_slot = request_slot;
+    vrf.current_round.result = RANDOMNESS;
+    vrf.current_round.num_verified = 1;
+
+    let (source, randomness) = round_randomness(&user, &vrf, &round).unwrap();
+    assert_eq!(
+        source,
+        RandomnessSource::Vrf {
+            counter: 2,
+            request_slot,
+            num_verified: 1,
+        }
+    );
+    assert_eq!(randomness, RANDOMNESS);
+
+    // the result only counts once an oracle has verified the proof
+    vrf.current_round.num_verified = 0;
+    assert!(round_randomness(&user, &vrf, &round).is_none());
+    vrf.current_round.num_verified = 1;
+
+    // an older round's randomness has been overwritten by the latest request
+    let older = settled_round(1, RANDOMNESS);
+    assert!(round_randomness(&user, &vrf, &older).is_none());
+}
+
+#[test]
+fn test_commit_reveal_randomness_is_not_taken_from_state() {
+    let round = settled_round(3, RANDOMNESS);
+    let mut user = UserState::default();
+    user.commit_reveal.round_id = 3;
+    user.commit_reveal.result = RANDOMNESS;
+    let vrf = VrfAccountData::zeroed();
+
+    // the secret is only in the reveal's logs, so the result alone proves nothing
+    assert!(round_randomness(&user, &vrf, &round).is_none());
+}
+
+fn randomness_used(user: Pubkey, round_id: u128, vrf_counter: u128) -> UserRoundRandomness {
+    UserRoundRandomness {
+        round_id,
+        user,
+        vrf_counter,
+        num_verified: if vrf_counter == 0 { 0 } else { 1 },
+        randomness: RANDOMNESS,
+    }
+}
+
+fn house_revealed(user: Pubkey, round_id: u128) -> HouseRevealed {
+    let slot_hash = [0x68; 32];
+    let result = hashv(&[&SECRET, &slot_hash, &round_id.to_le_bytes(), user.as_ref()]);
+    HouseRevealed {
+        round_id,
+        user,
+        commitment: hash(&SECRET).to_bytes(),
+        secret: SECRET,
+        slot_hash,
+        result: result.to_bytes(),
+    }
+}
+
+#[test]
+fn test_vrf_randomness_from_events() {
+    let user = Pubkey::new_unique();
+    let round = settled_round(2, RANDOMNESS);
+    let events = vec![
+        FlipEvent::UserRoundRandomness(randomness_used(user, 1, 1)),
+        // another user's round with the same id
+        FlipEvent::UserRoundRandomness(randomness_used(Pubkey::new_unique(), 2, 9)),
+        FlipEvent::UserRoundRandomness(randomness_used(user, 2, 2)),
+    ];
+
+    let (source, randomness) = round_randomness_from_events(&user, &round, &events)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        source,
+        RandomnessSource::Vrf {
+            counter: 2,
+            request_slot: round.request_slot,
+            num_verified: 1,
+        }
+    );
+    assert_eq!(randomness, RANDOMNESS);
+
+    let older = settled_round(0x10, RANDOMNESS);
+    assert!(round_randomness_from_events(&user, &older, &events)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_vrf_randomness_from_events_must_be_verified() {
+    let user = Pubkey::new_unique();
+    let round = settled_round(2, RANDOMNESS);
+    let mut used = randomness_used(user, 2, 2);
+    used.num_verified = 0;
+
+    let result =
+        round_randomness_from_events(&user, &round, &[FlipEvent::UserRoundRandomness(used)]);
+    assert!(matches!(result, Err(ClientError::UnverifiedRandomness(2))));
+}
+
+#[test]
+fn test_commit_reveal_randomness_from_events() {
+    let user = Pubkey::new_unique();
+    let revealed = house_revealed(user, 3);
+    let mut used = randomness_used(user, 3, 0);
+    used.randomness = revealed.result;
+    let round = settled_round(3, used.randomness);
+
+    // the settle alone can't be checked without the reveal
+    let mut events = vec![FlipEvent::UserRoundRandomness(used.clone())];
+    assert!(round_randomness_from_events(&user, &round, &events)
+        .unwrap()
+        .is_none());
+
+    events.push(FlipEvent::HouseRevealed(revealed.clone()));
+    let (source, randomness) = round_randomness_from_events(&user, &round, &events)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        source,
+        RandomnessSource::CommitReveal {
+            commitment: revealed.commitment,
+            secret: SECRET,
+            slot_hash: revealed.slot_hash,
+        }
+    );
+    assert_eq!(randomness, revealed.result);
+    assert!(verify_round(&round, source, randomness).is_match());
+}
+
+#[test]
+fn test_commit_reveal_randomness_rejects_bad_reveal() {
+    let user = Pubkey::new_unique();
+    let round = settled_round(3, RANDOMNESS);
+
+    // a secret that doesn't hash to the commitment
+    let mut revealed = house_revealed(user, 3);
+    revealed.commitment = [0; 32];
+    assert!(!reveal_is_valid(&revealed));
+
+    // or randomness that isn't what the reveal produced
+    let revealed = house_revealed(user, 3);
+    assert!(reveal_is_valid(&revealed));
+    let events = vec![
+        FlipEvent::UserRoundRandomness(randomness_used(user, 3, 0)),
+        FlipEvent::HouseRevealed(revealed),
+    ];
+    let result = round_randomness_from_events(&user, &round, &events);
+    assert!(matches!(result, Err(ClientError::InvalidReveal(3))));
+}
@@ -0,0 +1,297 @@
+//! Crank that settles awaiting rounds once their randomness is available, so
+//! players get paid even when the Switchboard callback never lands, records the
+//! rounds the callback settled in their house, and places the next bet of every
+//! autoplay session once its previous round settled.
+//!
+//! The crank only talks to the chain through [`Cluster`], so the same code runs
+//! against an RPC node in the binary and against a program-test bank in tests.
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use std::collections::HashMap;
+use vrf_flip_client::*;
+
+// getMultipleAccounts accepts at most 100 addresses
+const VRF_BATCH_SIZE: usize = 100;
+
+/// The chain access the crank needs
+pub trait Cluster {
+    /// Every `UserState` the crank should look at, with the error for any
+    /// account that failed to decode
+    fn user_states(&self) -> anyhow::Result<Vec<(Pubkey, vrf_flip_client::Result<UserState>)>>;
+    fn multiple_accounts(&self, addresses: &[Pubkey]) -> anyhow::Result<Vec<Option<Account>>>;
+    fn house_state(&self, house: &Pubkey) -> anyhow::Result<HouseState>;
+    fn slot(&self) -> anyhow::Result<u64>;
+    fn send(&self, payer: &Keypair, instructions: &[Instruction]) -> anyhow::Result<Signature>;
+}
+
+impl Cluster for RpcClient {
+    fn user_states(&self) -> anyhow::Result<Vec<(Pubkey, vrf_flip_client::Result<UserState>)>> {
+        Ok(fetch_all_user_states(self)?)
+    }
+
+    fn multiple_accounts(&self, addresses: &[Pubkey]) -> anyhow::Result<Vec<Option<Account>>> {
+        Ok(self.get_multiple_accounts(addresses)?)
+    }
+
+    fn house_state(&self, house: &Pubkey) -> anyhow::Result<HouseState> {
+        Ok(fetch_house_state(self, house)?)
+    }
+
+    fn slot(&self) -> anyhow::Result<u64> {
+        Ok(self.get_slot()?)
+    }
+
+    fn send(&self, payer: &Keypair, instructions: &[Instruction]) -> anyhow::Result<Signature> {
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            self.get_latest_blockhash()?,
+        );
+        Ok(self.send_and_confirm_transaction(&tx)?)
+    }
+}
+
+/// Settles every round that is ready, records the rounds VRF callbacks settled in
+/// their house, then places the autoplay bets that were waiting on them. Returns
+/// how many transactions landed.
+pub fn crank(cluster: &impl Cluster, payer: &Keypair) -> anyhow::Result<usize> {
+    let settled = settle_rounds(cluster, payer)?;
+    let recorded = record_settles(cluster, payer)?;
+    let placed = place_autoplay_bets(cluster, payer)?;
+    Ok(settled + recorded + placed)
+}
+
+/// The user states that decoded, logging the rest so one bad account doesn't
+/// stop the crank
+fn decoded_user_states(cluster: &impl Cluster) -> anyhow::Result<Vec<(Pubkey, UserState)>> {
+    let user_states = cluster
+        .user_states()?
+        .into_iter()
+        .filter_map(|(address, user)| match user {
+            Ok(user) => Some((address, user)),
+            Err(e) => {
+                eprintln!("user {}: {}", address, e);
+                None
+            }
+        })
+        .collect();
+    Ok(user_states)
+}
+
+/// Settles every round that is ready, returns how many were settled
+pub fn settle_rounds(cluster: &impl Cluster, payer: &Keypair) -> anyhow::Result<usize> {
+    let awaiting: Vec<(Pubkey, UserState)> = decoded_user_states(cluster)?
+        .into_iter()
+        .filter(|(_, user)| user.current_round.status == RoundStatus::Awaiting)
+        .collect();
+
+    // commit-reveal rounds the house didn't reveal in time settle as wins
+    let slot = cluster.slot()?;
+
+    // each house has its own vault, fetched once per scan
+    let mut house_vaults: HashMap<Pubkey, Pubkey> = HashMap::new();
+    let mut settled = 0;
+    for batch in awaiting.chunks(VRF_BATCH_SIZE) {
+        let vrf_keys: Vec<Pubkey> = batch.iter().map(|(_, user)| user.vrf).collect();
+        let vrf_accounts = cluster.multiple_accounts(&vrf_keys)?;
+
+        for ((address, user), vrf_account) in batch.iter().zip(vrf_accounts) {
+            let Some(vrf_account) = vrf_account else {
+                eprintln!("user {}: vrf account not found", address);
+                continue;
+            };
+            let vrf = match decode_vrf(&vrf_account.data) {
+                Ok(vrf) => vrf,
+                Err(e) => {
+                    eprintln!("user {}: {}", address, e);
+                    continue;
+                }
+            };
+            if !ready_to_settle(user, &vrf, slot) {
+                continue;
+            }
+
+            let round_id = user.current_round.round_id;
+            let user_accounts = UserAccounts::from_state(address, user);
+            let house_vault = match house_vaults.get(&user_accounts.house) {
+                Some(house_vault) => *house_vault,
+                None => match cluster.house_state(&user_accounts.house) {
+                    Ok(house) => {
+                        house_vaults.insert(user_accounts.house, house.house_vault);
+                        house.house_vault
+                    }
+                    Err(e) => {
+                        eprintln!("user {}: {}", address, e);
+                        continue;
+                    }
+                },
+            };
+            // the user pays for their history pages, settling fails until the page exists
+            let history_page = history_page_for_settle(address, user).map(|(page, _)| page);
+            let ix = user_settle(&user_accounts, &house_vault, history_page);
+            match cluster.send(payer, &[ix]) {
+                Ok(signature) => {
+                    println!(
+                        "user {}: settled round {} ({})",
+                        address, round_id, signature
+                    );
+                    settled += 1;
+                }
+                Err(e) => eprintln!(
+                    "user {}: failed to settle round {}: {}",
+                    address, round_id, e
+                ),
+            }
+        }
+    }
+
+    Ok(settled)
+}
+
+/// Records the rounds VRF callbacks settled with a read-only house, which the house
+/// counts towards its liability until then. Returns how many were recorded.
+pub fn record_settles(cluster: &impl Cluster, payer: &Keypair) -> anyhow::Result<usize> {
+    let pending: Vec<(Pubkey, UserState)> = decoded_user_states(cluster)?
+        .into_iter()
+        .filter(|(_, user)| user.house_settle_pending != 0)
+        .collect();
+
+    let mut recorded = 0;
+    for (address, user) in pending.iter() {
+        let round_id = user.current_round.round_id;
+        let ix = user_record_settle(&UserAccounts::from_state(address, user));
+        match cluster.send(payer, &[ix]) {
+            Ok(signature) => {
+                println!(
+                    "user {}: recorded round {} in the house ({})",
+                    address, round_id, signature
+                );
+                recorded += 1;
+            }
+            Err(e) => eprintln!(
+                "user {}: failed to record round {}: {}",
+                address, round_id, e
+            ),
+        }
+    }
+
+    Ok(recorded)
+}
+
+/// Places the next bet of every autoplay session whose last round settled,
+/// returns how many were placed. The house fee vault pays for the randomness.
+pub fn place_autoplay_bets(cluster: &impl Cluster, payer: &Keypair) -> anyhow::Result<usize> {
+    let idle: Vec<(Pubkey, UserState)> = decoded_user_states(cluster)?
+        .into_iter()
+        .filter(|(_, user)| ready_to_autoplay(user))
+        .collect();
+
+    // houses and oracle queues are shared between users, fetched once per scan
+    let mut houses: HashMap<Pubkey, HouseState> = HashMap::new();
+    let mut queues: HashMap<Pubkey, OracleQueueAccountData> = HashMap::new();
+    let mut placed = 0;
+    for batch in idle.chunks(VRF_BATCH_SIZE) {
+        let vrf_keys: Vec<Pubkey> = batch.iter().map(|(_, user)| user.vrf).collect();
+        let vrf_accounts = cluster.multiple_accounts(&vrf_keys)?;
+
+        for ((address, user), vrf_account) in batch.iter().zip(vrf_accounts) {
+            let Some(vrf_account) = vrf_account else {
+                eprintln!("user {}: vrf account not found", address);
+                continue;
+            };
+            let vrf = match decode_vrf(&vrf_account.data) {
+                Ok(vrf) => vrf,
+                Err(e) => {
+                    eprintln!("user {}: {}", address, e);
+                    continue;
+                }
+            };
+
+            let user_accounts = UserAccounts::from_state(address, user);
+            let house = match houses.get(&user_accounts.house) {
+                Some(house) => *house,
+                None => match cluster.house_state(&user_accounts.house) {
+                    Ok(house) => {
+                        houses.insert(user_accounts.house, house);
+                        house
+                    }
+                    Err(e) => {
+                        eprintln!("user {}: {}", address, e);
+                        continue;
+                    }
+                },
+            };
+            if !house.sponsors_vrf_fees() {
+                eprintln!(
+                    "user {}: house {} no longer sponsors vrf fees",
+                    address, user_accounts.house
+                );
+                continue;
+            }
+
+            let queue = match queues.get(&vrf.oracle_queue) {
+                Some(queue) => *queue,
+                None => match load_oracle_queue(cluster, &vrf.oracle_queue) {
+                    Ok(queue) => {
+                        queues.insert(vrf.oracle_queue, queue);
+                        queue
+                    }
+                    Err(e) => {
+                        eprintln!("user {}: {}", address, e);
+                        continue;
+                    }
+                },
+            };
+            let switchboard = SwitchboardAccounts::resolve(&user.vrf, &vrf, &queue);
+
+            // the user pays for their history pages, the bet fails until the page exists
+            let history_page = history_page_for_bet(address, user).map(|(page, _)| page);
+            if let Some(history_page) = history_page {
+                if !matches!(cluster.multiple_accounts(&[history_page])?[..], [Some(_)]) {
+                    eprintln!("user {}: history page {} not found", address, history_page);
+                    continue;
+                }
+            }
+
+            let ix = user_autoplay_bet(
+                &user_accounts,
+                &house.house_vault,
+                &house.fee_vault,
+                &switchboard,
+                history_page,
+            );
+            let remaining_bets = user.autoplay.remaining_bets;
+            match cluster.send(payer, &[ix]) {
+                Ok(signature) => {
+                    println!(
+                        "user {}: placed autoplay bet, {} left ({})",
+                        address,
+                        remaining_bets - 1,
+                        signature
+                    );
+                    placed += 1;
+                }
+                Err(e) => eprintln!("user {}: failed to place autoplay bet: {}", address, e),
+            }
+        }
+    }
+
+    Ok(placed)
+}
+
+fn load_oracle_queue(
+    cluster: &impl Cluster,
+    oracle_queue: &Pubkey,
+) -> anyhow::Result<OracleQueueAccountData> {
+    match &cluster.multiple_accounts(&[*oracle_queue])?[..] {
+        [Some(account)] => Ok(decode_oracle_queue(&account.data)?),
+        _ => Err(anyhow::anyhow!("oracle queue {} not found", oracle_queue)),
+    }
+}
@@ -0,0 +1,60 @@
+//! Runs the keeper crank against an RPC node on an interval.
+//!
+//! ```bash
+//! vrf-flip-keeper --rpc-url http://localhost:8899 --interval 5
+//! ```
+use anyhow::anyhow;
+use clap::Parser;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, Keypair},
+};
+use std::{thread, time::Duration};
+use vrf_flip_keeper::crank;
+
+#[derive(Parser)]
+#[command(
+    name = "vrf-flip-keeper",
+    about = "Settle VRF Flip rounds and place autoplay bets"
+)]
+struct Args {
+    #[arg(long, short = 'u', default_value = "http://localhost:8899")]
+    rpc_url: String,
+    /// Filesystem path to the Solana keypair that pays for the crank's transactions
+    #[arg(long, short, default_value = "~/.config/solana/id.json")]
+    keypair: String,
+    /// Seconds to wait between scans
+    #[arg(long, default_value_t = 5)]
+    interval: u64,
+    /// Scan once and exit
+    #[arg(long)]
+    once: bool,
+}
+
+fn load_keypair(path: &str) -> anyhow::Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{}", std::env::var("HOME")?, rest),
+        None => path.to_string(),
+    };
+    read_keypair_file(&path).map_err(|e| anyhow!("failed to read keypair {}: {}", path, e))
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let rpc = RpcClient::new_with_commitment(args.rpc_url.clone(), CommitmentConfig::confirmed());
+    let payer = load_keypair(&args.keypair)?;
+
+    loop {
+        match crank(&rpc, &payer) {
+            Ok(sent) if args.once => {
+                println!("sent {} transactions", sent);
+                return Ok(());
+            }
+            Ok(_) => {}
+            Err(e) if args.once => return Err(e),
+            Err(e) => eprintln!("scan failed: {}", e),
+        }
+        thread::sleep(Duration::from_secs(args.interval));
+    }
+}
@@ -0,0 +1,235 @@
+// drives the crank against the program harness, with mock_switchboard standing in for the oracle
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+};
+use std::cell::RefCell;
+use switchboard_vrf_flip::UserAutoplayStartParams;
+use tokio::runtime::{Builder, Runtime};
+use vrf_flip_client::{decode_house_state, decode_user_state, HouseState, RoundStatus, UserState};
+use vrf_flip_keeper::{crank, Cluster};
+use vrf_flip_test_harness::*;
+
+/// Serves the crank from a program-test bank
+struct BankCluster {
+    runtime: Runtime,
+    harness: RefCell<Harness>,
+    users: Vec<Pubkey>,
+}
+
+impl Cluster for BankCluster {
+    fn user_states(&self) -> anyhow::Result<Vec<(Pubkey, vrf_flip_client::Result<UserState>)>> {
+        let mut harness = self.harness.borrow_mut();
+        let user_states = self
+            .users
+            .iter()
+            .map(|user| {
+                let data = self.runtime.block_on(harness.account_data(user));
+                (*user, decode_user_state(&data))
+            })
+            .collect();
+        Ok(user_states)
+    }
+
+    fn multiple_accounts(&self, addresses: &[Pubkey]) -> anyhow::Result<Vec<Option<Account>>> {
+        let mut harness = self.harness.borrow_mut();
+        addresses
+            .iter()
+            .map(|address| {
+                let account = self
+                    .runtime
+                    .block_on(harness.context.banks_client.get_account(*address))?;
+                Ok(account)
+            })
+            .collect()
+    }
+
+    fn house_state(&self, house: &Pubkey) -> anyhow::Result<HouseState> {
+        let mut harness = self.harness.borrow_mut();
+        let data = self.runtime.block_on(harness.account_data(house));
+        Ok(decode_house_state(&data)?)
+    }
+
+    fn slot(&self) -> anyhow::Result<u64> {
+        let mut harness = self.harness.borrow_mut();
+        Ok(self.runtime.block_on(harness.clock()).slot)
+    }
+
+    // the harness payer signs every transaction, the tests hand it to the crank as well
+    fn send(&self, _payer: &Keypair, instructions: &[Instruction]) -> anyhow::Result<Signature> {
+        let mut harness = self.harness.borrow_mut();
+        self.runtime.block_on(harness.process(instructions, &[]))?;
+        Ok(Signature::default())
+    }
+}
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+#[test]
+fn test_crank_settles_fulfilled_round() {
+    let runtime = runtime();
+    let mut harness = runtime.block_on(Harness::new());
+    let player = runtime.block_on(harness.create_player());
+    runtime
+        .block_on(harness.bet(&player, COIN_FLIP, 1, TOKEN))
+        .unwrap();
+
+    let payer = harness.context.payer.insecure_clone();
+    let cluster = BankCluster {
+        runtime,
+        harness: RefCell::new(harness),
+        users: vec![player.user],
+    };
+
+    // nothing is ready until the oracle fulfills the request
+    assert_eq!(crank(&cluster, &payer).unwrap(), 0);
+
+    {
+        let mut harness = cluster.harness.borrow_mut();
+        cluster
+            .runtime
+            .block_on(harness.set_vrf_result(&player, vrf_result_for(1, 2)));
+    }
+    assert_eq!(crank(&cluster, &payer).unwrap(), 1);
+
+    {
+        let mut harness = cluster.harness.borrow_mut();
+        let user = cluster.runtime.block_on(harness.user_state(&player));
+        let result = user.current_round.result;
+        assert!(user.current_round.status == RoundStatus::Settled);
+        assert_eq!(result, 1);
+        assert_eq!(
+            cluster
+                .runtime
+                .block_on(harness.token_balance(&player.reward_address)),
+            11 * TOKEN
+        );
+    }
+
+    // settled rounds are skipped on the next scan
+    assert_eq!(crank(&cluster, &payer).unwrap(), 0);
+}
+
+#[test]
+fn test_crank_skips_accounts_that_fail_to_decode() {
+    let runtime = runtime();
+    let mut harness = runtime.block_on(Harness::new());
+    let player = runtime.block_on(harness.create_player());
+    runtime
+        .block_on(harness.bet(&player, COIN_FLIP, 1, TOKEN))
+        .unwrap();
+    runtime.block_on(harness.set_vrf_result(&player, vrf_result_for(1, 2)));
+
+    let payer = harness.context.payer.insecure_clone();
+    // the house is owned by the program but isn't a user
+    let users = vec![harness.house, player.user];
+    let cluster = BankCluster {
+        runtime,
+        harness: RefCell::new(harness),
+        users,
+    };
+
+    assert_eq!(crank(&cluster, &payer).unwrap(), 1);
+}
+
+#[test]
+fn test_crank_records_callback_settles() {
+    let runtime = runtime();
+    let mut harness = runtime.block_on(Harness::new());
+    let player = runtime.block_on(harness.create_player());
+    runtime
+        .block_on(harness.bet(&player, COIN_FLIP, 1, TOKEN))
+        .unwrap();
+    runtime.block_on(harness.set_vrf_result(&player, vrf_result_for(1, 2)));
+    runtime.block_on(harness.settle_callback(&player)).unwrap();
+
+    let payer = harness.context.payer.insecure_clone();
+    let cluster = BankCluster {
+        runtime,
+        harness: RefCell::new(harness),
+        users: vec![player.user],
+    };
+
+    // the callback settled the round with a read-only house
+    assert_eq!(crank(&cluster, &payer).unwrap(), 1);
+
+    {
+        let mut harness = cluster.harness.borrow_mut();
+        let user = cluster.runtime.block_on(harness.user_state(&player));
+        let house = cluster.runtime.block_on(harness.house_state());
+        assert_eq!({ user.house_settle_pending }, 0);
+        assert_eq!({ house.open_liability }, 0);
+    }
+
+    assert_eq!(crank(&cluster, &payer).unwrap(), 0);
+}
+
+#[test]
+fn test_crank_places_autoplay_bets() {
+    let runtime = runtime();
+    let mut harness = runtime.block_on(Harness::new());
+    runtime.block_on(harness.sponsor_vrf_fees(0)).unwrap();
+    let player = runtime.block_on(harness.create_player());
+    runtime
+        .block_on(harness.user_autoplay_start(
+            &player,
+            UserAutoplayStartParams {
+                game_type: COIN_FLIP,
+                user_guess: 1,
+                bet_amount: TOKEN,
+                num_bets: 2,
+                stop_loss: 0,
+                take_profit: 0,
+            },
+        ))
+        .unwrap();
+
+    let payer = harness.context.payer.insecure_clone();
+    let cluster = BankCluster {
+        runtime,
+        harness: RefCell::new(harness),
+        users: vec![player.user],
+    };
+
+    // the first bet goes out right away, the next waits for it to settle
+    assert_eq!(crank(&cluster, &payer).unwrap(), 1);
+    assert_eq!(crank(&cluster, &payer).unwrap(), 0);
+
+    // settling the first round and placing the second happen in one scan
+    {
+        let mut harness = cluster.harness.borrow_mut();
+        cluster
+            .runtime
+            .block_on(harness.set_vrf_result(&player, vrf_result_for(2, 2)));
+    }
+    assert_eq!(crank(&cluster, &payer).unwrap(), 2);
+
+    {
+        let mut harness = cluster.harness.borrow_mut();
+        cluster
+            .runtime
+            .block_on(harness.set_vrf_result(&player, vrf_result_for(2, 2)));
+    }
+    assert_eq!(crank(&cluster, &payer).unwrap(), 1);
+
+    let mut harness = cluster.harness.borrow_mut();
+    let user = cluster.runtime.block_on(harness.user_state(&player));
+    let remaining_bets = user.autoplay.remaining_bets;
+    assert!(!user.autoplay.is_active());
+    assert!(user.current_round.status == RoundStatus::Settled);
+    assert_eq!(remaining_bets, 0);
+    assert_eq!(
+        cluster
+            .runtime
+            .block_on(harness.token_balance(&player.escrow.pubkey())),
+        0
+    );
+    drop(harness);
+
+    // the session is over, nothing is left to crank
+    assert_eq!(crank(&cluster, &payer).unwrap(), 0);
+}
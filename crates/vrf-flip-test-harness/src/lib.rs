@@ -0,0 +1,1298 @@
+//! Offline test harness for the vrf-flip program.
+//!
+//! Runs the program against `mock_switchboard` deployed at
+//! `SWITCHBOARD_PROGRAM_ID`. The Switchboard queue, program state, permission
+//! and VRF accounts are written directly as fixtures so no network access is
+//! needed.
+//!
+//! Shared by the program's tests, the keeper's tests and the fuzz target.
+
+use anchor_lang::{AccountDeserialize, Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address},
+    token::spl_token::{
+        self,
+        state::{Account as SplAccount, AccountState, Mint as SplMint},
+    },
+};
+use bytemuck::Pod;
+use solana_program::{
+    clock::Clock,
+    hash::Hash,
+    program_option::COption,
+    program_pack::Pack,
+    rent::Rent,
+    slot_hashes::{SlotHashes, MAX_ENTRIES},
+    sysvar,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use switchboard_v2::{
+    OracleQueueAccountData, PermissionAccountData, SbState, VrfAccountData, SWITCHBOARD_PROGRAM_ID,
+};
+use switchboard_vrf_flip::{
+    accounts, instruction, HouseCommitParams, HouseInitFeeVaultParams, HouseInitNativeParams,
+    HouseInitParams, HouseRevealParams, HouseState, HouseUpdateParams, UserAirdropParams,
+    UserAutoplayBetParams, UserAutoplayStartParams, UserAutoplayStopParams, UserBetParams,
+    UserCloseParams, UserHistoryPage, UserHistoryPageCloseParams, UserHistoryPageInitParams,
+    UserInitParams, UserMigrateParams, UserRecordSettleParams, UserSetDelegateParams,
+    UserSetLimitsParams, UserSettleParams, UserState, UserWithdrawEscrowParams, VrfFlipError,
+    FEE_VAULT_SEED, HISTORY_PAGE_SEED, HOUSE_SEED, UNWRAP_SEED, USER_SEED,
+};
+
+pub const COIN_FLIP: u32 = 1;
+pub const SIX_SIDED_DICE_ROLL: u32 = 2;
+pub const TWENTY_SIDED_DICE_ROLL: u32 = 3;
+
+pub const TOKEN: u64 = 1_000_000_000;
+
+// enough switchboard tokens for a handful of vrf requests
+pub const VRF_PAYER_BALANCE: u64 = 100_000_000;
+
+fn process_flip_instruction(
+    program_id: &Pubkey,
+    accounts: &[solana_program::account_info::AccountInfo],
+    data: &[u8],
+) -> solana_program::entrypoint::ProgramResult {
+    // anchor's entry point requires the accounts to share the 'info lifetime
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    switchboard_vrf_flip::entry(program_id, accounts, data)
+}
+
+pub struct SwitchboardFixture {
+    pub mint: Pubkey,
+    pub mint_authority: Keypair,
+    pub queue: Pubkey,
+    pub queue_authority: Pubkey,
+    pub data_buffer: Pubkey,
+    pub program_state: Pubkey,
+}
+
+pub struct Player {
+    pub authority: Keypair,
+    pub user: Pubkey,
+    pub escrow: Keypair,
+    pub reward_address: Pubkey,
+    pub vrf: Pubkey,
+    pub vrf_escrow: Pubkey,
+    pub vrf_payer: Pubkey,
+    pub permission: Pubkey,
+}
+
+pub struct Harness {
+    pub context: ProgramTestContext,
+    pub switchboard: SwitchboardFixture,
+    pub house_authority: Keypair,
+    pub house: Pubkey,
+    pub mint: Pubkey,
+    pub house_vault: Pubkey,
+    pub mint_keypair: Keypair,
+}
+
+fn zero_copy_data<T: Discriminator + Pod>(state: &T) -> Vec<u8> {
+    let mut data = T::discriminator().to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+    data
+}
+
+fn program_account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn token_account_data(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0u8; SplAccount::LEN];
+    SplAccount::pack(
+        SplAccount {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..SplAccount::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+    data
+}
+
+pub fn program_test() -> (ProgramTest, SwitchboardFixture) {
+    let mut program_test = ProgramTest::new(
+        "switchboard_vrf_flip",
+        switchboard_vrf_flip::ID,
+        processor!(process_flip_instruction),
+    );
+    program_test.add_program(
+        "mock_switchboard",
+        SWITCHBOARD_PROGRAM_ID,
+        processor!(mock_switchboard::process_instruction),
+    );
+
+    let switchboard = SwitchboardFixture {
+        mint: Pubkey::new_unique(),
+        mint_authority: Keypair::new(),
+        queue: Pubkey::new_unique(),
+        queue_authority: Pubkey::new_unique(),
+        data_buffer: Pubkey::new_unique(),
+        program_state: Pubkey::new_unique(),
+    };
+
+    program_test.add_packable_account(
+        switchboard.mint,
+        1_000_000_000,
+        &SplMint {
+            mint_authority: COption::Some(switchboard.mint_authority.pubkey()),
+            supply: 0,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        },
+        &spl_token::ID,
+    );
+
+    let mut queue: OracleQueueAccountData = bytemuck::Zeroable::zeroed();
+    queue.authority = switchboard.queue_authority;
+    queue.data_buffer = switchboard.data_buffer;
+    queue.unpermissioned_vrf_enabled = true;
+    program_test.add_account(
+        switchboard.queue,
+        program_account(zero_copy_data(&queue), SWITCHBOARD_PROGRAM_ID),
+    );
+
+    program_test.add_account(
+        switchboard.data_buffer,
+        program_account(vec![0u8; 64], SWITCHBOARD_PROGRAM_ID),
+    );
+
+    let program_state: SbState = bytemuck::Zeroable::zeroed();
+    program_test.add_account(
+        switchboard.program_state,
+        program_account(zero_copy_data(&program_state), SWITCHBOARD_PROGRAM_ID),
+    );
+
+    (program_test, switchboard)
+}
+
+impl Harness {
+    pub async fn new() -> Self {
+        let mut harness = Self::start().await;
+        harness.house_init().await.unwrap();
+        harness
+    }
+
+    /// Starts the test validator with a house that takes native SOL, `liquidity`
+    /// lamports are wrapped into the house vault
+    pub async fn new_native(liquidity: u64) -> Self {
+        let mut harness = Self::start().await;
+        harness.mint = spl_token::native_mint::ID;
+        harness.house = house_address(&harness.mint);
+        harness.house_vault = get_associated_token_address(&harness.house, &harness.mint);
+
+        let mut data = vec![0u8; SplMint::LEN];
+        SplMint::pack(
+            SplMint {
+                mint_authority: COption::None,
+                supply: 0,
+                decimals: 9,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            },
+            &mut data,
+        )
+        .unwrap();
+        harness.context.set_account(
+            &spl_token::native_mint::ID,
+            &AccountSharedData::from(program_account(data, spl_token::ID)),
+        );
+
+        harness.house_init_native(liquidity).await.unwrap();
+        harness
+    }
+
+    /// Starts the test validator with a house at the legacy PDA seeded by HOUSE_SEED
+    /// alone, written as a fixture since house_init no longer creates one
+    pub async fn new_legacy() -> Self {
+        let mut harness = Self::new().await;
+        let (house, bump) = Pubkey::find_program_address(&[HOUSE_SEED], &switchboard_vrf_flip::ID);
+        let mint = harness.mint;
+        let house_vault = get_associated_token_address(&house, &mint);
+        let old_house_vault = harness.house_vault;
+        let vault_balance = harness.token_balance(&old_house_vault).await;
+
+        let mut house_state = harness.house_state().await;
+        house_state.bump = bump;
+        house_state.house_vault = house_vault;
+        house_state.mint_seeded = 0;
+        harness.context.set_account(
+            &house,
+            &AccountSharedData::from(program_account(
+                zero_copy_data(&house_state),
+                switchboard_vrf_flip::ID,
+            )),
+        );
+        harness.set_token_account(&house_vault, mint, house, vault_balance);
+
+        harness.house = house;
+        harness.house_vault = house_vault;
+        harness.set_mint_authority(Some(house));
+        harness
+    }
+
+    /// Starts the test validator without initializing the house
+    pub async fn start() -> Self {
+        let (program_test, switchboard) = program_test();
+        let context = program_test.start_with_context().await;
+
+        let mint = Keypair::new();
+        let house = house_address(&mint.pubkey());
+        let house_vault = get_associated_token_address(&house, &mint.pubkey());
+
+        Self {
+            context,
+            switchboard,
+            house_authority: Keypair::new(),
+            house,
+            mint: mint.pubkey(),
+            house_vault,
+            mint_keypair: mint,
+        }
+    }
+
+    pub async fn house_init(&mut self) -> Result<(), BanksClientError> {
+        let ix = self.house_init_ix(&self.mint);
+        let house_authority = self.house_authority.insecure_clone();
+        let mint = self.mint_keypair.insecure_clone();
+        self.process(&[ix], &[&house_authority, &mint]).await
+    }
+
+    pub fn house_init_ix(&self, mint: &Pubkey) -> Instruction {
+        let house = house_address(mint);
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::HouseInit {
+                house,
+                authority: self.house_authority.pubkey(),
+                switchboard_mint: self.switchboard.mint,
+                switchboard_queue: self.switchboard.queue,
+                mint: *mint,
+                house_vault: get_associated_token_address(&house, mint),
+                payer: self.context.payer.pubkey(),
+                system_program: solana_program::system_program::ID,
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::HouseInit {
+                params: HouseInitParams {},
+            }
+            .data(),
+        }
+    }
+
+    pub async fn house_init_native(&mut self, liquidity: u64) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::HouseInitNative {
+                house: self.house,
+                authority: self.house_authority.pubkey(),
+                switchboard_mint: self.switchboard.mint,
+                switchboard_queue: self.switchboard.queue,
+                mint: spl_token::native_mint::ID,
+                house_vault: get_associated_token_address(&self.house, &spl_token::native_mint::ID),
+                payer: self.context.payer.pubkey(),
+                system_program: solana_program::system_program::ID,
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::HouseInitNative {
+                params: HouseInitNativeParams { liquidity },
+            }
+            .data(),
+        };
+        let house_authority = self.house_authority.insecure_clone();
+        self.process(&[ix], &[&house_authority]).await
+    }
+
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self
+            .context
+            .banks_client
+            .get_new_latest_blockhash(&self.context.last_blockhash)
+            .await
+            .unwrap();
+        self.context.last_blockhash = blockhash;
+
+        let mut all_signers: Vec<&Keypair> = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    pub async fn airdrop_sol(&mut self, to: &Pubkey, lamports: u64) {
+        let ix = system_instruction::transfer(&self.context.payer.pubkey(), to, lamports);
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    pub fn set_token_account(
+        &mut self,
+        address: &Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) {
+        let account = program_account(token_account_data(mint, owner, amount), spl_token::ID);
+        self.context
+            .set_account(address, &AccountSharedData::from(account));
+    }
+
+    /// Writes a wrapped SOL token account holding `amount`, backed by as many lamports
+    pub fn set_native_token_account(&mut self, address: &Pubkey, owner: Pubkey, amount: u64) {
+        let rent_exempt_reserve = Rent::default().minimum_balance(SplAccount::LEN);
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount::pack(
+            SplAccount {
+                mint: spl_token::native_mint::ID,
+                owner,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::Some(rent_exempt_reserve),
+                ..SplAccount::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        let account = Account {
+            lamports: rent_exempt_reserve + amount,
+            data,
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.context
+            .set_account(address, &AccountSharedData::from(account));
+    }
+
+    pub fn user_address(&self, authority: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[USER_SEED, self.house.as_ref(), authority.as_ref()],
+            &switchboard_vrf_flip::ID,
+        )
+        .0
+    }
+
+    /// The account a native house unwraps the player's payouts through
+    pub fn unwrap_address(&self, player: &Player) -> Pubkey {
+        Pubkey::find_program_address(
+            &[UNWRAP_SEED, player.user.as_ref()],
+            &switchboard_vrf_flip::ID,
+        )
+        .0
+    }
+
+    /// Writes the switchboard fixtures for a new player and runs user_init
+    pub async fn create_player(&mut self) -> Player {
+        let player = self.prepare_player().await;
+        self.user_init(&player).await.unwrap();
+        player
+    }
+
+    /// Writes the switchboard fixtures for a new player without running user_init
+    pub async fn prepare_player(&mut self) -> Player {
+        let authority = Keypair::new();
+        self.airdrop_sol(&authority.pubkey(), 1_000_000_000).await;
+
+        let user = self.user_address(&authority.pubkey());
+        let reward_address = get_associated_token_address(&authority.pubkey(), &self.mint);
+
+        let vrf = Pubkey::new_unique();
+        let vrf_escrow = Pubkey::new_unique();
+        let vrf_payer = Pubkey::new_unique();
+        let permission = Pubkey::new_unique();
+
+        let mut vrf_state: VrfAccountData = bytemuck::Zeroable::zeroed();
+        vrf_state.authority = user;
+        vrf_state.oracle_queue = self.switchboard.queue;
+        vrf_state.escrow = vrf_escrow;
+        self.set_vrf_state(&vrf, &vrf_state);
+
+        let permission_state: PermissionAccountData = bytemuck::Zeroable::zeroed();
+        self.context.set_account(
+            &permission,
+            &AccountSharedData::from(program_account(
+                zero_copy_data(&permission_state),
+                SWITCHBOARD_PROGRAM_ID,
+            )),
+        );
+
+        let switchboard_mint = self.switchboard.mint;
+        let program_state = self.switchboard.program_state;
+        self.set_token_account(&vrf_escrow, switchboard_mint, program_state, 0);
+        self.set_token_account(
+            &vrf_payer,
+            switchboard_mint,
+            authority.pubkey(),
+            VRF_PAYER_BALANCE,
+        );
+
+        Player {
+            authority,
+            user,
+            escrow: Keypair::new(),
+            reward_address,
+            vrf,
+            vrf_escrow,
+            vrf_payer,
+            permission,
+        }
+    }
+
+    pub async fn user_init(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserInit {
+                user: player.user,
+                house: self.house,
+                mint: self.mint,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                reward_address: player.reward_address,
+                vrf: player.vrf,
+                payer: self.context.payer.pubkey(),
+                system_program: solana_program::system_program::ID,
+                token_program: spl_token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::UserInit {
+                params: UserInitParams {
+                    switchboard_state_bump: 255,
+                    vrf_permission_bump: 255,
+                },
+            }
+            .data(),
+        };
+        self.process(&[ix], &[&player.authority, &player.escrow])
+            .await
+    }
+
+    pub fn set_vrf_state(&mut self, vrf: &Pubkey, vrf_state: &VrfAccountData) {
+        self.context.set_account(
+            vrf,
+            &AccountSharedData::from(program_account(
+                zero_copy_data(vrf_state),
+                SWITCHBOARD_PROGRAM_ID,
+            )),
+        );
+    }
+
+    pub fn set_queue_state(&mut self, queue_state: &OracleQueueAccountData) {
+        let queue = self.switchboard.queue;
+        self.context.set_account(
+            &queue,
+            &AccountSharedData::from(program_account(
+                zero_copy_data(queue_state),
+                SWITCHBOARD_PROGRAM_ID,
+            )),
+        );
+    }
+
+    pub fn set_mint_authority(&mut self, authority: Option<Pubkey>) {
+        let mut data = vec![0u8; SplMint::LEN];
+        SplMint::pack(
+            SplMint {
+                mint_authority: authority.map(COption::Some).unwrap_or(COption::None),
+                supply: u64::MAX / 2,
+                decimals: 9,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            },
+            &mut data,
+        )
+        .unwrap();
+        let mint = self.mint;
+        self.context.set_account(
+            &mint,
+            &AccountSharedData::from(program_account(data, spl_token::ID)),
+        );
+    }
+
+    pub fn user_bet_ix(&self, player: &Player, params: UserBetParams) -> Instruction {
+        self.user_bet_with_history_page_ix(player, params, None)
+    }
+
+    pub fn user_bet_with_history_page_ix(
+        &self,
+        player: &Player,
+        params: UserBetParams,
+        history_page: Option<Pubkey>,
+    ) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserBet {
+                user: player.user,
+                house: self.house,
+                house_vault: self.house_vault,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                vrf: player.vrf,
+                oracle_queue: self.switchboard.queue,
+                queue_authority: self.switchboard.queue_authority,
+                data_buffer: self.switchboard.data_buffer,
+                permission: player.permission,
+                vrf_escrow: player.vrf_escrow,
+                switchboard_program_state: self.switchboard.program_state,
+                switchboard_program: SWITCHBOARD_PROGRAM_ID,
+                payer: player.authority.pubkey(),
+                vrf_payer: player.vrf_payer,
+                flip_payer: player.reward_address,
+                recent_blockhashes: sysvar::recent_blockhashes::ID,
+                system_program: solana_program::system_program::ID,
+                token_program: spl_token::ID,
+                history_page,
+                unwrap_account: Some(self.unwrap_address(player)),
+                native_mint: Some(spl_token::native_mint::ID),
+            }
+            .to_account_metas(None),
+            data: instruction::UserBet { params }.data(),
+        }
+    }
+
+    /// A bet signed by the player's delegate, paid from the player's reward address
+    /// with the delegate's switchboard tokens paying for the randomness
+    pub fn user_bet_delegate_ix(
+        &self,
+        player: &Player,
+        delegate: &Pubkey,
+        params: UserBetParams,
+    ) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserBetDelegate {
+                user: player.user,
+                house: self.house,
+                house_vault: self.house_vault,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                reward_address: player.reward_address,
+                vrf: player.vrf,
+                oracle_queue: self.switchboard.queue,
+                queue_authority: self.switchboard.queue_authority,
+                data_buffer: self.switchboard.data_buffer,
+                permission: player.permission,
+                vrf_escrow: player.vrf_escrow,
+                switchboard_program_state: self.switchboard.program_state,
+                switchboard_program: SWITCHBOARD_PROGRAM_ID,
+                delegate: *delegate,
+                vrf_payer: get_associated_token_address(delegate, &self.switchboard.mint),
+                recent_blockhashes: sysvar::recent_blockhashes::ID,
+                token_program: spl_token::ID,
+                history_page: None,
+            }
+            .to_account_metas(None),
+            data: instruction::UserBetDelegate { params }.data(),
+        }
+    }
+
+    /// Gives a delegate or keeper the flip and switchboard tokens it needs to place bets
+    pub fn fund_wallets(&mut self, owner: &Pubkey, amount: u64) {
+        let (mint, switchboard_mint) = (self.mint, self.switchboard.mint);
+        self.set_token_account(
+            &get_associated_token_address(owner, &mint),
+            mint,
+            *owner,
+            amount,
+        );
+        self.set_token_account(
+            &get_associated_token_address(owner, &switchboard_mint),
+            switchboard_mint,
+            *owner,
+            VRF_PAYER_BALANCE,
+        );
+    }
+
+    pub async fn bet(
+        &mut self,
+        player: &Player,
+        game_type: u32,
+        user_guess: u32,
+        bet_amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.user_bet_ix(
+            player,
+            UserBetParams {
+                game_type,
+                user_guess,
+                bet_amount,
+            },
+        );
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    /// Has the mock oracle fulfill the player's pending VRF request
+    pub async fn set_vrf_result(&mut self, player: &Player, result: [u8; 32]) {
+        let mut data = mock_switchboard::MOCK_SET_RESULT_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&result);
+        let ix = Instruction {
+            program_id: SWITCHBOARD_PROGRAM_ID,
+            accounts: vec![solana_sdk::instruction::AccountMeta::new(player.vrf, false)],
+            data,
+        };
+        self.process(&[ix], &[]).await.unwrap();
+    }
+
+    pub fn user_settle_ix(&self, player: &Player) -> Instruction {
+        self.user_settle_with_history_page_ix(player, None)
+    }
+
+    pub fn user_settle_with_history_page_ix(
+        &self,
+        player: &Player,
+        history_page: Option<Pubkey>,
+    ) -> Instruction {
+        let mut ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserSettle {
+                user: player.user,
+                house: self.house,
+                escrow: player.escrow.pubkey(),
+                reward_address: player.reward_address,
+                house_vault: self.house_vault,
+                vrf: player.vrf,
+                token_program: spl_token::ID,
+                history_page,
+                unwrap_account: Some(self.unwrap_address(player)),
+                authority: Some(player.authority.pubkey()),
+            }
+            .to_account_metas(None),
+            data: instruction::UserSettle {
+                params: UserSettleParams {},
+            }
+            .data(),
+        };
+        // settling outside the vrf callback records the round in the house right away
+        set_writable(&mut ix, &self.house, true);
+        ix
+    }
+
+    pub async fn settle(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = self.user_settle_ix(player);
+        self.process(&[ix], &[]).await
+    }
+
+    /// Settles the way the vrf callback does, with the house read-only
+    pub async fn settle_callback(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let mut ix = self.user_settle_ix(player);
+        set_writable(&mut ix, &self.house, false);
+        self.process(&[ix], &[]).await
+    }
+
+    pub async fn user_record_settle(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserRecordSettle {
+                user: player.user,
+                house: self.house,
+            }
+            .to_account_metas(None),
+            data: instruction::UserRecordSettle {
+                params: UserRecordSettleParams {},
+            }
+            .data(),
+        };
+        self.process(&[ix], &[]).await
+    }
+
+    pub fn user_airdrop_ix(&self, player: &Player) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserAirdrop {
+                user: player.user,
+                house: self.house,
+                house_vault: self.house_vault,
+                mint: self.mint,
+                authority: player.authority.pubkey(),
+                airdrop_token_wallet: player.reward_address,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::UserAirdrop {
+                params: UserAirdropParams {},
+            }
+            .data(),
+        }
+    }
+
+    pub async fn airdrop(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = self.user_airdrop_ix(player);
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub async fn account_data(&mut self, address: &Pubkey) -> Vec<u8> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .expect("account does not exist")
+            .data
+    }
+
+    pub async fn user_state(&mut self, player: &Player) -> UserState {
+        let data = self.account_data(&player.user).await;
+        UserState::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    pub async fn house_state(&mut self) -> HouseState {
+        let house = self.house;
+        let data = self.account_data(&house).await;
+        HouseState::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    pub async fn vrf_state(&mut self, player: &Player) -> VrfAccountData {
+        let data = self.account_data(&player.vrf).await;
+        *bytemuck::from_bytes(&data[8..8 + std::mem::size_of::<VrfAccountData>()])
+    }
+
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        self.token_account(address).await.amount
+    }
+
+    pub async fn token_account(&mut self, address: &Pubkey) -> SplAccount {
+        let data = self.account_data(address).await;
+        SplAccount::unpack(&data).unwrap()
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(*address)
+            .await
+            .unwrap()
+    }
+
+    pub fn fee_vault_address(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[FEE_VAULT_SEED, self.house.as_ref()],
+            &switchboard_vrf_flip::ID,
+        )
+        .0
+    }
+
+    pub async fn house_update(
+        &mut self,
+        params: HouseUpdateParams,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::HouseUpdate {
+                house: self.house,
+                authority: self.house_authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::HouseUpdate { params }.data(),
+        };
+        let house_authority = self.house_authority.insecure_clone();
+        self.process(&[ix], &[&house_authority]).await
+    }
+
+    pub async fn house_init_fee_vault(&mut self) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::HouseInitFeeVault {
+                house: self.house,
+                authority: self.house_authority.pubkey(),
+                switchboard_mint: self.switchboard.mint,
+                fee_vault: self.fee_vault_address(),
+                payer: self.context.payer.pubkey(),
+                system_program: solana_program::system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::HouseInitFeeVault {
+                params: HouseInitFeeVaultParams {},
+            }
+            .data(),
+        };
+        let house_authority = self.house_authority.insecure_clone();
+        self.process(&[ix], &[&house_authority]).await
+    }
+
+    /// Creates and funds the fee vault, then has the house pay for randomness for `vrf_fee`
+    pub async fn sponsor_vrf_fees(&mut self, vrf_fee: u64) -> Result<(), BanksClientError> {
+        self.house_init_fee_vault().await?;
+        let (fee_vault, house, switchboard_mint) =
+            (self.fee_vault_address(), self.house, self.switchboard.mint);
+        self.set_token_account(&fee_vault, switchboard_mint, house, VRF_PAYER_BALANCE);
+        self.house_update(HouseUpdateParams {
+            sponsor_vrf_fees: Some(true),
+            vrf_fee: Some(vrf_fee),
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+    }
+
+    pub async fn house_commit(
+        &mut self,
+        player: &Player,
+        commitment: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::HouseCommit {
+                user: player.user,
+                house: self.house,
+                authority: self.house_authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::HouseCommit {
+                params: HouseCommitParams { commitment },
+            }
+            .data(),
+        };
+        let house_authority = self.house_authority.insecure_clone();
+        self.process(&[ix], &[&house_authority]).await
+    }
+
+    pub async fn house_reveal(
+        &mut self,
+        player: &Player,
+        secret: [u8; 32],
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::HouseReveal {
+                user: player.user,
+                house: self.house,
+                authority: self.house_authority.pubkey(),
+                slot_hashes: sysvar::slot_hashes::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::HouseReveal {
+                params: HouseRevealParams { secret },
+            }
+            .data(),
+        };
+        let house_authority = self.house_authority.insecure_clone();
+        self.process(&[ix], &[&house_authority]).await
+    }
+
+    pub async fn bet_commit_reveal(
+        &mut self,
+        player: &Player,
+        game_type: u32,
+        user_guess: u32,
+        bet_amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserBetCommitReveal {
+                user: player.user,
+                house: self.house,
+                house_vault: self.house_vault,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                flip_payer: player.reward_address,
+                token_program: spl_token::ID,
+                history_page: None,
+            }
+            .to_account_metas(None),
+            data: instruction::UserBetCommitReveal {
+                params: UserBetParams {
+                    game_type,
+                    user_guess,
+                    bet_amount,
+                },
+            }
+            .data(),
+        };
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub fn set_user_state(&mut self, player: &Player, user_state: &UserState) {
+        self.context.set_account(
+            &player.user,
+            &AccountSharedData::from(program_account(
+                zero_copy_data(user_state),
+                switchboard_vrf_flip::ID,
+            )),
+        );
+    }
+
+    pub fn history_page_address(&self, player: &Player, page_index: u32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                HISTORY_PAGE_SEED,
+                player.user.as_ref(),
+                &page_index.to_le_bytes(),
+            ],
+            &switchboard_vrf_flip::ID,
+        )
+        .0
+    }
+
+    pub async fn history_page_state(
+        &mut self,
+        player: &Player,
+        page_index: u32,
+    ) -> UserHistoryPage {
+        let history_page = self.history_page_address(player, page_index);
+        let data = self.account_data(&history_page).await;
+        UserHistoryPage::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    pub fn history_page_init_ix(&self, player: &Player, page_index: u32) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserHistoryPageInit {
+                history_page: self.history_page_address(player, page_index),
+                user: player.user,
+                authority: player.authority.pubkey(),
+                payer: self.context.payer.pubkey(),
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::UserHistoryPageInit {
+                params: UserHistoryPageInitParams { page_index },
+            }
+            .data(),
+        }
+    }
+
+    pub async fn history_page_init(
+        &mut self,
+        player: &Player,
+        page_index: u32,
+    ) -> Result<(), BanksClientError> {
+        let ix = self.history_page_init_ix(player, page_index);
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub async fn history_page_close(
+        &mut self,
+        player: &Player,
+        page_index: u32,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserHistoryPageClose {
+                history_page: self.history_page_address(player, page_index),
+                user: player.user,
+                authority: player.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::UserHistoryPageClose {
+                params: UserHistoryPageCloseParams {},
+            }
+            .data(),
+        };
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    /// Writes raw account data, e.g. a user account in an older layout
+    pub fn set_user_data(&mut self, player: &Player, data: Vec<u8>) {
+        self.context.set_account(
+            &player.user,
+            &AccountSharedData::from(program_account(data, switchboard_vrf_flip::ID)),
+        );
+    }
+
+    pub async fn user_migrate(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserMigrate {
+                user: player.user,
+                house: self.house,
+                authority: player.authority.pubkey(),
+                payer: self.context.payer.pubkey(),
+                system_program: solana_program::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::UserMigrate {
+                params: UserMigrateParams {},
+            }
+            .data(),
+        };
+        self.process(&[ix], &[]).await
+    }
+
+    pub fn user_close_ix(&self, player: &Player) -> Instruction {
+        self.user_close_with_history_page_ix(player, None)
+    }
+
+    pub fn user_close_with_history_page_ix(
+        &self,
+        player: &Player,
+        history_page: Option<Pubkey>,
+    ) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserClose {
+                user: player.user,
+                house: self.house,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                token_program: spl_token::ID,
+                history_page,
+                unwrap_account: Some(self.unwrap_address(player)),
+            }
+            .to_account_metas(None),
+            data: instruction::UserClose {
+                params: UserCloseParams {},
+            }
+            .data(),
+        }
+    }
+
+    pub async fn user_close(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = self.user_close_ix(player);
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub fn user_withdraw_escrow_ix(&self, player: &Player) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserWithdrawEscrow {
+                user: player.user,
+                house: self.house,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                reward_address: player.reward_address,
+                token_program: spl_token::ID,
+                unwrap_account: Some(self.unwrap_address(player)),
+                native_mint: Some(spl_token::native_mint::ID),
+                system_program: Some(solana_program::system_program::ID),
+            }
+            .to_account_metas(None),
+            data: instruction::UserWithdrawEscrow {
+                params: UserWithdrawEscrowParams {},
+            }
+            .data(),
+        }
+    }
+
+    pub async fn user_withdraw_escrow(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = self.user_withdraw_escrow_ix(player);
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub async fn user_set_delegate(
+        &mut self,
+        player: &Player,
+        delegate: Pubkey,
+        expiry_slot: u64,
+        allowance: u64,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserSetDelegate {
+                user: player.user,
+                house: self.house,
+                authority: player.authority.pubkey(),
+                reward_address: player.reward_address,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::UserSetDelegate {
+                params: UserSetDelegateParams {
+                    delegate,
+                    expiry_slot,
+                    allowance,
+                },
+            }
+            .data(),
+        };
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub async fn user_autoplay_start(
+        &mut self,
+        player: &Player,
+        params: UserAutoplayStartParams,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserAutoplayStart {
+                user: player.user,
+                house: self.house,
+                house_vault: self.house_vault,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                flip_payer: player.reward_address,
+                token_program: spl_token::ID,
+                unwrap_account: Some(self.unwrap_address(player)),
+                native_mint: Some(spl_token::native_mint::ID),
+                system_program: Some(solana_program::system_program::ID),
+            }
+            .to_account_metas(None),
+            data: instruction::UserAutoplayStart { params }.data(),
+        };
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    /// The next autoplay bet, the house fee vault pays for the randomness
+    pub fn user_autoplay_bet_ix(&self, player: &Player) -> Instruction {
+        Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserAutoplayBet {
+                user: player.user,
+                house: self.house,
+                house_vault: self.house_vault,
+                authority: player.authority.pubkey(),
+                escrow: player.escrow.pubkey(),
+                vrf: player.vrf,
+                oracle_queue: self.switchboard.queue,
+                queue_authority: self.switchboard.queue_authority,
+                data_buffer: self.switchboard.data_buffer,
+                permission: player.permission,
+                vrf_escrow: player.vrf_escrow,
+                switchboard_program_state: self.switchboard.program_state,
+                switchboard_program: SWITCHBOARD_PROGRAM_ID,
+                vrf_payer: self.fee_vault_address(),
+                recent_blockhashes: sysvar::recent_blockhashes::ID,
+                token_program: spl_token::ID,
+                history_page: None,
+            }
+            .to_account_metas(None),
+            data: instruction::UserAutoplayBet {
+                params: UserAutoplayBetParams {},
+            }
+            .data(),
+        }
+    }
+
+    pub async fn user_autoplay_bet(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = self.user_autoplay_bet_ix(player);
+        self.process(&[ix], &[]).await
+    }
+
+    pub async fn user_autoplay_stop(&mut self, player: &Player) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserAutoplayStop {
+                user: player.user,
+                house: self.house,
+                authority: player.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::UserAutoplayStop {
+                params: UserAutoplayStopParams {},
+            }
+            .data(),
+        };
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub async fn user_set_limits(
+        &mut self,
+        player: &Player,
+        params: UserSetLimitsParams,
+    ) -> Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: switchboard_vrf_flip::ID,
+            accounts: accounts::UserSetLimits {
+                user: player.user,
+                house: self.house,
+                authority: player.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::UserSetLimits { params }.data(),
+        };
+        self.process(&[ix], &[&player.authority]).await
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context.banks_client.get_sysvar().await.unwrap()
+    }
+
+    pub async fn warp_slots(&mut self, slots: u64) {
+        let slot = self.context.banks_client.get_root_slot().await.unwrap();
+        self.context.warp_to_slot(slot + slots).unwrap();
+    }
+
+    /// Fills SlotHashes with the slots before the current one, evicting anything older
+    pub async fn evict_slot_hashes(&mut self) {
+        let slot = self.clock().await.slot;
+        let slot_hashes: Vec<(u64, Hash)> = (1..=MAX_ENTRIES as u64)
+            .filter_map(|offset| slot.checked_sub(offset))
+            .map(|slot| (slot, Hash::new_unique()))
+            .collect();
+        self.context.set_sysvar(&SlotHashes::new(&slot_hashes));
+    }
+}
+
+/// Derives the house PDA for a mint
+pub fn house_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[HOUSE_SEED, mint.as_ref()], &switchboard_vrf_flip::ID).0
+}
+
+/// Swaps every occurrence of an account in the instruction
+pub fn replace_account(ix: &mut Instruction, old: &Pubkey, new: Pubkey) {
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == *old {
+            meta.pubkey = new;
+        }
+    }
+}
+
+/// Marks every occurrence of an account in the instruction writable or read-only
+pub fn set_writable(ix: &mut Instruction, address: &Pubkey, is_writable: bool) {
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == *address {
+            meta.is_writable = is_writable;
+        }
+    }
+}
+
+pub fn assert_custom_error(result: Result<(), BanksClientError>, expected: u32) {
+    match result
+        .expect_err("expected the transaction to fail")
+        .unwrap()
+    {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected)
+        }
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+pub fn assert_flip_error(result: Result<(), BanksClientError>, expected: VrfFlipError) {
+    assert_custom_error(result, expected.into());
+}
+
+/// Builds a VRF result whose first u32 lands on `result` for a game with the given max
+pub fn vrf_result_for(result: u32, game_max: u32) -> [u8; 32] {
+    let mut buffer = [0u8; 32];
+    let value = (result - 1) % game_max + game_max;
+    buffer[0..4].copy_from_slice(&value.to_le_bytes());
+    buffer
+}
@@ -0,0 +1,110 @@
+//! Minimal stand-in for the Switchboard program so the vrf-flip program can be
+//! tested without cloning Switchboard from mainnet.
+//!
+//! Deploy it at `SWITCHBOARD_PROGRAM_ID`. It understands two instructions:
+//! - `vrf_request_randomness`, the CPI made by `user_bet`, which bumps the VRF
+//!   counter and clears the previous result
+//! - `mock_set_result`, which writes an arbitrary result into the VRF account so
+//!   a test can decide the outcome of the round
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+pub use switchboard_v2::{VrfAccountData, VrfRound, VrfStatus, SWITCHBOARD_PROGRAM_ID};
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+// sha256("global:vrf_request_randomness")[..8]
+pub const VRF_REQUEST_RANDOMNESS_DISCRIMINATOR: [u8; 8] = [230, 121, 14, 164, 28, 222, 117, 118];
+// sha256("global:mock_set_result")[..8]
+pub const MOCK_SET_RESULT_DISCRIMINATOR: [u8; 8] = [70, 49, 5, 208, 149, 174, 122, 243];
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (discriminator, data) = data.split_at(8);
+
+    if discriminator == VRF_REQUEST_RANDOMNESS_DISCRIMINATOR {
+        request_randomness(program_id, accounts)
+    } else if discriminator == MOCK_SET_RESULT_DISCRIMINATOR {
+        set_result(program_id, accounts, data)
+    } else {
+        msg!("mock switchboard does not support this instruction");
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+// accounts follow switchboard_v2::VrfRequestRandomness, only the first two are used
+fn request_randomness(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("mock_vrf_request_randomness");
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let vrf = next_account_info(account_info_iter)?;
+
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let clock = Clock::get()?;
+    let mut data = vrf.try_borrow_mut_data()?;
+    let vrf_state = load_vrf_mut(program_id, vrf, &mut data)?;
+    if vrf_state.authority != *authority.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    vrf_state.counter = vrf_state.counter.checked_add(1).unwrap();
+    vrf_state.status = VrfStatus::StatusRequesting;
+    vrf_state.current_round = VrfRound {
+        request_slot: clock.slot,
+        request_timestamp: clock.unix_timestamp,
+        ..bytemuck::Zeroable::zeroed()
+    };
+
+    Ok(())
+}
+
+// accounts: [vrf], data: the 32 byte result
+fn set_result(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    msg!("mock_set_result");
+    let account_info_iter = &mut accounts.iter();
+    let vrf = next_account_info(account_info_iter)?;
+
+    let result: [u8; 32] = data
+        .get(..32)
+        .and_then(|result| result.try_into().ok())
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    let mut data = vrf.try_borrow_mut_data()?;
+    let vrf_state = load_vrf_mut(program_id, vrf, &mut data)?;
+    vrf_state.current_round.result = result;
+    vrf_state.current_round.num_verified = 1;
+    vrf_state.status = VrfStatus::StatusCallbackSuccess;
+
+    Ok(())
+}
+
+fn load_vrf_mut<'a>(
+    program_id: &Pubkey,
+    vrf: &AccountInfo,
+    data: &'a mut [u8],
+) -> Result<&'a mut VrfAccountData, ProgramError> {
+    if vrf.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let size = std::mem::size_of::<VrfAccountData>();
+    if data.len() < 8 + size {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    Ok(bytemuck::from_bytes_mut(&mut data[8..8 + size]))
+}
@@ -0,0 +1,228 @@
+//! Runs random sequences of vrf-flip instructions against the offline harness
+//! and checks invariants that must hold no matter the order or parameters.
+//!
+//! ```bash
+//! cargo install honggfuzz
+//! cargo hfuzz run flip_instructions
+//! ```
+use anchor_spl::token::spl_token::state::Mint as SplMint;
+use arbitrary::Arbitrary;
+use honggfuzz::fuzz;
+use solana_program::{hash::hash, instruction::Instruction, program_pack::Pack};
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{RoundStatus, UserBetParams, UserState};
+use vrf_flip_test_harness::*;
+
+const MAX_PLAYERS: usize = 3;
+const SECRET: [u8; 32] = [42u8; 32];
+
+#[derive(Debug, Arbitrary)]
+struct AccountSwap {
+    a: u8,
+    b: u8,
+}
+
+#[derive(Debug, Arbitrary)]
+enum FuzzInstruction {
+    HouseInit,
+    UserInit,
+    UserBet {
+        player: u8,
+        game_type: u32,
+        user_guess: u32,
+        bet_amount: u64,
+        swap: Option<AccountSwap>,
+    },
+    SetVrfResult {
+        player: u8,
+        result: [u8; 32],
+    },
+    UserSettle {
+        player: u8,
+        swap: Option<AccountSwap>,
+    },
+    UserAirdrop {
+        player: u8,
+        swap: Option<AccountSwap>,
+    },
+    HouseCommit {
+        player: u8,
+    },
+    UserBetCommitReveal {
+        player: u8,
+        game_type: u32,
+        user_guess: u32,
+        bet_amount: u64,
+    },
+    HouseReveal {
+        player: u8,
+    },
+    WarpSlots {
+        slots: u8,
+    },
+}
+
+fn swap_accounts(ix: &mut Instruction, swap: &Option<AccountSwap>) {
+    if let Some(swap) = swap {
+        let len = ix.accounts.len();
+        ix.accounts
+            .swap(swap.a as usize % len, swap.b as usize % len);
+    }
+}
+
+// every token of the house mint lives in the vault, an escrow or a reward address
+async fn assert_tokens_conserved(harness: &mut Harness, players: &[Player]) {
+    let mint = harness.mint;
+    let supply = SplMint::unpack(&harness.account_data(&mint).await)
+        .unwrap()
+        .supply;
+
+    let house_vault = harness.house_vault;
+    let mut total = harness.token_balance(&house_vault).await as u128;
+    for player in players {
+        total += harness.token_balance(&player.escrow.pubkey()).await as u128;
+        total += harness.token_balance(&player.reward_address).await as u128;
+    }
+
+    assert_eq!(
+        total, supply as u128,
+        "house mint tokens were created or lost"
+    );
+}
+
+async fn assert_settlement_was_earned(
+    harness: &mut Harness,
+    player: &Player,
+    before: &UserState,
+    vrf_counter: u128,
+    vrf_result: [u8; 32],
+) {
+    let after = harness.user_state(player).await;
+    let before_round_id = before.current_round.round_id;
+    let after_round_id = after.current_round.round_id;
+
+    // only an awaiting round can be paid out, and only once
+    assert!(before.current_round.status == RoundStatus::Awaiting);
+    assert!(after.current_round.status == RoundStatus::Settled);
+    assert_eq!(before_round_id, after_round_id);
+
+    if before.is_commit_reveal_round() {
+        assert_ne!(before.commit_reveal.result, [0u8; 32]);
+    } else {
+        assert_eq!(vrf_counter, before.expected_vrf_counter());
+        assert_ne!(vrf_result, [0u8; 32]);
+    }
+}
+
+async fn run(instructions: Vec<FuzzInstruction>) {
+    let mut harness = Harness::new().await;
+    let mut players: Vec<Player> = Vec::new();
+
+    for instruction in instructions {
+        match instruction {
+            FuzzInstruction::HouseInit => {
+                assert!(harness.house_init().await.is_err());
+            }
+            FuzzInstruction::UserInit => {
+                if players.len() < MAX_PLAYERS {
+                    players.push(harness.create_player().await);
+                }
+            }
+            FuzzInstruction::UserBet {
+                player,
+                game_type,
+                user_guess,
+                bet_amount,
+                swap,
+            } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                let mut ix = harness.user_bet_ix(
+                    player,
+                    UserBetParams {
+                        game_type,
+                        user_guess,
+                        bet_amount,
+                    },
+                );
+                swap_accounts(&mut ix, &swap);
+                let _ = harness.process(&[ix], &[&player.authority]).await;
+            }
+            FuzzInstruction::SetVrfResult { player, result } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                harness.set_vrf_result(player, result).await;
+            }
+            FuzzInstruction::UserSettle { player, swap } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                let before = harness.user_state(player).await;
+                let vrf = harness.vrf_state(player).await;
+                let (vrf_counter, vrf_result) = (vrf.counter, vrf.current_round.result);
+
+                let mut ix = harness.user_settle_ix(player);
+                swap_accounts(&mut ix, &swap);
+                if harness.process(&[ix], &[]).await.is_ok() {
+                    assert_settlement_was_earned(
+                        &mut harness,
+                        player,
+                        &before,
+                        vrf_counter,
+                        vrf_result,
+                    )
+                    .await;
+                }
+            }
+            FuzzInstruction::UserAirdrop { player, swap } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                let mut ix = harness.user_airdrop_ix(player);
+                swap_accounts(&mut ix, &swap);
+                let _ = harness.process(&[ix], &[&player.authority]).await;
+            }
+            FuzzInstruction::HouseCommit { player } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                let _ = harness.house_commit(player, hash(&SECRET).to_bytes()).await;
+            }
+            FuzzInstruction::UserBetCommitReveal {
+                player,
+                game_type,
+                user_guess,
+                bet_amount,
+            } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                let _ = harness
+                    .bet_commit_reveal(player, game_type, user_guess, bet_amount)
+                    .await;
+            }
+            FuzzInstruction::HouseReveal { player } => {
+                let Some(player) = players.get(player as usize % MAX_PLAYERS) else {
+                    continue;
+                };
+                let _ = harness.house_reveal(player, SECRET).await;
+            }
+            FuzzInstruction::WarpSlots { slots } => {
+                harness.warp_slots(slots as u64 + 1).await;
+            }
+        }
+
+        assert_tokens_conserved(&mut harness, &players).await;
+    }
+}
+
+fn main() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    loop {
+        fuzz!(|instructions: Vec<FuzzInstruction>| {
+            runtime.block_on(run(instructions));
+        });
+    }
+}
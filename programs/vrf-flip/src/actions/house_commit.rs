@@ -0,0 +1,64 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: HouseCommitParams)] // rpc parameters hint
+pub struct HouseCommit<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            user.load()?.authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = authority,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct HouseCommitParams {
+    pub commitment: [u8; 32],
+}
+
+impl HouseCommit<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        params: &HouseCommitParams,
+    ) -> anchor_lang::Result<()> {
+        if params.commitment == [0u8; 32] {
+            return Err(error!(VrfFlipError::MissingCommitment));
+        }
+
+        let user = ctx.accounts.user.load()?;
+        if user.is_commit_reveal_round() && user.current_round.status == RoundStatus::Awaiting {
+            return Err(error!(VrfFlipError::CommitmentAlreadyBound));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &HouseCommitParams) -> anchor_lang::Result<()> {
+        msg!("house_commit");
+
+        let user = &mut ctx.accounts.user.load_mut()?;
+        user.commit_reveal = CommitReveal {
+            round_id: 0,
+            commitment: params.commitment,
+            result: [0u8; 32],
+        };
+        drop(user);
+
+        Ok(())
+    }
+}
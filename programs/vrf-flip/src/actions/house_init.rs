@@ -11,7 +11,7 @@ pub struct HouseInit<'info> {
         init,
         space = 8 + std::mem::size_of::<HouseState>(),
         payer = payer,
-        seeds = [HOUSE_SEED],
+        seeds = [HOUSE_SEED, mint.key().as_ref()],
         bump
     )]
     pub house: AccountLoader<'info, HouseState>,
@@ -75,7 +75,8 @@ impl HouseInit<'_> {
         if ctx.accounts.mint.mint_authority.is_some()
             && ctx.accounts.mint.mint_authority.unwrap() == ctx.accounts.house.key()
         {
-            let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, &[house_bump]]];
+            let mint_key = ctx.accounts.mint.key();
+            let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, mint_key.as_ref(), &[house_bump]]];
             msg!("minting 100_000_000 tokens to house vault");
             token::mint_to(
                 CpiContext::new_with_signer(
@@ -98,6 +99,7 @@ impl HouseInit<'_> {
         house.mint = ctx.accounts.mint.key().clone();
         house.switchboard_queue = ctx.accounts.switchboard_queue.key().clone();
         house.house_vault = ctx.accounts.house_vault.key().clone();
+        house.mint_seeded = 1;
         drop(house);
 
         Ok(())
@@ -0,0 +1,65 @@
+use crate::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(params: HouseInitFeeVaultParams)] // rpc parameters hint
+pub struct HouseInitFeeVault<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = authority,
+        has_one = switchboard_mint,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+
+    pub switchboard_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [FEE_VAULT_SEED, house.key().as_ref()],
+        bump,
+        token::mint = switchboard_mint,
+        token::authority = house,
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // SYSTEM ACCOUNTS
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    /// CHECK:
+    #[account(address = solana_program::sysvar::rent::ID)]
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct HouseInitFeeVaultParams {}
+
+impl HouseInitFeeVault<'_> {
+    pub fn validate(
+        &self,
+        _ctx: &Context<Self>,
+        _params: &HouseInitFeeVaultParams,
+    ) -> anchor_lang::Result<()> {
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        _params: &HouseInitFeeVaultParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("house_init_fee_vault");
+
+        let house = &mut ctx.accounts.house.load_mut()?;
+        house.fee_vault = ctx.accounts.fee_vault.key();
+        drop(house);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::native_mint, Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+#[instruction(params: HouseInitNativeParams)] // rpc parameters hint
+pub struct HouseInitNative<'info> {
+    #[account(
+        init,
+        space = 8 + std::mem::size_of::<HouseState>(),
+        payer = payer,
+        seeds = [HOUSE_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(mut, signer)]
+    pub authority: AccountInfo<'info>,
+
+    pub switchboard_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        constraint =
+            switchboard_queue.load()?.unpermissioned_vrf_enabled == true @ VrfFlipError::OracleQueueRequiresPermissions
+    )]
+    pub switchboard_queue: AccountLoader<'info, OracleQueueAccountData>,
+
+    #[account(address = native_mint::ID)]
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = house,
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // SYSTEM ACCOUNTS
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK:
+    #[account(address = solana_program::sysvar::rent::ID)]
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct HouseInitNativeParams {
+    // lamports the payer wraps into the house vault
+    pub liquidity: u64,
+}
+
+impl HouseInitNative<'_> {
+    pub fn validate(
+        &self,
+        _ctx: &Context<Self>,
+        _params: &HouseInitNativeParams,
+    ) -> anchor_lang::Result<()> {
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &HouseInitNativeParams) -> anchor_lang::Result<()> {
+        msg!("house_init_native");
+
+        let house_bump = ctx.bumps.get("house").unwrap().clone();
+
+        if params.liquidity > 0 {
+            msg!(
+                "wrapping {} lamports into the house vault",
+                params.liquidity
+            );
+            wrap_native(
+                &ctx.accounts.system_program,
+                &ctx.accounts.token_program,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.house_vault,
+                params.liquidity,
+            )?;
+        }
+
+        let house = &mut ctx.accounts.house.load_init()?;
+        house.bump = house_bump;
+        house.authority = ctx.accounts.authority.key().clone();
+        house.switchboard_mint = ctx.accounts.switchboard_mint.key().clone();
+        house.mint = ctx.accounts.mint.key().clone();
+        house.switchboard_queue = ctx.accounts.switchboard_queue.key().clone();
+        house.house_vault = ctx.accounts.house_vault.key().clone();
+        house.mint_seeded = 1;
+        drop(house);
+
+        Ok(())
+    }
+}
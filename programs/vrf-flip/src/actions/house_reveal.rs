@@ -0,0 +1,91 @@
+use crate::*;
+use solana_program::hash::{hash, hashv};
+
+#[derive(Accounts)]
+#[instruction(params: HouseRevealParams)] // rpc parameters hint
+pub struct HouseReveal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            user.load()?.authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = authority,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+
+    // SYSTEM ACCOUNTS
+    /// CHECK:
+    #[account(address = solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct HouseRevealParams {
+    pub secret: [u8; 32],
+}
+
+impl HouseReveal<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        params: &HouseRevealParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if !user.is_commit_reveal_round() {
+            return Err(error!(VrfFlipError::CommitmentNotBound));
+        }
+        if user.current_round.status != RoundStatus::Awaiting {
+            return Err(error!(VrfFlipError::CurrentRoundAlreadyClosed));
+        }
+        if user.current_round.reveal_timed_out(&Clock::get()?) {
+            return Err(error!(VrfFlipError::RevealTimedOut));
+        }
+        if hash(&params.secret).to_bytes() != user.commit_reveal.commitment {
+            return Err(error!(VrfFlipError::InvalidReveal));
+        }
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &HouseRevealParams) -> anchor_lang::Result<()> {
+        msg!("house_reveal");
+
+        let user = &mut ctx.accounts.user.load_mut()?;
+        let round_id = user.current_round.round_id;
+
+        // the slot hash was unknown to the house when it committed and is
+        // unknown to the user when the bet is placed
+        let slot_hash = find_slot_hash(&ctx.accounts.slot_hashes, user.current_round.request_slot)?;
+
+        user.commit_reveal.result = hashv(&[
+            &params.secret,
+            &slot_hash,
+            &round_id.to_le_bytes(),
+            ctx.accounts.user.key().as_ref(),
+        ])
+        .to_bytes();
+
+        emit!(HouseRevealed {
+            round_id: round_id,
+            user: ctx.accounts.user.key(),
+            commitment: user.commit_reveal.commitment,
+            secret: params.secret,
+            slot_hash: slot_hash,
+            result: user.commit_reveal.result
+        });
+        drop(user);
+
+        Ok(())
+    }
+}
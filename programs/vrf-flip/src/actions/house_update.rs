@@ -0,0 +1,92 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: HouseUpdateParams)] // rpc parameters hint
+pub struct HouseUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = authority,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct HouseUpdateParams {
+    pub sponsor_vrf_fees: Option<bool>,
+    pub vrf_fee: Option<u64>,
+    pub bet_limits: Option<GameBetLimitsParams>,
+    pub max_liability_bps: Option<u32>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct GameBetLimitsParams {
+    pub game_type: u32,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub max_payout_bps: u32,
+}
+
+impl GameBetLimitsParams {
+    pub fn bet_limits(&self) -> BetLimits {
+        BetLimits {
+            min_bet: self.min_bet,
+            max_bet: self.max_bet,
+            max_payout_bps: self.max_payout_bps,
+        }
+    }
+}
+
+impl HouseUpdate<'_> {
+    pub fn validate(
+        &self,
+        _ctx: &Context<Self>,
+        params: &HouseUpdateParams,
+    ) -> anchor_lang::Result<()> {
+        // sponsored requests are paid from the fee vault, see house_init_fee_vault
+        if params.sponsor_vrf_fees == Some(true) {
+            let fee_vault = self.house.load()?.fee_vault;
+            if fee_vault == Pubkey::default() {
+                return Err(error!(VrfFlipError::FeeVaultRequired));
+            }
+        }
+        if let Some(bet_limits) = &params.bet_limits {
+            GameType::from_u32(bet_limits.game_type)?.index()?;
+            bet_limits.bet_limits().validate()?;
+        }
+        if let Some(max_liability_bps) = params.max_liability_bps {
+            if max_liability_bps > MAX_BPS {
+                return Err(error!(VrfFlipError::InvalidMaxLiability));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &HouseUpdateParams) -> anchor_lang::Result<()> {
+        msg!("house_update");
+
+        let house = &mut ctx.accounts.house.load_mut()?;
+        if let Some(sponsor_vrf_fees) = params.sponsor_vrf_fees {
+            house.sponsor_vrf_fees = sponsor_vrf_fees as u8;
+        }
+        if let Some(vrf_fee) = params.vrf_fee {
+            house.vrf_fee = vrf_fee;
+        }
+        if let Some(bet_limits) = &params.bet_limits {
+            let game_type = GameType::from_u32(bet_limits.game_type)?;
+            msg!("updating bet limits for {:?}", game_type);
+            house.bet_limits[game_type.index()?] = bet_limits.bet_limits();
+        }
+        if let Some(max_liability_bps) = params.max_liability_bps {
+            house.max_liability_bps = max_liability_bps;
+        }
+        drop(house);
+
+        Ok(())
+    }
+}
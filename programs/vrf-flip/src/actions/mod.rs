@@ -1,14 +1,68 @@
 pub mod house_init;
 pub use house_init::*;
 
+pub mod house_init_native;
+pub use house_init_native::*;
+
+pub mod house_update;
+pub use house_update::*;
+
+pub mod house_init_fee_vault;
+pub use house_init_fee_vault::*;
+
+pub mod house_commit;
+pub use house_commit::*;
+
+pub mod house_reveal;
+pub use house_reveal::*;
+
 pub mod user_init;
 pub use user_init::*;
 
 pub mod user_bet;
 pub use user_bet::*;
 
+pub mod user_bet_commit_reveal;
+pub use user_bet_commit_reveal::*;
+
+pub mod user_bet_delegate;
+pub use user_bet_delegate::*;
+
 pub mod user_settle;
 pub use user_settle::*;
 
+pub mod user_record_settle;
+pub use user_record_settle::*;
+
 pub mod user_airdrop;
 pub use user_airdrop::*;
+
+pub mod user_history_page_init;
+pub use user_history_page_init::*;
+
+pub mod user_history_page_close;
+pub use user_history_page_close::*;
+
+pub mod user_migrate;
+pub use user_migrate::*;
+
+pub mod user_close;
+pub use user_close::*;
+
+pub mod user_withdraw_escrow;
+pub use user_withdraw_escrow::*;
+
+pub mod user_set_delegate;
+pub use user_set_delegate::*;
+
+pub mod user_autoplay_start;
+pub use user_autoplay_start::*;
+
+pub mod user_autoplay_bet;
+pub use user_autoplay_bet::*;
+
+pub mod user_autoplay_stop;
+pub use user_autoplay_stop::*;
+
+pub mod user_set_limits;
+pub use user_set_limits::*;
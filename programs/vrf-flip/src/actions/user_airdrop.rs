@@ -19,7 +19,7 @@ pub struct UserAirdrop<'info> {
     )]
     pub user: AccountLoader<'info, UserState>,
     #[account(
-        seeds = [HOUSE_SEED],
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
         bump = house.load()?.bump,
         has_one = house_vault,
         has_one = mint,
@@ -84,7 +84,8 @@ impl UserAirdrop<'_> {
 
         let house = ctx.accounts.house.load()?;
         let house_bump = house.bump.clone();
-        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, &[house_bump]]];
+        let house_mint_seed = house.mint_seed().to_vec();
+        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, house_mint_seed.as_ref(), &[house_bump]]];
         drop(house);
 
         let user = &mut ctx.accounts.user.load_mut()?;
@@ -0,0 +1,279 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+pub use switchboard_v2::{
+    OracleQueueAccountData, PermissionAccountData, SbState, VrfAccountData, SWITCHBOARD_PROGRAM_ID,
+};
+const VRF_REQUEST_COST: u64 = 2 * LAMPORTS_PER_SOL / 1000;
+
+#[derive(Accounts)]
+#[instruction(params: UserAutoplayBetParams)] // rpc parameters hint
+pub struct UserAutoplayBet<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = vrf,
+        has_one = authority,
+        has_one = escrow,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = house_vault
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    #[account(
+        mut,
+        associated_token::mint = house.load()?.mint,
+        associated_token::authority = house,
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: the user's authority, doesn't need to sign
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = house,
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    // SWITCHBOARD ACCOUNTS
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+        constraint =
+            vrf.load()?.escrow == vrf_escrow.key() &&
+            vrf.load()?.authority == user.key()
+    )]
+    pub vrf: AccountLoader<'info, VrfAccountData>,
+    /// CHECK
+    #[account(mut,
+        has_one = data_buffer,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+        constraint =
+            oracle_queue.load()?.authority == queue_authority.key()
+    )]
+    pub oracle_queue: AccountLoader<'info, OracleQueueAccountData>,
+    /// CHECK: Will be checked in the CPI instruction
+    pub queue_authority: UncheckedAccount<'info>,
+    /// CHECK
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+    )]
+    pub data_buffer: AccountInfo<'info>,
+    /// CHECK
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+    )]
+    pub permission: AccountLoader<'info, PermissionAccountData>,
+    #[account(
+        mut,
+        token::mint = house.load()?.switchboard_mint,
+        token::authority = switchboard_program_state,
+    )]
+    pub vrf_escrow: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Will be checked in the CPI instruction
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+    )]
+    pub switchboard_program_state: AccountLoader<'info, SbState>,
+    /// CHECK:
+    #[account(
+        address = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+        constraint =
+            switchboard_program.executable == true
+    )]
+    pub switchboard_program: AccountInfo<'info>,
+
+    // FEE VAULT ACCOUNTS
+    // any keeper can place the next bet, the house fee vault pays for the randomness
+    #[account(
+        mut,
+        token::mint = house.load()?.switchboard_mint,
+        constraint = house.load()?.is_fee_vault(&vrf_payer.key()) @ VrfFlipError::InvalidVrfPayer
+    )]
+    pub vrf_payer: Box<Account<'info, TokenAccount>>,
+
+    // SYSTEM ACCOUNTS
+    /// CHECK:
+    #[account(address = solana_program::sysvar::recent_blockhashes::ID)]
+    pub recent_blockhashes: AccountInfo<'info>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    // HISTORY ACCOUNTS
+    // required once the history ring is full, see UserState::needs_history_page
+    #[account(mut, has_one = user)]
+    pub history_page: Option<AccountLoader<'info, UserHistoryPage>>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserAutoplayBetParams {}
+
+impl UserAutoplayBet<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserAutoplayBetParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if !user.autoplay.is_active() || user.autoplay.remaining_bets == 0 {
+            return Err(error!(VrfFlipError::AutoPlayInactive));
+        }
+        // the next bet is only placed once the previous one settled
+        if user.current_round.status == RoundStatus::Awaiting {
+            return Err(error!(VrfFlipError::CurrentRoundStillActive));
+        }
+        let bet_params = user.autoplay.bet_params();
+        let next_bet_amount = user.autoplay.next_bet_amount();
+        let house = ctx.accounts.house.load()?;
+        bet_params.validate(&user, &house, ctx.accounts.house_vault.amount)?;
+        if !house.sponsors_vrf_fees() {
+            return Err(error!(VrfFlipError::VrfSponsorshipDisabled));
+        }
+        drop(house);
+        drop(user);
+
+        if ctx.accounts.escrow.amount < next_bet_amount {
+            msg!(
+                "escrow can't cover the bet, need {}, have {}",
+                next_bet_amount,
+                ctx.accounts.escrow.amount
+            );
+            return Err(error!(VrfFlipError::InsufficientFunds));
+        }
+
+        // check token balance
+        let combined_balance = ctx
+            .accounts
+            .vrf_payer
+            .amount
+            .checked_add(ctx.accounts.vrf_escrow.amount)
+            .unwrap_or(0);
+        if combined_balance < VRF_REQUEST_COST {
+            msg!(
+                "missing funds to request randomness, need {}, have {}",
+                VRF_REQUEST_COST,
+                combined_balance
+            );
+            return Err(error!(VrfFlipError::InsufficientFunds));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: Context<Self>, _params: &UserAutoplayBetParams) -> anchor_lang::Result<()> {
+        msg!("user_autoplay_bet");
+        let clock = Clock::get()?;
+
+        let user = ctx.accounts.user.load()?;
+        let user_bump = user.bump;
+        let switchboard_state_bump = user.switchboard_state_bump;
+        let vrf_permission_bump = user.vrf_permission_bump;
+        let params = user.autoplay.bet_params();
+        let vrf_fee = user.autoplay.vrf_fee;
+        drop(user);
+
+        let house = ctx.accounts.house.load()?;
+        let house_bump = house.bump;
+        let house_mint_seed = house.mint_seed().to_vec();
+        drop(house);
+
+        if vrf_fee > 0 {
+            msg!(
+                "transferring {} flip tokens from escrow for the vrf fee",
+                vrf_fee
+            );
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.house_vault.to_account_info(),
+                        authority: ctx.accounts.house.to_account_info(),
+                    },
+                    &[&[&HOUSE_SEED, house_mint_seed.as_ref(), &[house_bump]]],
+                ),
+                vrf_fee,
+            )?;
+        }
+
+        msg!("creating randomness instruction");
+        UserState::request_randomness(&RequestRandomness {
+            switchboard_program: ctx.accounts.switchboard_program.to_account_info(),
+            house: ctx.accounts.house.to_account_info(),
+            house_mint_seed,
+            authority: ctx.accounts.authority.to_account_info(),
+            bumps: RequestRandomnessBumps {
+                user: user_bump,
+                switchboard_state: switchboard_state_bump,
+                vrf_permission: vrf_permission_bump,
+                house: Some(house_bump),
+            },
+            accounts: RequestRandomnessAccounts {
+                user: ctx.accounts.user.to_account_info().clone(),
+                vrf: ctx.accounts.vrf.to_account_info(),
+                oracle_queue: ctx.accounts.oracle_queue.to_account_info(),
+                queue_authority: ctx.accounts.queue_authority.to_account_info(),
+                data_buffer: ctx.accounts.data_buffer.to_account_info(),
+                permission: ctx.accounts.permission.to_account_info(),
+                vrf_escrow: *ctx.accounts.vrf_escrow.clone(),
+                vrf_payer: *ctx.accounts.vrf_payer.clone(),
+                payer_authority: ctx.accounts.house.to_account_info(),
+                recent_blockhashes: ctx.accounts.recent_blockhashes.to_account_info(),
+                switchboard_program_state: ctx.accounts.switchboard_program_state.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        })?;
+        msg!("randomness requested successfully");
+
+        let user = &mut ctx.accounts.user.load_mut()?;
+
+        let game_type = GameType::from_u32(params.game_type)?;
+
+        let mut history_page = match &ctx.accounts.history_page {
+            Some(history_page) => Some(history_page.load_mut()?),
+            None => None,
+        };
+        let previous_round = user.current_round;
+        let previous_settle_pending = user.take_house_settle_pending();
+        user.new_round(
+            game_type,
+            params.user_guess,
+            params.bet_amount,
+            history_page.as_deref_mut(),
+        )?;
+        drop(history_page);
+        user.autoplay.remaining_bets -= 1;
+        let round_id = user.current_round.round_id;
+        let max_payout = user.current_round.max_payout()?;
+
+        drop(user);
+
+        let mut house = ctx.accounts.house.load_mut()?;
+        house.record_refund(&previous_round)?;
+        if previous_settle_pending {
+            house.record_settled_round(&previous_round)?;
+        }
+        house.record_bet(game_type, params.bet_amount, max_payout)?;
+        drop(house);
+
+        emit!(UserBetPlaced {
+            round_id: round_id,
+            user: ctx.accounts.user.key(),
+            game_type: game_type,
+            bet_amount: params.bet_amount,
+            guess: params.user_guess,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(params: UserAutoplayStartParams)] // rpc parameters hint
+pub struct UserAutoplayStart<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+        has_one = escrow,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = house_vault
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    #[account(
+        associated_token::mint = house.load()?.mint,
+        associated_token::authority = house,
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK:
+    #[account(mut, signer)]
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = house,
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = authority,
+    )]
+    pub flip_payer: Box<Account<'info, TokenAccount>>,
+
+    // SYSTEM ACCOUNTS
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    // NATIVE ACCOUNTS
+    // a native house creates the user's unwrap account for the session's payouts, see UNWRAP_SEED
+    /// CHECK: created by the program
+    #[account(mut, seeds = [UNWRAP_SEED, user.key().as_ref()], bump)]
+    pub unwrap_account: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: Option<UncheckedAccount<'info>>,
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserAutoplayStartParams {
+    pub game_type: u32,
+    pub user_guess: u32,
+    pub bet_amount: u64,
+    pub num_bets: u32,
+    pub stop_loss: u64,
+    pub take_profit: u64,
+}
+
+impl UserAutoplayStartParams {
+    pub fn bet_params(&self) -> UserBetParams {
+        UserBetParams {
+            game_type: self.game_type,
+            user_guess: self.user_guess,
+            bet_amount: self.bet_amount,
+        }
+    }
+
+    // escrow needed to cover every bet in the session and the vrf fee the house charges for each
+    pub fn session_amount(&self, vrf_fee: u64) -> anchor_lang::Result<u64> {
+        self.bet_amount
+            .checked_add(vrf_fee)
+            .and_then(|amount| amount.checked_mul(self.num_bets as u64))
+            .ok_or(error!(VrfFlipError::InvalidAutoPlay))
+    }
+}
+
+impl UserAutoplayStart<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        params: &UserAutoplayStartParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if user.autoplay.is_active() {
+            return Err(error!(VrfFlipError::AutoPlayActive));
+        }
+        // every round in the session has to be an autoplay round
+        if user.current_round.status == RoundStatus::Awaiting {
+            return Err(error!(VrfFlipError::CurrentRoundStillActive));
+        }
+        let house = ctx.accounts.house.load()?;
+        params
+            .bet_params()
+            .validate(&user, &house, ctx.accounts.house_vault.amount)?;
+        drop(user);
+
+        if params.num_bets == 0 || params.bet_amount == 0 {
+            return Err(error!(VrfFlipError::InvalidAutoPlay));
+        }
+
+        // the keeper places the bets, so the house fee vault pays for their randomness
+        if !house.sponsors_vrf_fees() {
+            return Err(error!(VrfFlipError::VrfSponsorshipDisabled));
+        }
+        let vrf_fee = house.vrf_fee;
+        drop(house);
+
+        let required_balance = params
+            .session_amount(vrf_fee)?
+            .saturating_sub(ctx.accounts.escrow.amount);
+        if ctx.accounts.flip_payer.amount < required_balance {
+            msg!(
+                "missing funds to play, need {}, have {}",
+                required_balance,
+                ctx.accounts.flip_payer.amount
+            );
+            return Err(error!(VrfFlipError::InsufficientFunds));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        params: &UserAutoplayStartParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("user_autoplay_start");
+
+        let house = ctx.accounts.house.load()?;
+        let vrf_fee = house.vrf_fee;
+        let native = house.is_native();
+        drop(house);
+
+        if let (true, Some(unwrap_account), Some(native_mint), Some(system_program)) = (
+            native,
+            &ctx.accounts.unwrap_account,
+            &ctx.accounts.native_mint,
+            &ctx.accounts.system_program,
+        ) {
+            if unwrap_account.owner != &token::ID {
+                msg!("creating the unwrap account");
+                create_unwrap_account(
+                    system_program,
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.authority,
+                    unwrap_account,
+                    &native_mint.to_account_info(),
+                    &ctx.accounts.house.to_account_info(),
+                    &ctx.accounts.user.key(),
+                    *ctx.bumps.get("unwrap_account").unwrap(),
+                )?;
+            }
+        }
+
+        let escrow_transfer_amount = params
+            .session_amount(vrf_fee)?
+            .saturating_sub(ctx.accounts.escrow.amount);
+        if escrow_transfer_amount == 0 {
+            msg!("escrow already funded");
+        } else {
+            msg!(
+                "transferring {} flip tokens to escrow",
+                escrow_transfer_amount
+            );
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info().clone(),
+                    Transfer {
+                        from: ctx.accounts.flip_payer.to_account_info(),
+                        to: ctx.accounts.escrow.to_account_info(),
+                        authority: ctx.accounts.authority.clone(),
+                    },
+                ),
+                escrow_transfer_amount,
+            )?;
+        }
+
+        let mut user = ctx.accounts.user.load_mut()?;
+        user.autoplay = AutoPlay {
+            active: 1,
+            game_type: params.game_type,
+            guess: params.user_guess,
+            bet_amount: params.bet_amount,
+            remaining_bets: params.num_bets,
+            stop_loss: params.stop_loss,
+            take_profit: params.take_profit,
+            net_pnl: 0,
+            vrf_fee,
+        };
+        drop(user);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: UserAutoplayStopParams)] // rpc parameters hint
+pub struct UserAutoplayStop<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserAutoplayStopParams {}
+
+impl UserAutoplayStop<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserAutoplayStopParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if !user.autoplay.is_active() {
+            return Err(error!(VrfFlipError::AutoPlayInactive));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        _params: &UserAutoplayStopParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("user_autoplay_stop");
+
+        // the reserved escrow can be withdrawn, or comes back when the open round settles
+        let mut user = ctx.accounts.user.load_mut()?;
+        let remaining_bets = user.autoplay.remaining_bets;
+        msg!("stopping autoplay with {} bets left", remaining_bets);
+        user.autoplay.stop();
+        drop(user);
+
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ pub use switchboard_v2::{
     SWITCHBOARD_PROGRAM_ID,
 };
 const VRF_REQUEST_COST: u64 = 2 * LAMPORTS_PER_SOL / 1000;
+// lamports a native bet leaves in the payer's wallet for its next transactions
+pub const NATIVE_FEE_RESERVE: u64 = LAMPORTS_PER_SOL / 1000;
 
 #[derive(Accounts)]
 #[instruction(params: UserBetParams)] // rpc parameters hint
@@ -24,12 +26,14 @@ pub struct UserBet<'info> {
     )]
     pub user: AccountLoader<'info, UserState>,
     #[account(
-        seeds = [HOUSE_SEED],
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
         bump = house.load()?.bump,
         has_one = house_vault
     )]
     pub house: AccountLoader<'info, HouseState>,
     #[account(
+        mut,
         associated_token::mint = house.load()?.mint,
         associated_token::authority = house,
     )]
@@ -99,7 +103,9 @@ pub struct UserBet<'info> {
     #[account(
         mut,
         token::mint = house.load()?.switchboard_mint,
-        token::authority = payer,
+        constraint = 
+            vrf_payer.owner == payer.key() || 
+            house.load()?.is_fee_vault(&vrf_payer.key()) @ VrfFlipError::InvalidVrfPayer
     )]
     pub vrf_payer: Box<Account<'info, TokenAccount>>,
     #[account(
@@ -116,6 +122,20 @@ pub struct UserBet<'info> {
     pub system_program: Program<'info, System>,
     #[account(address = anchor_spl::token::ID)]
     pub token_program: Program<'info, Token>,
+
+    // HISTORY ACCOUNTS
+    // required once the history ring is full, see UserState::needs_history_page
+    #[account(mut, has_one = user)]
+    pub history_page: Option<AccountLoader<'info, UserHistoryPage>>,
+
+    // NATIVE ACCOUNTS
+    // a native house creates the user's unwrap account if it's missing, see UNWRAP_SEED
+    /// CHECK: created by the program
+    #[account(mut, seeds = [UNWRAP_SEED, user.key().as_ref()], bump)]
+    pub unwrap_account: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: Option<UncheckedAccount<'info>>,
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
@@ -125,38 +145,116 @@ pub struct UserBetParams {
     pub bet_amount: u64,
 }
 
-impl UserBet<'_> {
-    pub fn validate(&self, ctx: &Context<Self>, params: &UserBetParams) -> anchor_lang::Result<()> {
-        let user = ctx.accounts.user.load()?;
+impl UserBetParams {
+    // checks shared by every instruction that opens a new round
+    pub fn validate(
+        &self,
+        user: &UserState,
+        house: &HouseState,
+        house_vault_balance: u64,
+    ) -> anchor_lang::Result<()> {
+        if user.version != USER_STATE_VERSION {
+            return Err(error!(VrfFlipError::UserStateOutdated));
+        }
 
-        if user.current_round.is_open() {
+        let clock = Clock::get()?;
+        if user.current_round.is_open(&clock) {
+            return Err(error!(VrfFlipError::CurrentRoundStillActive));
+        }
+        // once the house revealed a commit-reveal round its result is public, so it has to
+        // be settled rather than refunded by a new bet however long ago it was placed
+        if user.current_round.status == RoundStatus::Awaiting
+            && user.is_commit_reveal_round()
+            && user.commit_reveal.result != [0u8; 32]
+        {
             return Err(error!(VrfFlipError::CurrentRoundStillActive));
         }
 
-        let game_type = GameType::from_u32(params.game_type)?;
+        let game_type = GameType::from_u32(self.game_type)?;
         let game_config = game_type.get_game_config()?;
-        if params.user_guess < game_config.min || params.user_guess > game_config.max {
+        if self.user_guess < game_config.min || self.user_guess > game_config.max {
             return Err(error!(VrfFlipError::InvalidBet));
         }
 
-        let house_vault_balance = ctx.accounts.house_vault.amount;
-        if params.bet_amount * 10 > house_vault_balance || params.bet_amount > MAX_BET_AMOUNT {
-            return Err(error!(VrfFlipError::MaxBetAmountExceeded));
+        let max_payout = self
+            .bet_amount
+            .checked_mul(game_config.payout_multiplier as u64)
+            .ok_or(error!(VrfFlipError::MaxPayoutExceeded))?;
+        house
+            .bet_limits(game_type)?
+            .check_bet(self.bet_amount, max_payout, house_vault_balance)?;
+        // the round this bet replaces no longer counts towards the liability
+        house.check_liability(user.released_payout()?, max_payout, house_vault_balance)?;
+
+        user.limits.check_bet(
+            self.bet_amount,
+            user.current_round.request_timestamp,
+            &clock,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl UserBet<'_> {
+    // whether a native bet has to create the user's unwrap account
+    fn needs_unwrap_account(&self) -> bool {
+        match (&self.unwrap_account, &self.native_mint) {
+            (Some(unwrap_account), Some(_)) => unwrap_account.owner != &token::ID,
+            _ => false,
         }
+    }
 
-        // let clock = Clock::get()?;
-        // if user.current_round.request_timestamp != 0
-        //     && clock.unix_timestamp - 10 < user.current_round.request_timestamp
-        // {
-        //     return Err(error!(VrfFlipError::FlipRequestedTooSoon));
-        // }
+    /// The payer's lamports a native bet can wrap, what is left after keeping the payer
+    /// rent exempt, paying for a new unwrap account and keeping NATIVE_FEE_RESERVE.
+    pub fn spendable_lamports(&self) -> anchor_lang::Result<u64> {
+        let rent = Rent::get()?;
+        let mut reserved = rent
+            .minimum_balance(self.payer.data_len())
+            .saturating_add(NATIVE_FEE_RESERVE);
+        if self.needs_unwrap_account() {
+            reserved = reserved.saturating_add(rent.minimum_balance(TokenAccount::LEN));
+        }
+        Ok(self.payer.lamports().saturating_sub(reserved))
+    }
 
-        // check FLIP balance
-        if ctx.accounts.flip_payer.amount < params.bet_amount {
+    pub fn validate(&self, ctx: &Context<Self>, params: &UserBetParams) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        let house = ctx.accounts.house.load()?;
+        params.validate(&user, &house, ctx.accounts.house_vault.amount)?;
+        drop(house);
+        if user.autoplay.is_active() {
+            return Err(error!(VrfFlipError::AutoPlayActive));
+        }
+        drop(user);
+
+        // the house fee vault can pay for randomness in exchange for a fee
+        let house = ctx.accounts.house.load()?;
+        let mut vrf_fee: u64 = 0;
+        if house.is_fee_vault(&ctx.accounts.vrf_payer.key()) {
+            if !house.sponsors_vrf_fees() {
+                return Err(error!(VrfFlipError::VrfSponsorshipDisabled));
+            }
+            vrf_fee = house.vrf_fee;
+        }
+        let native = house.is_native();
+        drop(house);
+
+        // check FLIP balance, a native house wraps whatever is missing from the payer's SOL
+        let required_balance = params
+            .bet_amount
+            .checked_add(vrf_fee)
+            .ok_or(error!(VrfFlipError::MaxBetAmountExceeded))?;
+        let mut available_balance = ctx.accounts.flip_payer.amount;
+        if native {
+            available_balance =
+                available_balance.saturating_add(ctx.accounts.spendable_lamports()?);
+        }
+        if available_balance < required_balance {
             msg!(
                 "missing funds to play, need {}, have {}",
-                params.bet_amount,
-                ctx.accounts.flip_payer.amount
+                required_balance,
+                available_balance
             );
             return Err(error!(VrfFlipError::InsufficientFunds));
         }
@@ -184,10 +282,6 @@ impl UserBet<'_> {
         msg!("user_flip");
         let clock = Clock::get()?;
 
-        let vrf = ctx.accounts.vrf.load()?;
-        let round_id = vrf.counter.checked_add(1).unwrap();
-        drop(vrf);
-
         let user = ctx.accounts.user.load()?;
 
         let user_bump = user.bump;
@@ -196,6 +290,64 @@ impl UserBet<'_> {
 
         drop(user);
 
+        let house = ctx.accounts.house.load()?;
+        let house_bump = house.bump;
+        let house_mint_seed = house.mint_seed().to_vec();
+        let sponsored = house.is_fee_vault(&ctx.accounts.vrf_payer.key());
+        let vrf_fee = house.vrf_fee;
+        let native = house.is_native();
+        drop(house);
+
+        if native && ctx.accounts.needs_unwrap_account() {
+            msg!("creating the unwrap account");
+            create_unwrap_account(
+                &ctx.accounts.system_program,
+                &ctx.accounts.token_program,
+                &ctx.accounts.payer,
+                ctx.accounts.unwrap_account.as_ref().unwrap(),
+                &ctx.accounts.native_mint.as_ref().unwrap().to_account_info(),
+                &ctx.accounts.house.to_account_info(),
+                &ctx.accounts.user.key(),
+                *ctx.bumps.get("unwrap_account").unwrap(),
+            )?;
+        }
+
+        if native {
+            let mut required_amount = params.bet_amount.saturating_sub(ctx.accounts.escrow.amount);
+            if sponsored {
+                required_amount = required_amount.saturating_add(vrf_fee);
+            }
+            let wrap_amount = required_amount.saturating_sub(ctx.accounts.flip_payer.amount);
+            if wrap_amount > 0 {
+                msg!("wrapping {} lamports for the bet", wrap_amount);
+                wrap_native(
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.payer,
+                    &ctx.accounts.flip_payer,
+                    wrap_amount,
+                )?;
+            }
+        }
+
+        if sponsored && vrf_fee > 0 {
+            msg!(
+                "transferring {} flip tokens to the house for the vrf fee",
+                vrf_fee
+            );
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info().clone(),
+                    Transfer {
+                        from: ctx.accounts.flip_payer.to_account_info(),
+                        to: ctx.accounts.house_vault.to_account_info(),
+                        authority: ctx.accounts.authority.clone(),
+                    },
+                ),
+                vrf_fee,
+            )?;
+        }
+
         if ctx.accounts.escrow.amount >= params.bet_amount {
             msg!("escrow already funded");
         } else {
@@ -224,11 +376,13 @@ impl UserBet<'_> {
         UserState::request_randomness(&RequestRandomness {
             switchboard_program: ctx.accounts.switchboard_program.to_account_info(),
             house: ctx.accounts.house.to_account_info(),
+            house_mint_seed,
             authority: ctx.accounts.authority.to_account_info(),
             bumps: RequestRandomnessBumps {
                 user: user_bump,
                 switchboard_state: switchboard_state_bump,
                 vrf_permission: vrf_permission_bump,
+                house: if sponsored { Some(house_bump) } else { None },
             },
             accounts: RequestRandomnessAccounts {
                 user: ctx.accounts.user.to_account_info().clone(),
@@ -239,7 +393,11 @@ impl UserBet<'_> {
                 permission: ctx.accounts.permission.to_account_info(),
                 vrf_escrow: *ctx.accounts.vrf_escrow.clone(),
                 vrf_payer: *ctx.accounts.vrf_payer.clone(),
-                payer_authority: ctx.accounts.payer.to_account_info(),
+                payer_authority: if sponsored {
+                    ctx.accounts.house.to_account_info()
+                } else {
+                    ctx.accounts.payer.to_account_info()
+                },
                 recent_blockhashes: ctx.accounts.recent_blockhashes.to_account_info(),
                 switchboard_program_state: ctx.accounts.switchboard_program_state.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
@@ -251,10 +409,32 @@ impl UserBet<'_> {
 
         let game_type = GameType::from_u32(params.game_type)?;
 
-        user.new_round(game_type, params.user_guess, params.bet_amount)?;
+        let mut history_page = match &ctx.accounts.history_page {
+            Some(history_page) => Some(history_page.load_mut()?),
+            None => None,
+        };
+        let previous_round = user.current_round;
+        let previous_settle_pending = user.take_house_settle_pending();
+        user.new_round(
+            game_type,
+            params.user_guess,
+            params.bet_amount,
+            history_page.as_deref_mut(),
+        )?;
+        drop(history_page);
+        let round_id = user.current_round.round_id;
+        let max_payout = user.current_round.max_payout()?;
 
         drop(user);
 
+        let mut house = ctx.accounts.house.load_mut()?;
+        house.record_refund(&previous_round)?;
+        if previous_settle_pending {
+            house.record_settled_round(&previous_round)?;
+        }
+        house.record_bet(game_type, params.bet_amount, max_payout)?;
+        drop(house);
+
         emit!(UserBetPlaced {
             round_id: round_id,
             user: ctx.accounts.user.key(),
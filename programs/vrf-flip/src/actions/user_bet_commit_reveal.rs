@@ -0,0 +1,157 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(params: UserBetParams)] // rpc parameters hint
+pub struct UserBetCommitReveal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+        has_one = escrow,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = house_vault
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    #[account(
+        associated_token::mint = house.load()?.mint,
+        associated_token::authority = house,
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK:
+    #[account(mut, signer)]
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = house,
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = authority,
+    )]
+    pub flip_payer: Box<Account<'info, TokenAccount>>,
+
+    // SYSTEM ACCOUNTS
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    // HISTORY ACCOUNTS
+    // required once the history ring is full, see UserState::needs_history_page
+    #[account(mut, has_one = user)]
+    pub history_page: Option<AccountLoader<'info, UserHistoryPage>>,
+}
+
+impl UserBetCommitReveal<'_> {
+    pub fn validate(&self, ctx: &Context<Self>, params: &UserBetParams) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        let house = ctx.accounts.house.load()?;
+        params.validate(&user, &house, ctx.accounts.house_vault.amount)?;
+        drop(house);
+        if user.autoplay.is_active() {
+            return Err(error!(VrfFlipError::AutoPlayActive));
+        }
+
+        // the bet binds to a commitment the house made before the bet was placed
+        if user.commit_reveal.commitment == [0u8; 32] || user.commit_reveal.round_id != 0 {
+            return Err(error!(VrfFlipError::MissingCommitment));
+        }
+
+        if ctx.accounts.flip_payer.amount < params.bet_amount {
+            msg!(
+                "missing funds to play, need {}, have {}",
+                params.bet_amount,
+                ctx.accounts.flip_payer.amount
+            );
+            return Err(error!(VrfFlipError::InsufficientFunds));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &UserBetParams) -> anchor_lang::Result<()> {
+        msg!("user_bet_commit_reveal");
+        let clock = Clock::get()?;
+
+        if ctx.accounts.escrow.amount >= params.bet_amount {
+            msg!("escrow already funded");
+        } else {
+            let escrow_transfer_amount = params
+                .bet_amount
+                .checked_sub(ctx.accounts.escrow.amount)
+                .unwrap_or(params.bet_amount);
+            msg!(
+                "transferring {} flip tokens to escrow",
+                escrow_transfer_amount
+            );
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info().clone(),
+                    Transfer {
+                        from: ctx.accounts.flip_payer.to_account_info(),
+                        to: ctx.accounts.escrow.to_account_info(),
+                        authority: ctx.accounts.authority.clone(),
+                    },
+                ),
+                escrow_transfer_amount,
+            )?;
+        }
+
+        let user = &mut ctx.accounts.user.load_mut()?;
+
+        let game_type = GameType::from_u32(params.game_type)?;
+
+        let mut history_page = match &ctx.accounts.history_page {
+            Some(history_page) => Some(history_page.load_mut()?),
+            None => None,
+        };
+        let previous_round = user.current_round;
+        let previous_settle_pending = user.take_house_settle_pending();
+        user.new_round(
+            game_type,
+            params.user_guess,
+            params.bet_amount,
+            history_page.as_deref_mut(),
+        )?;
+        drop(history_page);
+        let round_id = user.current_round.round_id;
+        let max_payout = user.current_round.max_payout()?;
+        user.commit_reveal.round_id = round_id;
+        user.commit_reveal_rounds = user.commit_reveal_rounds.checked_add(1).unwrap();
+
+        drop(user);
+
+        let mut house = ctx.accounts.house.load_mut()?;
+        house.record_refund(&previous_round)?;
+        if previous_settle_pending {
+            house.record_settled_round(&previous_round)?;
+        }
+        house.record_bet(game_type, params.bet_amount, max_payout)?;
+        drop(house);
+
+        emit!(UserBetPlaced {
+            round_id: round_id,
+            user: ctx.accounts.user.key(),
+            game_type: game_type,
+            bet_amount: params.bet_amount,
+            guess: params.user_guess,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,352 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+pub use switchboard_v2::{
+    OracleQueueAccountData, PermissionAccountData, SbState, VrfAccountData, SWITCHBOARD_PROGRAM_ID,
+};
+const VRF_REQUEST_COST: u64 = 2 * LAMPORTS_PER_SOL / 1000;
+
+// Kept apart from user_bet, whose payer funds the bet from its own token account and
+// wallet and whose accounts are fixed by callers already. A delegate bet is funded from
+// the owner's reward address by the user PDA instead, so none of those accounts apply.
+#[derive(Accounts)]
+#[instruction(params: UserBetParams)] // rpc parameters hint
+pub struct UserBetDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = vrf,
+        has_one = authority,
+        has_one = escrow,
+        has_one = reward_address,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+        has_one = house_vault
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    #[account(
+        mut,
+        associated_token::mint = house.load()?.mint,
+        associated_token::authority = house,
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: the user's authority, approved the user PDA on the reward address
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = house,
+    )]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+    // funds the bet through the approval user_set_delegate gives the user PDA
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = authority,
+    )]
+    pub reward_address: Box<Account<'info, TokenAccount>>,
+
+    // SWITCHBOARD ACCOUNTS
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+        constraint =
+            vrf.load()?.escrow == vrf_escrow.key() &&
+            vrf.load()?.authority == user.key()
+    )]
+    pub vrf: AccountLoader<'info, VrfAccountData>,
+    /// CHECK
+    #[account(mut,
+        has_one = data_buffer,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+        constraint =
+            oracle_queue.load()?.authority == queue_authority.key()
+    )]
+    pub oracle_queue: AccountLoader<'info, OracleQueueAccountData>,
+    /// CHECK: Will be checked in the CPI instruction
+    pub queue_authority: UncheckedAccount<'info>,
+    /// CHECK
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+    )]
+    pub data_buffer: AccountInfo<'info>,
+    /// CHECK
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+    )]
+    pub permission: AccountLoader<'info, PermissionAccountData>,
+    #[account(
+        mut,
+        token::mint = house.load()?.switchboard_mint,
+        token::authority = switchboard_program_state,
+    )]
+    pub vrf_escrow: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Will be checked in the CPI instruction
+    #[account(mut,
+        owner = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+    )]
+    pub switchboard_program_state: AccountLoader<'info, SbState>,
+    /// CHECK:
+    #[account(
+        address = SWITCHBOARD_PROGRAM_ID @ VrfFlipError::InvalidSwitchboardAccount,
+        constraint =
+            switchboard_program.executable == true
+    )]
+    pub switchboard_program: AccountInfo<'info>,
+
+    // DELEGATE ACCOUNTS
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.switchboard_mint,
+        constraint =
+            vrf_payer.owner == delegate.key() ||
+            house.load()?.is_fee_vault(&vrf_payer.key()) @ VrfFlipError::InvalidVrfPayer
+    )]
+    pub vrf_payer: Box<Account<'info, TokenAccount>>,
+
+    // SYSTEM ACCOUNTS
+    /// CHECK:
+    #[account(address = solana_program::sysvar::recent_blockhashes::ID)]
+    pub recent_blockhashes: AccountInfo<'info>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    // HISTORY ACCOUNTS
+    // required once the history ring is full, see UserState::needs_history_page
+    #[account(mut, has_one = user)]
+    pub history_page: Option<AccountLoader<'info, UserHistoryPage>>,
+}
+
+impl UserBetDelegate<'_> {
+    pub fn validate(&self, ctx: &Context<Self>, params: &UserBetParams) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        let house = ctx.accounts.house.load()?;
+        params.validate(&user, &house, ctx.accounts.house_vault.amount)?;
+        drop(house);
+        if user.autoplay.is_active() {
+            return Err(error!(VrfFlipError::AutoPlayActive));
+        }
+
+        // the house fee vault can pay for randomness in exchange for a fee
+        let house = ctx.accounts.house.load()?;
+        let mut vrf_fee: u64 = 0;
+        if house.is_fee_vault(&ctx.accounts.vrf_payer.key()) {
+            if !house.sponsors_vrf_fees() {
+                return Err(error!(VrfFlipError::VrfSponsorshipDisabled));
+            }
+            vrf_fee = house.vrf_fee;
+        }
+        drop(house);
+
+        let required_balance = params
+            .bet_amount
+            .checked_add(vrf_fee)
+            .ok_or(error!(VrfFlipError::MaxBetAmountExceeded))?;
+        user.delegate.authorize(
+            &ctx.accounts.delegate.key(),
+            required_balance,
+            Clock::get()?.slot,
+        )?;
+        drop(user);
+
+        // the approval can be changed outside of the program, so check it as well
+        let reward_address = &ctx.accounts.reward_address;
+        if reward_address.delegate != COption::Some(ctx.accounts.user.key()) {
+            return Err(error!(VrfFlipError::InvalidDelegate));
+        }
+        if reward_address.delegated_amount < required_balance {
+            return Err(error!(VrfFlipError::DelegateAllowanceExceeded));
+        }
+
+        // check FLIP balance
+        if reward_address.amount < required_balance {
+            msg!(
+                "missing funds to play, need {}, have {}",
+                required_balance,
+                reward_address.amount
+            );
+            return Err(error!(VrfFlipError::InsufficientFunds));
+        }
+
+        // check token balance
+        let combined_balance = ctx
+            .accounts
+            .vrf_payer
+            .amount
+            .checked_add(ctx.accounts.vrf_escrow.amount)
+            .unwrap_or(0);
+        if combined_balance < VRF_REQUEST_COST {
+            msg!(
+                "missing funds to request randomness, need {}, have {}",
+                VRF_REQUEST_COST,
+                combined_balance
+            );
+            return Err(error!(VrfFlipError::InsufficientFunds));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: Context<Self>, params: &UserBetParams) -> anchor_lang::Result<()> {
+        msg!("user_bet_delegate");
+        let clock = Clock::get()?;
+
+        let user = ctx.accounts.user.load()?;
+        let user_bump = user.bump;
+        let switchboard_state_bump = user.switchboard_state_bump;
+        let vrf_permission_bump = user.vrf_permission_bump;
+        drop(user);
+
+        let house = ctx.accounts.house.load()?;
+        let house_bump = house.bump;
+        let house_mint_seed = house.mint_seed().to_vec();
+        let sponsored = house.is_fee_vault(&ctx.accounts.vrf_payer.key());
+        let vrf_fee = house.vrf_fee;
+        drop(house);
+
+        // the reward address approved the user PDA, which signs the transfers below
+        let house_key = ctx.accounts.house.key();
+        let authority_key = ctx.accounts.authority.key();
+        let user_seeds: &[&[&[u8]]] = &[&[
+            USER_SEED,
+            house_key.as_ref(),
+            authority_key.as_ref(),
+            &[user_bump],
+        ]];
+
+        let mut spent_amount: u64 = 0;
+        if sponsored && vrf_fee > 0 {
+            msg!(
+                "transferring {} flip tokens to the house for the vrf fee",
+                vrf_fee
+            );
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info().clone(),
+                    Transfer {
+                        from: ctx.accounts.reward_address.to_account_info(),
+                        to: ctx.accounts.house_vault.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                    user_seeds,
+                ),
+                vrf_fee,
+            )?;
+            spent_amount = vrf_fee;
+        }
+
+        if ctx.accounts.escrow.amount >= params.bet_amount {
+            msg!("escrow already funded");
+        } else {
+            let escrow_transfer_amount = params
+                .bet_amount
+                .checked_sub(ctx.accounts.escrow.amount)
+                .unwrap_or(params.bet_amount);
+            msg!(
+                "transferring {} flip tokens to escrow",
+                escrow_transfer_amount
+            );
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info().clone(),
+                    Transfer {
+                        from: ctx.accounts.reward_address.to_account_info(),
+                        to: ctx.accounts.escrow.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                    user_seeds,
+                ),
+                escrow_transfer_amount,
+            )?;
+            spent_amount = spent_amount.saturating_add(escrow_transfer_amount);
+        }
+
+        msg!("creating randomness instruction");
+        UserState::request_randomness(&RequestRandomness {
+            switchboard_program: ctx.accounts.switchboard_program.to_account_info(),
+            house: ctx.accounts.house.to_account_info(),
+            house_mint_seed,
+            authority: ctx.accounts.authority.to_account_info(),
+            bumps: RequestRandomnessBumps {
+                user: user_bump,
+                switchboard_state: switchboard_state_bump,
+                vrf_permission: vrf_permission_bump,
+                house: if sponsored { Some(house_bump) } else { None },
+            },
+            accounts: RequestRandomnessAccounts {
+                user: ctx.accounts.user.to_account_info().clone(),
+                vrf: ctx.accounts.vrf.to_account_info(),
+                oracle_queue: ctx.accounts.oracle_queue.to_account_info(),
+                queue_authority: ctx.accounts.queue_authority.to_account_info(),
+                data_buffer: ctx.accounts.data_buffer.to_account_info(),
+                permission: ctx.accounts.permission.to_account_info(),
+                vrf_escrow: *ctx.accounts.vrf_escrow.clone(),
+                vrf_payer: *ctx.accounts.vrf_payer.clone(),
+                payer_authority: if sponsored {
+                    ctx.accounts.house.to_account_info()
+                } else {
+                    ctx.accounts.delegate.to_account_info()
+                },
+                recent_blockhashes: ctx.accounts.recent_blockhashes.to_account_info(),
+                switchboard_program_state: ctx.accounts.switchboard_program_state.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        })?;
+        msg!("randomness requested successfully");
+
+        let user = &mut ctx.accounts.user.load_mut()?;
+
+        // tracks the spl approval, which the transfers above drew down
+        user.delegate.spend(spent_amount)?;
+
+        let game_type = GameType::from_u32(params.game_type)?;
+
+        let mut history_page = match &ctx.accounts.history_page {
+            Some(history_page) => Some(history_page.load_mut()?),
+            None => None,
+        };
+        let previous_round = user.current_round;
+        let previous_settle_pending = user.take_house_settle_pending();
+        user.new_round(
+            game_type,
+            params.user_guess,
+            params.bet_amount,
+            history_page.as_deref_mut(),
+        )?;
+        drop(history_page);
+        let round_id = user.current_round.round_id;
+        let max_payout = user.current_round.max_payout()?;
+
+        drop(user);
+
+        let mut house = ctx.accounts.house.load_mut()?;
+        house.record_refund(&previous_round)?;
+        if previous_settle_pending {
+            house.record_settled_round(&previous_round)?;
+        }
+        house.record_bet(game_type, params.bet_amount, max_payout)?;
+        drop(house);
+
+        emit!(UserBetPlaced {
+            round_id: round_id,
+            user: ctx.accounts.user.key(),
+            game_type: game_type,
+            bet_amount: params.bet_amount,
+            guess: params.user_guess,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp
+        });
+
+        Ok(())
+    }
+}
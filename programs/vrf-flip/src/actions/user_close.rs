@@ -0,0 +1,138 @@
+use crate::*;
+use anchor_spl::token::{CloseAccount, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(params: UserCloseParams)] // rpc parameters hint
+pub struct UserClose<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+        has_one = escrow,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(mut, signer)]
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = house,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    // HISTORY ACCOUNTS
+    // the page still being written to, older pages are closed with user_history_page_close
+    #[account(
+        mut,
+        close = authority,
+        has_one = user,
+    )]
+    pub history_page: Option<AccountLoader<'info, UserHistoryPage>>,
+
+    // NATIVE ACCOUNTS
+    // a native house closes the user's unwrap account with it, see UNWRAP_SEED
+    /// CHECK: only closed once the program created it
+    #[account(mut, seeds = [UNWRAP_SEED, user.key().as_ref()], bump)]
+    pub unwrap_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserCloseParams {}
+
+impl UserClose<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserCloseParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if user.current_round.is_open(&Clock::get()?) {
+            return Err(error!(VrfFlipError::CurrentRoundStillActive));
+        }
+
+        // pages left open would outlive the user and collide with a re-created account
+        let closing_pages = ctx.accounts.history_page.is_some() as u32;
+        if user.history_pages.saturating_sub(closing_pages) != 0 {
+            msg!("{} history pages still open", { user.history_pages });
+            return Err(error!(VrfFlipError::HistoryPagesOpen));
+        }
+        drop(user);
+
+        if ctx.accounts.escrow.amount != 0 {
+            msg!(
+                "escrow still holds {} flip tokens",
+                ctx.accounts.escrow.amount
+            );
+            return Err(error!(VrfFlipError::EscrowNotEmpty));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, _params: &UserCloseParams) -> anchor_lang::Result<()> {
+        msg!("user_close");
+
+        let house = ctx.accounts.house.load()?;
+        let house_bump = house.bump;
+        let house_mint_seed = house.mint_seed().to_vec();
+        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, house_mint_seed.as_ref(), &[house_bump]]];
+        drop(house);
+
+        // the escrow has no close authority, so the house closes it as the owner
+        msg!("closing user escrow");
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.escrow.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: ctx.accounts.house.to_account_info(),
+            },
+            house_seeds,
+        ))?;
+
+        // its lamports and any wrapped balance left in it go to the authority as SOL
+        if let Some(unwrap_account) = &ctx.accounts.unwrap_account {
+            if unwrap_account.owner == &token::ID {
+                msg!("closing user unwrap account");
+                close_unwrap_account(
+                    &ctx.accounts.token_program,
+                    &unwrap_account.to_account_info(),
+                    &ctx.accounts.authority,
+                    &ctx.accounts.house.to_account_info(),
+                    house_seeds,
+                )?;
+            }
+        }
+
+        // the house stops counting the user's last round before the user goes away, an
+        // expired round is refunded and one the vrf callback settled is recorded
+        let mut user = ctx.accounts.user.load_mut()?;
+        let settle_pending = user.take_house_settle_pending();
+        let round = user.current_round;
+        drop(user);
+        let mut house = ctx.accounts.house.load_mut()?;
+        house.record_refund(&round)?;
+        if settle_pending {
+            house.record_settled_round(&round)?;
+        }
+        drop(house);
+
+        Ok(())
+    }
+}
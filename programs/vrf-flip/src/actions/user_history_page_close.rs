@@ -0,0 +1,64 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: UserHistoryPageCloseParams)] // rpc parameters hint
+pub struct UserHistoryPageClose<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            HISTORY_PAGE_SEED,
+            user.key().as_ref(),
+            &history_page.load()?.page_index.to_le_bytes()
+        ],
+        bump = history_page.load()?.bump,
+        has_one = user,
+    )]
+    pub history_page: AccountLoader<'info, UserHistoryPage>,
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    /// CHECK:
+    #[account(mut, signer)]
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserHistoryPageCloseParams {}
+
+impl UserHistoryPageClose<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserHistoryPageCloseParams,
+    ) -> anchor_lang::Result<()> {
+        // only pages the user has moved past can be closed
+        let page_index = ctx.accounts.history_page.load()?.page_index;
+        if page_index >= ctx.accounts.user.load()?.history_page_index() {
+            return Err(error!(VrfFlipError::HistoryPageInUse));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        _params: &UserHistoryPageCloseParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("user_history_page_close");
+        msg!(
+            "closing history page {}, rent returned to {}",
+            { ctx.accounts.history_page.load()?.page_index },
+            ctx.accounts.authority.key()
+        );
+
+        // pages opened before the count was tracked aren't part of it
+        let user = &mut ctx.accounts.user.load_mut()?;
+        user.history_pages = user.history_pages.saturating_sub(1);
+        drop(user);
+
+        Ok(())
+    }
+}
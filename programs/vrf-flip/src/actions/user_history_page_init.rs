@@ -0,0 +1,75 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: UserHistoryPageInitParams)] // rpc parameters hint
+pub struct UserHistoryPageInit<'info> {
+    #[account(
+        init,
+        space = 8 + std::mem::size_of::<UserHistoryPage>(),
+        payer = payer,
+        seeds = [
+            HISTORY_PAGE_SEED,
+            user.key().as_ref(),
+            &params.page_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub history_page: AccountLoader<'info, UserHistoryPage>,
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            user.load()?.house.as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = authority,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    // pages count towards the user's history, so only the user can open them
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserHistoryPageInitParams {
+    pub page_index: u32,
+}
+
+impl UserHistoryPageInit<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        params: &UserHistoryPageInitParams,
+    ) -> anchor_lang::Result<()> {
+        // pages are created in order so the chain has no gaps
+        let user = ctx.accounts.user.load()?;
+        if params.page_index != user.history_page_index() {
+            return Err(error!(VrfFlipError::InvalidHistoryPage));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        params: &UserHistoryPageInitParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("user_history_page_init");
+
+        let history_page = &mut ctx.accounts.history_page.load_init()?;
+        history_page.bump = *ctx.bumps.get("history_page").unwrap();
+        history_page.user = ctx.accounts.user.key();
+        history_page.page_index = params.page_index;
+        history_page.len = 0;
+        drop(history_page);
+
+        let user = &mut ctx.accounts.user.load_mut()?;
+        user.history_pages = user.history_pages.checked_add(1).unwrap();
+        drop(user);
+
+        Ok(())
+    }
+}
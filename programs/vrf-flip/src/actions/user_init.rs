@@ -21,7 +21,7 @@ pub struct UserInit<'info> {
     )]
     pub user: AccountLoader<'info, UserState>,
     #[account(
-        seeds = [HOUSE_SEED],
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
         bump = house.load()?.bump,
         has_one = mint
     )]
@@ -104,12 +104,14 @@ impl UserInit<'_> {
         user.current_round = Round::default();
         user.last_airdrop_request_slot = 0;
         user.history = History::default();
+        user.version = USER_STATE_VERSION;
 
         drop(user);
 
         let house = ctx.accounts.house.load()?;
         let house_key = ctx.accounts.house.key().clone();
-        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, &[house.bump]]];
+        let house_mint_seed = house.mint_seed().to_vec();
+        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, house_mint_seed.as_ref(), &[house.bump]]];
         drop(house);
 
         msg!("setting user escrow authority to the house");
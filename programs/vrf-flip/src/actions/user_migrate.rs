@@ -0,0 +1,82 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: UserMigrateParams)] // rpc parameters hint
+pub struct UserMigrate<'info> {
+    /// CHECK: older layouts can't be loaded as a UserState, see UserState::migrate
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump,
+    )]
+    pub user: AccountInfo<'info>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK: only used to derive the user PDA
+    pub authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserMigrateParams {}
+
+impl UserMigrate<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserMigrateParams,
+    ) -> anchor_lang::Result<()> {
+        let data = ctx.accounts.user.try_borrow_data()?;
+        let version = UserState::version_of(&data)?;
+        if version == USER_STATE_VERSION {
+            return Err(error!(VrfFlipError::UserStateUpToDate));
+        }
+        if version > USER_STATE_VERSION {
+            return Err(error!(VrfFlipError::InvalidUserStateVersion));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, _params: &UserMigrateParams) -> anchor_lang::Result<()> {
+        msg!("user_migrate");
+
+        let user = ctx.accounts.user.to_account_info();
+        let size = UserState::size();
+        if user.data_len() < size {
+            let lamports = Rent::get()?
+                .minimum_balance(size)
+                .saturating_sub(user.lamports());
+            if lamports > 0 {
+                msg!("transferring {} lamports for the larger account", lamports);
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: user.clone(),
+                        },
+                    ),
+                    lamports,
+                )?;
+            }
+            user.realloc(size, true)?;
+        }
+
+        let mut data = user.try_borrow_mut_data()?;
+        UserState::migrate(&mut data)?;
+        msg!("migrated user to version {}", USER_STATE_VERSION);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: UserRecordSettleParams)] // rpc parameters hint
+pub struct UserRecordSettle<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            user.load()?.authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserRecordSettleParams {}
+
+impl UserRecordSettle<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserRecordSettleParams,
+    ) -> anchor_lang::Result<()> {
+        if ctx.accounts.user.load()?.house_settle_pending == 0 {
+            return Err(error!(VrfFlipError::NoPendingSettle));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        _params: &UserRecordSettleParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("user_record_settle");
+
+        // the vrf callback settles with a read-only house, anyone can record the round after
+        let mut user = ctx.accounts.user.load_mut()?;
+        user.take_house_settle_pending();
+        let round = user.current_round;
+        drop(user);
+
+        let mut house = ctx.accounts.house.load_mut()?;
+        house.record_settled_round(&round)?;
+        drop(house);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::*;
+use anchor_spl::token::{Approve, Revoke};
+
+#[derive(Accounts)]
+#[instruction(params: UserSetDelegateParams)] // rpc parameters hint
+pub struct UserSetDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+        has_one = reward_address,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+    // delegate bets are paid from here through an approval on the user PDA, so the
+    // delegate can only spend it with user_bet_delegate
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = authority,
+    )]
+    pub reward_address: Account<'info, TokenAccount>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserSetDelegateParams {
+    // the default pubkey revokes the current delegate
+    pub delegate: Pubkey,
+    pub expiry_slot: u64,
+    pub allowance: u64,
+}
+
+impl UserSetDelegate<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        params: &UserSetDelegateParams,
+    ) -> anchor_lang::Result<()> {
+        if params.delegate == ctx.accounts.authority.key() {
+            return Err(error!(VrfFlipError::InvalidDelegate));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &UserSetDelegateParams) -> anchor_lang::Result<()> {
+        msg!("user_set_delegate");
+
+        let mut user = ctx.accounts.user.load_mut()?;
+        user.delegate = if params.delegate == Pubkey::default() {
+            msg!("revoking delegate");
+            UserDelegate::default()
+        } else {
+            msg!(
+                "delegating {} flip tokens to {} until slot {}",
+                params.allowance,
+                params.delegate,
+                params.expiry_slot
+            );
+            UserDelegate {
+                key: params.delegate,
+                expiry_slot: params.expiry_slot,
+                remaining_allowance: params.allowance,
+            }
+        };
+        drop(user);
+
+        if params.delegate == Pubkey::default() {
+            token::revoke(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Revoke {
+                    source: ctx.accounts.reward_address.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ))?;
+        } else {
+            token::approve(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Approve {
+                        to: ctx.accounts.reward_address.to_account_info(),
+                        delegate: ctx.accounts.user.to_account_info(),
+                        authority: ctx.accounts.authority.to_account_info(),
+                    },
+                ),
+                params.allowance,
+            )?;
+        }
+
+        Ok(())
+    }
+}
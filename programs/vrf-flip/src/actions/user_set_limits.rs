@@ -0,0 +1,67 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: UserSetLimitsParams)] // rpc parameters hint
+pub struct UserSetLimits<'info> {
+    #[account(
+        mut,
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserSetLimitsParams {
+    pub daily_wager_limit: Option<u64>,
+    pub weekly_wager_limit: Option<u64>,
+    pub daily_loss_limit: Option<u64>,
+    pub cooldown: Option<u32>,
+    pub self_excluded_until: Option<i64>,
+}
+
+impl UserSetLimits<'_> {
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        params: &UserSetLimitsParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if let Some(self_excluded_until) = params.self_excluded_until {
+            if self_excluded_until < user.limits.self_excluded_until {
+                return Err(error!(VrfFlipError::SelfExclusionShortened));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(ctx: &Context<Self>, params: &UserSetLimitsParams) -> anchor_lang::Result<()> {
+        msg!("user_set_limits");
+
+        let clock = Clock::get()?;
+        let mut user = ctx.accounts.user.load_mut()?;
+        user.limits.set(params, clock.unix_timestamp);
+        if let Some(self_excluded_until) = params.self_excluded_until {
+            msg!("self-excluded until {}", self_excluded_until);
+            user.limits.self_excluded_until = self_excluded_until;
+        }
+        drop(user);
+
+        Ok(())
+    }
+}
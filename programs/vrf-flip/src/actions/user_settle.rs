@@ -18,8 +18,10 @@ pub struct UserSettle<'info> {
         has_one = reward_address,
     )]
     pub user: AccountLoader<'info, UserState>,
+    // read-only in vrf callbacks, the round is recorded in the house once it's writable,
+    // see UserState::house_settle_pending
     #[account(
-        seeds = [HOUSE_SEED],
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
         bump = house.load()?.bump,
         has_one = house_vault,
     )]
@@ -52,12 +54,40 @@ pub struct UserSettle<'info> {
     )]
     pub vrf: AccountLoader<'info, VrfAccountData>,
     pub token_program: Program<'info, Token>,
+
+    // HISTORY ACCOUNTS
+    // left out of the vrf callback, see UserState::needs_history_page_to_settle
+    #[account(mut, has_one = user)]
+    pub history_page: Option<AccountLoader<'info, UserHistoryPage>>,
+
+    // NATIVE ACCOUNTS
+    // a native house pays through the unwrap account when both are passed, see UNWRAP_SEED
+    /// CHECK: only used once the program created it
+    #[account(mut, seeds = [UNWRAP_SEED, user.key().as_ref()], bump)]
+    pub unwrap_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the unwrapped payout
+    #[account(mut, address = user.load()?.authority @ VrfFlipError::InvalidUnwrapDestination)]
+    pub authority: Option<UncheckedAccount<'info>>,
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct UserSettleParams {}
 
-impl UserSettle<'_> {
+impl<'info> UserSettle<'info> {
+    /// The account payouts go to before being unwrapped, when a native house was passed
+    /// the user's unwrap account and authority. Other payouts go to the reward address.
+    pub fn unwrap_account(&self) -> anchor_lang::Result<Option<Account<'info, TokenAccount>>> {
+        if !self.house.load()?.is_native() || self.authority.is_none() {
+            return Ok(None);
+        }
+        match &self.unwrap_account {
+            Some(unwrap_account) if unwrap_account.owner == &token::ID => {
+                Ok(Some(Account::try_from(&unwrap_account.to_account_info())?))
+            }
+            _ => Ok(None),
+        }
+    }
+
     pub fn validate(
         &self,
         ctx: &Context<Self>,
@@ -76,9 +106,15 @@ impl UserSettle<'_> {
 
         let house = ctx.accounts.house.load()?;
         let house_bump = house.bump.clone();
-        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, &[house_bump]]];
+        let house_mint_seed = house.mint_seed().to_vec();
+        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, house_mint_seed.as_ref(), &[house_bump]]];
         drop(house);
 
+        let unwrap_account = ctx.accounts.unwrap_account()?;
+        let payout_address = unwrap_account
+            .as_ref()
+            .unwrap_or(&ctx.accounts.reward_address);
+
         let vrf = ctx.accounts.vrf.load()?;
         if vrf.authority != ctx.accounts.user.key() {
             return Err(error!(VrfFlipError::InvalidVrfAuthority));
@@ -86,15 +122,58 @@ impl UserSettle<'_> {
 
         let mut user = ctx.accounts.user.load_mut()?;
 
-        if vrf.counter != user.current_round.round_id {
-            return Err(error!(VrfFlipError::IncorrectVrfCounter));
-        }
-
-        let vrf_result_buffer = vrf.get_result()?;
-        let vrf_value: &[u32] = bytemuck::cast_slice(&vrf_result_buffer[..]);
+        let vrf_result_buffer: Option<[u8; 32]> = if user.is_commit_reveal_round() {
+            if user.commit_reveal.result != [0u8; 32] {
+                Some(user.commit_reveal.result)
+            } else if user.current_round.reveal_timed_out(&clock) {
+                msg!("house failed to reveal in time");
+                None
+            } else {
+                return Err(error!(VrfFlipError::RandomnessNotRevealed));
+            }
+        } else {
+            if vrf.counter != user.expected_vrf_counter() {
+                return Err(error!(VrfFlipError::IncorrectVrfCounter));
+            }
+            // get_result only checks a result was written, not that its proof checked out
+            if vrf.current_round.num_verified == 0 {
+                return Err(error!(VrfFlipError::VrfNotVerified));
+            }
+            Some(vrf.get_result()?)
+        };
+        let (vrf_counter, num_verified) = if user.is_commit_reveal_round() {
+            (0, 0)
+        } else {
+            (vrf.counter, vrf.current_round.num_verified)
+        };
+        drop(vrf);
 
-        let user_won = user.current_round.settle(vrf_value)?;
+        let user_won = match vrf_result_buffer {
+            Some(vrf_result_buffer) => {
+                let vrf_value: &[u32] = bytemuck::cast_slice(&vrf_result_buffer[..]);
+                user.current_round.settle(vrf_value, &clock)?
+            }
+            None => user.current_round.forfeit(&clock)?,
+        };
         let reward_amount = user.current_round.payout_amount()?;
+        let bet_amount = user.current_round.bet_amount;
+        let max_payout = user.current_round.max_payout()?;
+        user.stats.record(bet_amount, reward_amount, user_won);
+        user.limits
+            .record_settle(bet_amount, reward_amount, user_won, clock.unix_timestamp);
+
+        if user.autoplay.is_active() {
+            user.autoplay.record(bet_amount, reward_amount, user_won);
+        }
+
+        // anything escrowed beyond the bet goes back to the user whether they win or lose,
+        // except what an active autoplay session still needs for its remaining bets
+        let residual_amount = ctx
+            .accounts
+            .escrow
+            .amount
+            .saturating_sub(bet_amount)
+            .saturating_sub(user.autoplay.reserved_amount());
 
         let escrow_change: u64;
         if user_won {
@@ -103,7 +182,7 @@ impl UserSettle<'_> {
             transfer(
                 &ctx.accounts.token_program,
                 &ctx.accounts.house_vault,
-                &ctx.accounts.reward_address,
+                payout_address,
                 &ctx.accounts.house.to_account_info(),
                 house_seeds,
                 reward_amount,
@@ -111,10 +190,10 @@ impl UserSettle<'_> {
             transfer(
                 &ctx.accounts.token_program,
                 &ctx.accounts.escrow,
-                &ctx.accounts.reward_address,
+                payout_address,
                 &ctx.accounts.house.to_account_info(),
                 house_seeds,
-                ctx.accounts.escrow.amount,
+                bet_amount,
             )?;
         } else {
             escrow_change = user.current_round.bet_amount;
@@ -129,6 +208,43 @@ impl UserSettle<'_> {
             )?;
         }
 
+        if residual_amount > 0 {
+            msg!("returning {} residual escrow tokens", residual_amount);
+            transfer(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow,
+                payout_address,
+                &ctx.accounts.house.to_account_info(),
+                house_seeds,
+                residual_amount,
+            )?;
+        }
+
+        // an active autoplay session keeps paying into the unwrap account until it stops
+        if let Some(unwrap_account) = &unwrap_account {
+            if !user.autoplay.is_active() {
+                msg!("unwrapping the payout to the authority");
+                close_unwrap_account(
+                    &ctx.accounts.token_program,
+                    &unwrap_account.to_account_info(),
+                    ctx.accounts.authority.as_ref().unwrap(),
+                    &ctx.accounts.house.to_account_info(),
+                    house_seeds,
+                )?;
+            }
+        }
+
+        // the house signs the transfers above, so only borrow it once they are done
+        let game_type = user.current_round.game_type;
+        if ctx.accounts.house.to_account_info().is_writable {
+            let mut house = ctx.accounts.house.load_mut()?;
+            house.record_settle(game_type, bet_amount, max_payout, reward_amount)?;
+            drop(house);
+        } else {
+            msg!("house is read-only, the round is recorded in it later");
+            user.house_settle_pending = 1;
+        }
+
         emit!(UserBetSettled {
             round_id: user.current_round.round_id,
             user: ctx.accounts.user.key(),
@@ -142,8 +258,26 @@ impl UserSettle<'_> {
             timestamp: clock.unix_timestamp
         });
 
+        // a forfeited round had no randomness to record
+        if let Some(randomness) = vrf_result_buffer {
+            emit!(UserRoundRandomness {
+                round_id: user.current_round.round_id,
+                user: ctx.accounts.user.key(),
+                vrf_counter: vrf_counter,
+                num_verified: num_verified,
+                randomness: randomness
+            });
+        }
+
         user.current_round.status = RoundStatus::Settled;
 
+        let mut history_page = match &ctx.accounts.history_page {
+            Some(history_page) => Some(history_page.load_mut()?),
+            None => None,
+        };
+        user.archive_current_round(history_page.as_deref_mut())?;
+        drop(history_page);
+
         Ok(())
     }
 }
@@ -0,0 +1,164 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(params: UserWithdrawEscrowParams)] // rpc parameters hint
+pub struct UserWithdrawEscrow<'info> {
+    #[account(
+        seeds = [
+            USER_SEED,
+            house.key().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump = user.load()?.bump,
+        has_one = house,
+        has_one = authority,
+        has_one = escrow,
+        has_one = reward_address,
+    )]
+    pub user: AccountLoader<'info, UserState>,
+    #[account(
+        seeds = [HOUSE_SEED, house.load()?.mint_seed()],
+        bump = house.load()?.bump,
+    )]
+    pub house: AccountLoader<'info, HouseState>,
+    /// CHECK:
+    #[account(mut, signer)]
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = house,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = house.load()?.mint,
+        token::authority = authority,
+    )]
+    pub reward_address: Account<'info, TokenAccount>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    // NATIVE ACCOUNTS
+    // a native house unwraps the withdrawal through these when they are passed, see UNWRAP_SEED
+    /// CHECK: created by the program
+    #[account(mut, seeds = [UNWRAP_SEED, user.key().as_ref()], bump)]
+    pub unwrap_account: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: Option<UncheckedAccount<'info>>,
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserWithdrawEscrowParams {}
+
+impl UserWithdrawEscrow<'_> {
+    /// The amount of escrow not backing an unsettled bet or an active autoplay session.
+    pub fn unlocked_amount(&self) -> anchor_lang::Result<u64> {
+        let user = self.user.load()?;
+        let locked_amount = if user.current_round.status == RoundStatus::Awaiting {
+            user.current_round.bet_amount
+        } else {
+            0
+        };
+
+        Ok(self
+            .escrow
+            .amount
+            .saturating_sub(locked_amount)
+            .saturating_sub(user.autoplay.reserved_amount()))
+    }
+
+    pub fn validate(
+        &self,
+        ctx: &Context<Self>,
+        _params: &UserWithdrawEscrowParams,
+    ) -> anchor_lang::Result<()> {
+        let user = ctx.accounts.user.load()?;
+        if user.current_round.is_open(&Clock::get()?) {
+            return Err(error!(VrfFlipError::CurrentRoundStillActive));
+        }
+        drop(user);
+
+        if ctx.accounts.unlocked_amount()? == 0 {
+            return Err(error!(VrfFlipError::NoUnlockedEscrow));
+        }
+
+        Ok(())
+    }
+
+    pub fn actuate(
+        ctx: &Context<Self>,
+        _params: &UserWithdrawEscrowParams,
+    ) -> anchor_lang::Result<()> {
+        msg!("user_withdraw_escrow");
+
+        let house = ctx.accounts.house.load()?;
+        let house_bump = house.bump;
+        let house_mint_seed = house.mint_seed().to_vec();
+        let house_seeds: &[&[&[u8]]] = &[&[&HOUSE_SEED, house_mint_seed.as_ref(), &[house_bump]]];
+        let native = house.is_native();
+        drop(house);
+
+        let amount = ctx.accounts.unlocked_amount()?;
+
+        if let (true, Some(unwrap_account), Some(native_mint), Some(system_program)) = (
+            native,
+            &ctx.accounts.unwrap_account,
+            &ctx.accounts.native_mint,
+            &ctx.accounts.system_program,
+        ) {
+            if unwrap_account.owner != &token::ID {
+                msg!("creating the unwrap account");
+                create_unwrap_account(
+                    system_program,
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.authority,
+                    unwrap_account,
+                    &native_mint.to_account_info(),
+                    &ctx.accounts.house.to_account_info(),
+                    &ctx.accounts.user.key(),
+                    *ctx.bumps.get("unwrap_account").unwrap(),
+                )?;
+            }
+            let unwrap_account: Account<TokenAccount> =
+                Account::try_from(&unwrap_account.to_account_info())?;
+
+            msg!("unwrapping {} lamports from escrow", amount);
+            transfer(
+                &ctx.accounts.token_program,
+                &ctx.accounts.escrow,
+                &unwrap_account,
+                &ctx.accounts.house.to_account_info(),
+                house_seeds,
+                amount,
+            )?;
+
+            // an active autoplay session keeps paying into the unwrap account until it stops
+            if !ctx.accounts.user.load()?.autoplay.is_active() {
+                close_unwrap_account(
+                    &ctx.accounts.token_program,
+                    &unwrap_account.to_account_info(),
+                    &ctx.accounts.authority,
+                    &ctx.accounts.house.to_account_info(),
+                    house_seeds,
+                )?;
+            }
+            return Ok(());
+        }
+
+        msg!("withdrawing {} flip tokens from escrow", amount);
+        transfer(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow,
+            &ctx.accounts.reward_address,
+            &ctx.accounts.house.to_account_info(),
+            house_seeds,
+            amount,
+        )?;
+
+        Ok(())
+    }
+}
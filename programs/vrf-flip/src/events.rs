@@ -0,0 +1,106 @@
+//! Decodes the program's events from transaction logs.
+//!
+//! `emit!` logs each event as `Program data: <base64>`, where the payload is the
+//! event discriminator followed by its borsh encoding. New events only need to
+//! be added to the `flip_events!` list below.
+use crate::*;
+use anchor_lang::Discriminator;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::{Error, ErrorKind};
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+macro_rules! flip_events {
+    ($($event:ident),* $(,)?) => {
+        /// Every event the program emits
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum FlipEvent {
+            $($event($event),)*
+        }
+
+        impl FlipEvent {
+            /// Decodes an event payload, returns `None` for unknown discriminators
+            pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Option<Self>> {
+                if data.len() < 8 {
+                    return Ok(None);
+                }
+                let (discriminator, mut payload) = data.split_at(8);
+                $(
+                    if discriminator == $event::DISCRIMINATOR {
+                        return Ok(Some(FlipEvent::$event($event::deserialize(&mut payload)?)));
+                    }
+                )*
+                Ok(None)
+            }
+
+            /// Encodes the event the same way `emit!` does
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let (discriminator, payload) = match self {
+                    $(FlipEvent::$event(event) => ($event::DISCRIMINATOR, event.try_to_vec()),)*
+                };
+                let mut data = discriminator.to_vec();
+                data.extend(payload.unwrap());
+                data
+            }
+        }
+    };
+}
+
+flip_events!(
+    UserBetPlaced,
+    UserBetSettled,
+    UserRoundRandomness,
+    HouseRevealed,
+);
+
+impl FlipEvent {
+    /// Decodes a single `Program data:` log line, returns `None` for any other log
+    pub fn from_log(log: &str) -> std::io::Result<Option<Self>> {
+        let Some(data) = log.strip_prefix(PROGRAM_DATA) else {
+            return Ok(None);
+        };
+        let data = STANDARD
+            .decode(data.trim())
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Self::try_from_bytes(&data)
+    }
+
+    /// The log line `emit!` writes for this event
+    pub fn to_log(&self) -> String {
+        format!("{}{}", PROGRAM_DATA, STANDARD.encode(self.to_bytes()))
+    }
+}
+
+/// Decodes the events emitted by this program from a transaction's log
+/// messages, skipping data logged by any other program in the call stack.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> std::io::Result<Vec<FlipEvent>> {
+    let program_id = ID.to_string();
+    let mut stack: Vec<String> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs.iter().map(AsRef::as_ref) {
+        if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            let (program, action) = (words.next(), words.next());
+            match action {
+                Some("invoke") => {
+                    stack.push(program.unwrap_or_default().to_string());
+                    continue;
+                }
+                Some("success") | Some("failed:") => {
+                    stack.pop();
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        if stack.last() == Some(&program_id) {
+            if let Some(event) = FlipEvent::from_log(log)? {
+                events.push(event);
+            }
+        }
+    }
+
+    Ok(events)
+}
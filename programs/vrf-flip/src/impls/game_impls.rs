@@ -13,6 +13,16 @@ impl GameType {
         }
     }
 
+    // position of the game in per-game arrays such as HouseState::game_stats
+    pub fn index(&self) -> anchor_lang::Result<usize> {
+        match self {
+            GameType::CoinFlip => Ok(0),
+            GameType::SixSidedDiceRoll => Ok(1),
+            GameType::TwentySidedDiceRoll => Ok(2),
+            _ => Err(error!(VrfFlipError::InvalidGameType)),
+        }
+    }
+
     pub fn get_game_config(&self) -> anchor_lang::Result<GameConfig> {
         match self {
             GameType::CoinFlip => Ok(GameConfig {
@@ -52,3 +62,95 @@ impl Default for GameType {
         GameType::None
     }
 }
+
+impl GameStats {
+    pub fn record_bet(&mut self, bet_amount: u64, max_payout: u64) {
+        self.volume = self.volume.saturating_add(bet_amount);
+        self.open_exposure = self.open_exposure.saturating_add(max_payout);
+        self.rounds_placed = self.rounds_placed.saturating_add(1);
+    }
+
+    // a round replaced by a new bet before it settled no longer owes a payout
+    pub fn record_refund(&mut self, max_payout: u64) {
+        self.open_exposure = self.open_exposure.saturating_sub(max_payout);
+    }
+
+    pub fn record_settle(&mut self, bet_amount: u64, max_payout: u64, payout_amount: u64) {
+        self.open_exposure = self.open_exposure.saturating_sub(max_payout);
+        self.rounds_settled = self.rounds_settled.saturating_add(1);
+
+        if payout_amount > 0 {
+            self.payouts = self.payouts.saturating_add(payout_amount);
+            self.net_pnl = self.net_pnl.saturating_sub_unsigned(payout_amount);
+        } else {
+            self.net_pnl = self.net_pnl.saturating_add_unsigned(bet_amount);
+        }
+    }
+}
+
+impl BetLimits {
+    pub fn max_bet(&self) -> u64 {
+        if self.max_bet == 0 {
+            MAX_BET_AMOUNT
+        } else {
+            self.max_bet
+        }
+    }
+
+    pub fn validate(&self) -> anchor_lang::Result<()> {
+        if self.min_bet > self.max_bet() || self.max_payout_bps > MAX_BPS {
+            return Err(error!(VrfFlipError::InvalidBetLimits));
+        }
+
+        Ok(())
+    }
+
+    // checks a bet and the payout it can win against the house vault balance
+    pub fn check_bet(
+        &self,
+        bet_amount: u64,
+        max_payout: u64,
+        house_vault_balance: u64,
+    ) -> anchor_lang::Result<()> {
+        let min_bet = self.min_bet;
+        if bet_amount < min_bet {
+            msg!("bet of {} is below the min bet of {}", bet_amount, min_bet);
+            return Err(error!(VrfFlipError::MinBetAmountNotMet));
+        }
+        let max_bet = self.max_bet();
+        if bet_amount > max_bet {
+            msg!("bet of {} is above the max bet of {}", bet_amount, max_bet);
+            return Err(error!(VrfFlipError::MaxBetAmountExceeded));
+        }
+
+        let max_payout_bps = self.max_payout_bps;
+        // without a payout limit the house keeps the original rule on the bet itself
+        if max_payout_bps == 0 {
+            let max_bet_allowed = house_vault_balance / DEFAULT_MAX_BET_VAULT_RATIO;
+            if bet_amount > max_bet_allowed {
+                msg!(
+                    "bet of {} is above the max bet of {} for the house vault",
+                    bet_amount,
+                    max_bet_allowed
+                );
+                return Err(error!(VrfFlipError::MaxBetAmountExceeded));
+            }
+            return Ok(());
+        }
+
+        let max_payout_allowed = (house_vault_balance as u128)
+            .checked_mul(max_payout_bps as u128)
+            .and_then(|amount| amount.checked_div(MAX_BPS as u128))
+            .ok_or(error!(VrfFlipError::MaxPayoutExceeded))?;
+        if max_payout as u128 > max_payout_allowed {
+            msg!(
+                "payout of {} is above the max payout of {}",
+                max_payout,
+                max_payout_allowed
+            );
+            return Err(error!(VrfFlipError::MaxPayoutExceeded));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::*;
+
+impl History {
+    // rounds in the ring, newest first
+    pub fn iter_recent(&self) -> impl DoubleEndedIterator<Item = &Round> + '_ {
+        let idx = self.idx as usize;
+        let len = self.rounds.len();
+        (1..=len)
+            .map(move |i| &self.rounds[(idx + len - i) % len])
+            .filter(|round| round.round_id != 0)
+    }
+
+    pub fn push(&mut self, round: Round) {
+        let idx = self.idx;
+        self.rounds[idx as usize] = round;
+        self.idx = (idx + 1) % MAX_HISTORY;
+    }
+}
+
+impl UserHistoryPage {
+    pub fn size() -> usize {
+        std::mem::size_of::<UserHistoryPage>() + 8
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len >= HISTORY_PAGE_SIZE
+    }
+
+    pub fn push(&mut self, round: Round) -> anchor_lang::Result<()> {
+        if self.is_full() {
+            return Err(error!(VrfFlipError::InvalidHistoryPage));
+        }
+        let len = self.len;
+        self.rounds[len as usize] = round;
+        self.payouts[len as usize] = round.settled_payout()?;
+        self.len = len + 1;
+        Ok(())
+    }
+}
+
+impl UserState {
+    // the page that the next round pushed out of the history ring is archived to
+    pub fn history_page_index(&self) -> u32 {
+        (self.archived_rounds / HISTORY_PAGE_SIZE as u64) as u32
+    }
+
+    pub fn is_current_round_archived(&self) -> bool {
+        let round_id = self.current_round.round_id;
+        round_id == 0
+            || self
+                .history
+                .iter_recent()
+                .next()
+                .map(|round| round.round_id)
+                == Some(round_id)
+    }
+
+    // whether the next bet moves a round out of the full history ring
+    pub fn needs_history_page(&self) -> bool {
+        let idx = self.history.idx as usize;
+        if self.is_current_round_archived() {
+            return self.history.rounds[idx].round_id != 0;
+        }
+        let settle_idx = (idx + 1) % MAX_HISTORY as usize;
+        self.history.rounds[idx].round_id != 0 || self.history.rounds[settle_idx].round_id != 0
+    }
+
+    pub fn archive_round(
+        &mut self,
+        page: &mut UserHistoryPage,
+        round: Round,
+    ) -> anchor_lang::Result<()> {
+        if page.page_index != self.history_page_index() {
+            return Err(error!(VrfFlipError::InvalidHistoryPage));
+        }
+        page.push(round)?;
+        self.archived_rounds = self.archived_rounds.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    fn archive_history_slot(
+        &mut self,
+        idx: usize,
+        page: &mut UserHistoryPage,
+    ) -> anchor_lang::Result<()> {
+        let round = self.history.rounds[idx];
+        self.archive_round(page, round)?;
+        self.history.rounds[idx] = Round::default();
+        Ok(())
+    }
+
+    // whether settling the current round moves a round out of the full history ring,
+    // only when the bet that started it couldn't free the slot it settles into
+    pub fn needs_history_page_to_settle(&self) -> bool {
+        let idx = self.history.idx as usize;
+        !self.is_current_round_archived() && self.history.rounds[idx].round_id != 0
+    }
+
+    // pushes the settled round to history, archiving the round in its slot first
+    // so rounds reach the history pages in the order they were played
+    pub fn archive_current_round(
+        &mut self,
+        history_page: Option<&mut UserHistoryPage>,
+    ) -> anchor_lang::Result<()> {
+        if self.is_current_round_archived() {
+            return Ok(());
+        }
+        let idx = self.history.idx as usize;
+        if self.history.rounds[idx].round_id != 0 {
+            let page = history_page.ok_or(error!(VrfFlipError::HistoryPageRequired))?;
+            self.archive_history_slot(idx, page)?;
+        }
+        let round = self.current_round;
+        self.history.push(round);
+        Ok(())
+    }
+
+    // called before a new round starts, pushes the current round to history if
+    // it was never archived and frees the slot the new round settles into
+    pub fn prepare_history(
+        &mut self,
+        mut history_page: Option<&mut UserHistoryPage>,
+    ) -> anchor_lang::Result<()> {
+        let mut page_filled = false;
+        if !self.is_current_round_archived() {
+            let idx = self.history.idx as usize;
+            if self.history.rounds[idx].round_id != 0 {
+                let page = history_page
+                    .as_deref_mut()
+                    .ok_or(error!(VrfFlipError::HistoryPageRequired))?;
+                self.archive_history_slot(idx, page)?;
+                page_filled = page.is_full();
+            }
+            let round = self.current_round;
+            self.history.push(round);
+        }
+
+        let idx = self.history.idx as usize;
+        if self.history.rounds[idx].round_id != 0 {
+            match history_page {
+                // the page is full, settlement archives the round to the next page
+                Some(_) if page_filled => {}
+                Some(page) => self.archive_history_slot(idx, page)?,
+                None => return Err(error!(VrfFlipError::HistoryPageRequired)),
+            }
+        }
+
+        Ok(())
+    }
+}
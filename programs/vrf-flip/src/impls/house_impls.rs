@@ -0,0 +1,143 @@
+use crate::*;
+
+impl HouseState {
+    pub fn is_fee_vault(&self, key: &Pubkey) -> bool {
+        let fee_vault = self.fee_vault;
+        fee_vault != Pubkey::default() && fee_vault == *key
+    }
+
+    // the seed after HOUSE_SEED in the house PDA, empty for houses seeded by HOUSE_SEED alone
+    // since an empty seed leaves their address unchanged
+    pub fn mint_seed(&self) -> &[u8] {
+        if self.mint_seeded == 1 {
+            self.mint.as_ref()
+        } else {
+            &[]
+        }
+    }
+
+    // native houses take bets in wrapped SOL and unwrap payouts, see UNWRAP_SEED
+    pub fn is_native(&self) -> bool {
+        let mint = self.mint;
+        mint == token::spl_token::native_mint::ID
+    }
+
+    pub fn sponsors_vrf_fees(&self) -> bool {
+        self.sponsor_vrf_fees != 0
+    }
+
+    pub fn game_stats_mut(&mut self, game_type: GameType) -> anchor_lang::Result<&mut GameStats> {
+        Ok(&mut self.game_stats[game_type.index()?])
+    }
+
+    pub fn max_liability_bps(&self) -> u32 {
+        if self.max_liability_bps == 0 {
+            DEFAULT_MAX_LIABILITY_BPS
+        } else {
+            self.max_liability_bps
+        }
+    }
+
+    // checks the open liability stays within its share of the house vault once a bet
+    // with `max_payout` replaces a round that still owed `released_payout`
+    pub fn check_liability(
+        &self,
+        released_payout: u64,
+        max_payout: u64,
+        house_vault_balance: u64,
+    ) -> anchor_lang::Result<()> {
+        let open_liability = self
+            .open_liability
+            .saturating_sub(released_payout)
+            .checked_add(max_payout)
+            .ok_or(error!(VrfFlipError::MaxLiabilityExceeded))?;
+        let max_liability = (house_vault_balance as u128)
+            .checked_mul(self.max_liability_bps() as u128)
+            .and_then(|amount| amount.checked_div(MAX_BPS as u128))
+            .ok_or(error!(VrfFlipError::MaxLiabilityExceeded))?;
+        if open_liability as u128 > max_liability {
+            msg!(
+                "open liability of {} would exceed the max liability of {}",
+                open_liability,
+                max_liability
+            );
+            return Err(error!(VrfFlipError::MaxLiabilityExceeded));
+        }
+
+        Ok(())
+    }
+
+    pub fn record_bet(
+        &mut self,
+        game_type: GameType,
+        bet_amount: u64,
+        max_payout: u64,
+    ) -> anchor_lang::Result<()> {
+        self.game_stats_mut(game_type)?
+            .record_bet(bet_amount, max_payout);
+        self.open_liability = self
+            .open_liability
+            .checked_add(max_payout)
+            .ok_or(error!(VrfFlipError::MaxLiabilityExceeded))?;
+        Ok(())
+    }
+
+    pub fn record_settle(
+        &mut self,
+        game_type: GameType,
+        bet_amount: u64,
+        max_payout: u64,
+        payout_amount: u64,
+    ) -> anchor_lang::Result<()> {
+        self.game_stats_mut(game_type)?
+            .record_settle(bet_amount, max_payout, payout_amount);
+        self.release_liability(max_payout);
+        Ok(())
+    }
+
+    // records a round that settled without a writable house, see
+    // UserState::house_settle_pending
+    pub fn record_settled_round(&mut self, round: &Round) -> anchor_lang::Result<()> {
+        self.record_settle(
+            round.game_type,
+            round.bet_amount,
+            round.max_payout()?,
+            round.payout_amount()?,
+        )
+    }
+
+    // releases what an unsettled round owed once a new bet replaces it
+    pub fn record_refund(&mut self, round: &Round) -> anchor_lang::Result<()> {
+        let max_payout = round.outstanding_payout()?;
+        if max_payout == 0 {
+            return Ok(());
+        }
+        self.game_stats_mut(round.game_type)?
+            .record_refund(max_payout);
+        self.release_liability(max_payout);
+        Ok(())
+    }
+
+    fn release_liability(&mut self, max_payout: u64) {
+        // rounds placed before the liability was tracked were never added to it
+        self.open_liability = self.open_liability.saturating_sub(max_payout);
+    }
+
+    pub fn bet_limits(&self, game_type: GameType) -> anchor_lang::Result<BetLimits> {
+        Ok(self.bet_limits[game_type.index()?])
+    }
+
+    // house-wide totals across every game
+    pub fn total_stats(&self) -> GameStats {
+        let mut total = GameStats::default();
+        for stats in self.game_stats {
+            total.volume = total.volume.saturating_add(stats.volume);
+            total.payouts = total.payouts.saturating_add(stats.payouts);
+            total.net_pnl = total.net_pnl.saturating_add(stats.net_pnl);
+            total.open_exposure = total.open_exposure.saturating_add(stats.open_exposure);
+            total.rounds_placed = total.rounds_placed.saturating_add(stats.rounds_placed);
+            total.rounds_settled = total.rounds_settled.saturating_add(stats.rounds_settled);
+        }
+        total
+    }
+}
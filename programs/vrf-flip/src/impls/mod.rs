@@ -6,3 +6,9 @@ pub use game_impls::*;
 
 pub mod round_impls;
 pub use round_impls::*;
+
+pub mod house_impls;
+pub use house_impls::*;
+
+pub mod history_impls;
+pub use history_impls::*;
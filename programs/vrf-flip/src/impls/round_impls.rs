@@ -3,9 +3,7 @@ use anchor_lang::prelude::*;
 use solana_program::clock::Clock;
 
 impl Round {
-    pub fn is_open(&self) -> bool {
-        let clock = Clock::get().unwrap();
-
+    pub fn is_open(&self, clock: &Clock) -> bool {
         if self.status == RoundStatus::Awaiting
             && self.request_timestamp > clock.unix_timestamp - 60
         {
@@ -15,10 +13,13 @@ impl Round {
         false
     }
 
-    pub fn settle(&mut self, vrf_result: &[u32]) -> anchor_lang::Result<bool> {
-        let clock = Clock::get().unwrap();
+    // the result a vrf value maps to for this round's game, shared with off-chain verifiers
+    pub fn outcome(&self, vrf_result: &[u32]) -> u32 {
+        vrf_result[0] % self.game_config.max + self.game_config.min
+    }
 
-        let result = vrf_result[0] % self.game_config.max + self.game_config.min;
+    pub fn settle(&mut self, vrf_result: &[u32], clock: &Clock) -> anchor_lang::Result<bool> {
+        let result = self.outcome(vrf_result);
 
         self.result = result;
         self.settle_slot = clock.slot;
@@ -28,6 +29,36 @@ impl Round {
         Ok(self.result == self.guess)
     }
 
+    pub fn reveal_timed_out(&self, clock: &Clock) -> bool {
+        clock.slot > self.request_slot.saturating_add(REVEAL_TIMEOUT_SLOTS)
+    }
+
+    // the house forfeits a commit-reveal round it didn't reveal in time, so withholding
+    // a losing reveal can't leave the round unsettled
+    pub fn forfeit(&mut self, clock: &Clock) -> anchor_lang::Result<bool> {
+        self.result = self.guess;
+        self.settle_slot = clock.slot;
+        self.settle_timestamp = clock.unix_timestamp;
+        self.status = RoundStatus::Settled;
+
+        Ok(true)
+    }
+
+    // payout owed if the guess wins
+    pub fn max_payout(&self) -> anchor_lang::Result<u64> {
+        self.bet_amount
+            .checked_mul(self.game_config.payout_multiplier as u64)
+            .ok_or(error!(VrfFlipError::InvalidBet))
+    }
+
+    // payout still owed if the round is waiting on its result
+    pub fn outstanding_payout(&self) -> anchor_lang::Result<u64> {
+        if self.status != RoundStatus::Awaiting {
+            return Ok(0);
+        }
+        self.max_payout()
+    }
+
     pub fn payout_amount(&self) -> anchor_lang::Result<u64> {
         if self.result == 0 {
             return Err(error!(VrfFlipError::CurrentRoundStillActive));
@@ -43,4 +74,12 @@ impl Round {
 
         Ok(payout_amount)
     }
+
+    // payout of a settled round, rounds refunded before they settled paid nothing
+    pub fn settled_payout(&self) -> anchor_lang::Result<u64> {
+        if self.status != RoundStatus::Settled {
+            return Ok(0);
+        }
+        self.payout_amount()
+    }
 }
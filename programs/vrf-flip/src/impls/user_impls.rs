@@ -33,6 +33,8 @@ pub struct RequestRandomnessBumps {
     pub user: u8,
     pub switchboard_state: u8,
     pub vrf_permission: u8,
+    // set when the house fee vault is paying for the request
+    pub house: Option<u8>,
 }
 
 pub struct RequestRandomness<'a> {
@@ -40,6 +42,8 @@ pub struct RequestRandomness<'a> {
     pub switchboard_program: AccountInfo<'a>,
     /// CHECK:
     pub house: AccountInfo<'a>,
+    // see HouseState::mint_seed
+    pub house_mint_seed: Vec<u8>,
     /// CHECK:
     pub authority: AccountInfo<'a>,
     pub bumps: RequestRandomnessBumps,
@@ -47,10 +51,104 @@ pub struct RequestRandomness<'a> {
 }
 
 impl UserState {
+    // where the baseline layout's _ebuf started, every field up to history was later
+    // carved out of it
+    pub const COMMIT_REVEAL_OFFSET: usize = 8 + 1 + 5 * 32 + 2 + std::mem::size_of::<Round>() + 8;
+
+    // the version byte stays at this offset in every layout so old accounts
+    // can be identified before they are migrated
+    pub const VERSION_OFFSET: usize = Self::COMMIT_REVEAL_OFFSET
+        + std::mem::size_of::<CommitReveal>()
+        + 8
+        + std::mem::size_of::<UserStats>()
+        + 8;
+
+    // fields each version added after the version byte, see migrate
+    const DELEGATE_OFFSET: usize = Self::VERSION_OFFSET + 1;
+    const AUTOPLAY_OFFSET: usize = Self::DELEGATE_OFFSET + std::mem::size_of::<UserDelegate>();
+    const LIMITS_OFFSET: usize = Self::AUTOPLAY_OFFSET + std::mem::size_of::<AutoPlay>();
+    const HISTORY_PAGES_OFFSET: usize = Self::LIMITS_OFFSET + std::mem::size_of::<UserLimits>();
+    const HOUSE_SETTLE_PENDING_OFFSET: usize = Self::HISTORY_PAGES_OFFSET + 4;
+
+    pub const HISTORY_OFFSET: usize =
+        8 + std::mem::size_of::<UserState>() - std::mem::size_of::<History>();
+
     pub fn size() -> usize {
         std::mem::size_of::<UserState>() + 8
     }
 
+    pub fn version_of(data: &[u8]) -> anchor_lang::Result<u8> {
+        if data.len() <= Self::VERSION_OFFSET || data[..8] != UserState::discriminator() {
+            return Err(error!(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+            ));
+        }
+        Ok(data[Self::VERSION_OFFSET])
+    }
+
+    // rewrites raw account data from an older layout into the current one, one
+    // version at a time, zeroing the fields each version added. The data must
+    // already be sized for the current layout.
+    pub fn migrate(data: &mut [u8]) -> anchor_lang::Result<()> {
+        // settle doesn't check the version, so a vrf callback may have flagged its round
+        // on an outdated account. The flag is carried over whichever version it was set on
+        // so the round is still recorded in the house.
+        let house_settle_pending = data[Self::HOUSE_SETTLE_PENDING_OFFSET] == 1;
+        loop {
+            let version = Self::version_of(data)?;
+            let added_fields = match version {
+                // v1 added the commit-reveal state, stats, archived_rounds and the version
+                // byte. Nothing in the baseline _ebuf is trusted, so all of it is zeroed.
+                0 => Self::COMMIT_REVEAL_OFFSET..Self::HISTORY_OFFSET,
+                // v2 added delegate
+                1 => Self::DELEGATE_OFFSET..Self::AUTOPLAY_OFFSET,
+                // v3 added autoplay
+                2 => Self::AUTOPLAY_OFFSET..Self::LIMITS_OFFSET,
+                // v4 added limits
+                3 => Self::LIMITS_OFFSET..Self::HISTORY_PAGES_OFFSET,
+                // v5 added history_pages, pages opened before it are left out of the count
+                4 => Self::HISTORY_PAGES_OFFSET..Self::HOUSE_SETTLE_PENDING_OFFSET,
+                // v6 added house_settle_pending
+                5 => Self::HOUSE_SETTLE_PENDING_OFFSET..Self::HOUSE_SETTLE_PENDING_OFFSET + 1,
+                USER_STATE_VERSION => break,
+                _ => return Err(error!(VrfFlipError::InvalidUserStateVersion)),
+            };
+            data[added_fields].fill(0);
+            data[Self::VERSION_OFFSET] = version + 1;
+        }
+        data[Self::HOUSE_SETTLE_PENDING_OFFSET] = house_settle_pending as u8;
+        Ok(())
+    }
+
+    pub fn is_commit_reveal_round(&self) -> bool {
+        let bound_round_id = self.commit_reveal.round_id;
+        bound_round_id != 0 && bound_round_id == self.current_round.round_id
+    }
+
+    // whether the current round still has to be recorded in the house, cleared since
+    // the caller records it
+    pub fn take_house_settle_pending(&mut self) -> bool {
+        let pending = self.house_settle_pending != 0;
+        self.house_settle_pending = 0;
+        pending
+    }
+
+    // what the house stops owing once a new bet replaces the current round, an expired
+    // round or one that settled before the house could record it
+    pub fn released_payout(&self) -> anchor_lang::Result<u64> {
+        if self.house_settle_pending != 0 {
+            return self.current_round.max_payout();
+        }
+        self.current_round.outstanding_payout()
+    }
+
+    // the vrf counter only advances for rounds that requested randomness
+    pub fn expected_vrf_counter(&self) -> u128 {
+        self.current_round
+            .round_id
+            .saturating_sub(self.commit_reveal_rounds as u128)
+    }
+
     pub fn request_randomness(ctx: &RequestRandomness) -> anchor_lang::Result<()> {
         let vrf_request_randomness = VrfRequestRandomness {
             authority: ctx.accounts.user.clone(),
@@ -67,17 +165,35 @@ impl UserState {
             token_program: ctx.accounts.token_program.clone(),
         };
 
-        vrf_request_randomness.invoke_signed(
-            ctx.switchboard_program.clone(),
-            ctx.bumps.switchboard_state,
-            ctx.bumps.vrf_permission,
-            &[&[
-                &USER_SEED,
-                ctx.house.key().as_ref(),
-                ctx.authority.key().as_ref(),
-                &[ctx.bumps.user],
-            ]],
-        )?;
+        let house_key = ctx.house.key();
+        let authority_key = ctx.authority.key();
+        let user_seeds: &[&[u8]] = &[
+            &USER_SEED,
+            house_key.as_ref(),
+            authority_key.as_ref(),
+            &[ctx.bumps.user],
+        ];
+
+        match ctx.bumps.house {
+            Some(house_bump) => {
+                let house_seeds: &[&[u8]] =
+                    &[&HOUSE_SEED, ctx.house_mint_seed.as_ref(), &[house_bump]];
+                vrf_request_randomness.invoke_signed(
+                    ctx.switchboard_program.clone(),
+                    ctx.bumps.switchboard_state,
+                    ctx.bumps.vrf_permission,
+                    &[user_seeds, house_seeds],
+                )?;
+            }
+            None => {
+                vrf_request_randomness.invoke_signed(
+                    ctx.switchboard_program.clone(),
+                    ctx.bumps.switchboard_state,
+                    ctx.bumps.vrf_permission,
+                    &[user_seeds],
+                )?;
+            }
+        }
 
         Ok(())
     }
@@ -87,18 +203,12 @@ impl UserState {
         game_type: GameType,
         guess: u32,
         bet_amount: u64,
+        history_page: Option<&mut UserHistoryPage>,
     ) -> anchor_lang::Result<()> {
         let clock = Clock::get()?;
 
-        // push current round to history
-        if self.current_round != Round::default()
-            && self.current_round.round_id
-                != self.history.rounds[self.history.idx as usize].round_id
-        {
-            let history_idx = self.history.idx;
-            self.history.rounds[history_idx as usize] = self.current_round;
-            self.history.idx = (history_idx + 1) % MAX_HISTORY;
-        }
+        self.prepare_history(history_page)?;
+        self.limits.record_bet(bet_amount, clock.unix_timestamp);
 
         // set new round
         self.current_round = Round {
@@ -118,3 +228,283 @@ impl UserState {
         Ok(())
     }
 }
+
+impl UserStats {
+    pub fn record(&mut self, bet_amount: u64, payout_amount: u64, user_won: bool) {
+        self.total_wagered = self.total_wagered.saturating_add(bet_amount);
+        self.rounds_played = self.rounds_played.saturating_add(1);
+
+        if user_won {
+            self.total_won = self.total_won.saturating_add(payout_amount);
+            self.net_pnl = self.net_pnl.saturating_add_unsigned(payout_amount);
+            self.wins = self.wins.saturating_add(1);
+            self.current_win_streak = self.current_win_streak.saturating_add(1);
+            self.longest_win_streak = self.longest_win_streak.max(self.current_win_streak);
+        } else {
+            self.net_pnl = self.net_pnl.saturating_sub_unsigned(bet_amount);
+            self.current_win_streak = 0;
+        }
+    }
+}
+
+impl UserDelegate {
+    pub fn is_set(&self) -> bool {
+        self.key != Pubkey::default()
+    }
+
+    /// Checks the signer can place a bet of `bet_amount` on the authority's behalf.
+    pub fn authorize(
+        &self,
+        signer: &Pubkey,
+        bet_amount: u64,
+        slot: u64,
+    ) -> anchor_lang::Result<()> {
+        if !self.is_set() || self.key != *signer {
+            return Err(error!(VrfFlipError::InvalidDelegate));
+        }
+        if slot > self.expiry_slot {
+            return Err(error!(VrfFlipError::DelegateExpired));
+        }
+        if bet_amount > self.remaining_allowance {
+            return Err(error!(VrfFlipError::DelegateAllowanceExceeded));
+        }
+        Ok(())
+    }
+
+    pub fn spend(&mut self, bet_amount: u64) -> anchor_lang::Result<()> {
+        self.remaining_allowance = self
+            .remaining_allowance
+            .checked_sub(bet_amount)
+            .ok_or(error!(VrfFlipError::DelegateAllowanceExceeded))?;
+        Ok(())
+    }
+}
+
+impl AutoPlay {
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+
+    /// Escrow set aside for the bets the keeper hasn't placed yet, along with their vrf fees.
+    pub fn reserved_amount(&self) -> u64 {
+        if !self.is_active() {
+            return 0;
+        }
+        self.bet_amount
+            .saturating_add(self.vrf_fee)
+            .saturating_mul(self.remaining_bets as u64)
+    }
+
+    /// Escrow the next bet draws down, the bet itself plus the vrf fee.
+    pub fn next_bet_amount(&self) -> u64 {
+        self.bet_amount.saturating_add(self.vrf_fee)
+    }
+
+    pub fn bet_params(&self) -> UserBetParams {
+        UserBetParams {
+            game_type: self.game_type,
+            user_guess: self.guess,
+            bet_amount: self.bet_amount,
+        }
+    }
+
+    /// Records a settled round and stops the session once it runs out of bets or hits a limit.
+    pub fn record(&mut self, bet_amount: u64, payout_amount: u64, user_won: bool) {
+        self.net_pnl = if user_won {
+            self.net_pnl.saturating_add_unsigned(payout_amount)
+        } else {
+            self.net_pnl.saturating_sub_unsigned(bet_amount)
+        };
+
+        let net_pnl = self.net_pnl;
+        let stop_loss = self.stop_loss;
+        let take_profit = self.take_profit;
+        if self.remaining_bets == 0
+            || (stop_loss != 0 && net_pnl <= 0 && net_pnl.unsigned_abs() >= stop_loss)
+            || (take_profit != 0 && net_pnl >= 0 && net_pnl.unsigned_abs() >= take_profit)
+        {
+            msg!("autoplay finished with a net pnl of {}", net_pnl);
+            self.stop();
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.active = 0;
+        self.remaining_bets = 0;
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// a limit of 0 is disabled, which makes it the loosest
+fn tightens(current: u64, new: u64) -> bool {
+    current == 0 || (new != 0 && new <= current)
+}
+
+impl UserLimits {
+    fn day_of(unix_timestamp: i64) -> i64 {
+        unix_timestamp.div_euclid(SECONDS_PER_DAY)
+    }
+
+    fn week_of(unix_timestamp: i64) -> i64 {
+        Self::day_of(unix_timestamp).div_euclid(7)
+    }
+
+    /// Replaces the limits with the loosened ones once their delay has passed.
+    fn apply_pending(&mut self, unix_timestamp: i64) {
+        let pending = self.pending;
+        let effective_at = pending.effective_at;
+        if effective_at == 0 || unix_timestamp < effective_at {
+            return;
+        }
+        self.daily_wager_limit = pending.daily_wager_limit;
+        self.weekly_wager_limit = pending.weekly_wager_limit;
+        self.daily_loss_limit = pending.daily_loss_limit;
+        self.cooldown = pending.cooldown;
+        self.pending = PendingLimits::default();
+    }
+
+    /// Updates the limits, tightening applies right away while loosening waits
+    /// LIMIT_LOOSEN_DELAY. Loosening again restarts the wait.
+    pub fn set(&mut self, params: &UserSetLimitsParams, unix_timestamp: i64) {
+        self.apply_pending(unix_timestamp);
+
+        // start from what is pending so an earlier loosening isn't dropped
+        let previous = self.pending;
+        let mut target = if previous.effective_at != 0 {
+            previous
+        } else {
+            PendingLimits {
+                daily_wager_limit: self.daily_wager_limit,
+                weekly_wager_limit: self.weekly_wager_limit,
+                daily_loss_limit: self.daily_loss_limit,
+                cooldown: self.cooldown,
+                effective_at: 0,
+            }
+        };
+        if let Some(daily_wager_limit) = params.daily_wager_limit {
+            target.daily_wager_limit = daily_wager_limit;
+        }
+        if let Some(weekly_wager_limit) = params.weekly_wager_limit {
+            target.weekly_wager_limit = weekly_wager_limit;
+        }
+        if let Some(daily_loss_limit) = params.daily_loss_limit {
+            target.daily_loss_limit = daily_loss_limit;
+        }
+        if let Some(cooldown) = params.cooldown {
+            target.cooldown = cooldown;
+        }
+
+        if tightens(self.daily_wager_limit, target.daily_wager_limit) {
+            self.daily_wager_limit = target.daily_wager_limit;
+        }
+        if tightens(self.weekly_wager_limit, target.weekly_wager_limit) {
+            self.weekly_wager_limit = target.weekly_wager_limit;
+        }
+        if tightens(self.daily_loss_limit, target.daily_loss_limit) {
+            self.daily_loss_limit = target.daily_loss_limit;
+        }
+        if target.cooldown >= self.cooldown {
+            self.cooldown = target.cooldown;
+        }
+
+        let loosened = target.daily_wager_limit != self.daily_wager_limit
+            || target.weekly_wager_limit != self.weekly_wager_limit
+            || target.daily_loss_limit != self.daily_loss_limit
+            || target.cooldown != self.cooldown;
+        if !loosened {
+            self.pending = PendingLimits::default();
+            return;
+        }
+        target.effective_at = previous.effective_at;
+        if target != previous {
+            target.effective_at = unix_timestamp.saturating_add(LIMIT_LOOSEN_DELAY);
+        }
+        let effective_at = target.effective_at;
+        msg!("loosened limits apply at {}", effective_at);
+        self.pending = target;
+    }
+
+    /// Resets the daily and weekly totals once their window has passed.
+    fn roll(&mut self, unix_timestamp: i64) {
+        let day = Self::day_of(unix_timestamp);
+        if self.day != day {
+            self.day = day;
+            self.day_wagered = 0;
+            self.day_net_pnl = 0;
+        }
+        let week = Self::week_of(unix_timestamp);
+        if self.week != week {
+            self.week = week;
+            self.week_wagered = 0;
+        }
+    }
+
+    /// Checks a new bet against the limits, the last bet was placed at `last_request_timestamp`.
+    pub fn check_bet(
+        &self,
+        bet_amount: u64,
+        last_request_timestamp: i64,
+        clock: &Clock,
+    ) -> anchor_lang::Result<()> {
+        let now = clock.unix_timestamp;
+        let self_excluded_until = self.self_excluded_until;
+        if now < self_excluded_until {
+            msg!("self-excluded until {}", self_excluded_until);
+            return Err(error!(VrfFlipError::UserSelfExcluded));
+        }
+
+        // loosened limits past their delay already count, totals from a previous window don't
+        let mut limits = *self;
+        limits.apply_pending(now);
+        limits.roll(now);
+
+        let cooldown = limits.cooldown as i64;
+        if cooldown != 0
+            && last_request_timestamp != 0
+            && now < last_request_timestamp.saturating_add(cooldown)
+        {
+            return Err(error!(VrfFlipError::FlipRequestedTooSoon));
+        }
+
+        let day_wagered = limits.day_wagered.saturating_add(bet_amount);
+        let week_wagered = limits.week_wagered.saturating_add(bet_amount);
+        if (limits.daily_wager_limit != 0 && day_wagered > limits.daily_wager_limit)
+            || (limits.weekly_wager_limit != 0 && week_wagered > limits.weekly_wager_limit)
+        {
+            return Err(error!(VrfFlipError::WagerLimitExceeded));
+        }
+
+        if limits.daily_loss_limit != 0 {
+            let worst_case = limits.day_net_pnl.saturating_sub_unsigned(bet_amount);
+            if worst_case < 0 && worst_case.unsigned_abs() > limits.daily_loss_limit {
+                return Err(error!(VrfFlipError::LossLimitExceeded));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record_bet(&mut self, bet_amount: u64, unix_timestamp: i64) {
+        self.apply_pending(unix_timestamp);
+        self.roll(unix_timestamp);
+        self.day_wagered = self.day_wagered.saturating_add(bet_amount);
+        self.week_wagered = self.week_wagered.saturating_add(bet_amount);
+    }
+
+    pub fn record_settle(
+        &mut self,
+        bet_amount: u64,
+        payout_amount: u64,
+        user_won: bool,
+        unix_timestamp: i64,
+    ) {
+        self.apply_pending(unix_timestamp);
+        self.roll(unix_timestamp);
+        self.day_net_pnl = if user_won {
+            self.day_net_pnl.saturating_add_unsigned(payout_amount)
+        } else {
+            self.day_net_pnl.saturating_sub_unsigned(bet_amount)
+        };
+    }
+}
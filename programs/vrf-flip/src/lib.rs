@@ -9,12 +9,17 @@ pub use impls::*;
 pub mod utils;
 pub use utils::*;
 
+#[cfg(feature = "events")]
+pub mod events;
+#[cfg(feature = "events")]
+pub use events::*;
+
 pub use solana_program::program_option::COption;
 
 pub use anchor_lang::prelude::Pubkey;
 pub use anchor_lang::prelude::*;
 pub use anchor_lang::{AnchorDeserialize, AnchorSerialize};
-pub use anchor_spl::token::{self, Mint, SetAuthority, Token, TokenAccount, Transfer};
+pub use anchor_spl::token::{self, Mint, SetAuthority, SyncNative, Token, TokenAccount, Transfer};
 // pub use spl_token::instruction::AuthorityType;
 
 pub use switchboard_v2::{
@@ -31,10 +36,34 @@ use solana_security_txt::security_txt;
 
 declare_id!("FLiPhaxG6sdasFpRoc17u1QKq96g2p2BTNNT1rqXvcnC");
 
-const HOUSE_SEED: &[u8] = b"HOUSESEED";
-const USER_SEED: &[u8] = b"USERSEED";
+pub const HOUSE_SEED: &[u8] = b"HOUSESEED";
+pub const USER_SEED: &[u8] = b"USERSEED";
+pub const FEE_VAULT_SEED: &[u8] = b"FEEVAULTSEED";
+pub const HISTORY_PAGE_SEED: &[u8] = b"HISTORYPAGESEED";
+// a native house pays a user's winnings into this account and closes it to unwrap them
+pub const UNWRAP_SEED: &[u8] = b"UNWRAPSEED";
+
+// used for a game whose BetLimits leave max_bet unset
+pub const MAX_BET_AMOUNT: u64 = 1_000_000_000 * 100;
+// used for a game whose BetLimits leave max_payout_bps unset, a bet can be at most a
+// tenth of the house vault whatever it can win
+pub const DEFAULT_MAX_BET_VAULT_RATIO: u64 = 10;
+pub const MAX_BPS: u32 = 10_000;
+// used when the house leaves max_liability_bps unset, open rounds can't owe more than the vault
+pub const DEFAULT_MAX_LIABILITY_BPS: u32 = MAX_BPS;
+
+// SlotHashes only holds the last 512 slots, a commit-reveal round the house hasn't revealed
+// by then can't be revealed anymore and is settled as a win for the user
+pub const REVEAL_TIMEOUT_SLOTS: u64 = solana_program::slot_hashes::MAX_ENTRIES as u64;
+
+// loosened user limits wait this long before they apply, tightened limits apply right away
+pub const LIMIT_LOOSEN_DELAY: i64 = 24 * 60 * 60;
 
-const MAX_BET_AMOUNT: u64 = 1_000_000_000 * 100;
+// bumped whenever the UserState layout changes, see UserState::migrate
+pub const USER_STATE_VERSION: u8 = 6;
+
+// CoinFlip, SixSidedDiceRoll and TwentySidedDiceRoll
+pub const NUM_GAME_TYPES: usize = 3;
 
 #[program]
 pub mod switchboard_vrf_flip {
@@ -45,6 +74,41 @@ pub mod switchboard_vrf_flip {
     pub fn house_init(ctx: Context<HouseInit>, params: HouseInitParams) -> anchor_lang::Result<()> {
         HouseInit::actuate(&ctx, &params)
     }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn house_init_native(
+        ctx: Context<HouseInitNative>,
+        params: HouseInitNativeParams,
+    ) -> anchor_lang::Result<()> {
+        HouseInitNative::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn house_update(
+        ctx: Context<HouseUpdate>,
+        params: HouseUpdateParams,
+    ) -> anchor_lang::Result<()> {
+        HouseUpdate::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn house_init_fee_vault(
+        ctx: Context<HouseInitFeeVault>,
+        params: HouseInitFeeVaultParams,
+    ) -> anchor_lang::Result<()> {
+        HouseInitFeeVault::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn house_commit(
+        ctx: Context<HouseCommit>,
+        params: HouseCommitParams,
+    ) -> anchor_lang::Result<()> {
+        HouseCommit::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn house_reveal(
+        ctx: Context<HouseReveal>,
+        params: HouseRevealParams,
+    ) -> anchor_lang::Result<()> {
+        HouseReveal::actuate(&ctx, &params)
+    }
 
     // user actions
     #[access_control(ctx.accounts.validate(&ctx, &params))]
@@ -56,6 +120,20 @@ pub mod switchboard_vrf_flip {
         UserBet::actuate(ctx, &params)
     }
     #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_bet_commit_reveal(
+        ctx: Context<UserBetCommitReveal>,
+        params: UserBetParams,
+    ) -> anchor_lang::Result<()> {
+        UserBetCommitReveal::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_bet_delegate(
+        ctx: Context<UserBetDelegate>,
+        params: UserBetParams,
+    ) -> anchor_lang::Result<()> {
+        UserBetDelegate::actuate(ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
     pub fn user_settle(
         ctx: Context<UserSettle>,
         params: UserSettleParams,
@@ -63,16 +141,90 @@ pub mod switchboard_vrf_flip {
         UserSettle::actuate(&ctx, &params)
     }
     #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_record_settle(
+        ctx: Context<UserRecordSettle>,
+        params: UserRecordSettleParams,
+    ) -> anchor_lang::Result<()> {
+        UserRecordSettle::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
     pub fn user_airdrop(
         ctx: Context<UserAirdrop>,
         params: UserAirdropParams,
     ) -> anchor_lang::Result<()> {
         UserAirdrop::actuate(&ctx, &params)
     }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_history_page_init(
+        ctx: Context<UserHistoryPageInit>,
+        params: UserHistoryPageInitParams,
+    ) -> anchor_lang::Result<()> {
+        UserHistoryPageInit::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_history_page_close(
+        ctx: Context<UserHistoryPageClose>,
+        params: UserHistoryPageCloseParams,
+    ) -> anchor_lang::Result<()> {
+        UserHistoryPageClose::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_migrate(
+        ctx: Context<UserMigrate>,
+        params: UserMigrateParams,
+    ) -> anchor_lang::Result<()> {
+        UserMigrate::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_close(ctx: Context<UserClose>, params: UserCloseParams) -> anchor_lang::Result<()> {
+        UserClose::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_withdraw_escrow(
+        ctx: Context<UserWithdrawEscrow>,
+        params: UserWithdrawEscrowParams,
+    ) -> anchor_lang::Result<()> {
+        UserWithdrawEscrow::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_set_delegate(
+        ctx: Context<UserSetDelegate>,
+        params: UserSetDelegateParams,
+    ) -> anchor_lang::Result<()> {
+        UserSetDelegate::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_autoplay_start(
+        ctx: Context<UserAutoplayStart>,
+        params: UserAutoplayStartParams,
+    ) -> anchor_lang::Result<()> {
+        UserAutoplayStart::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_autoplay_bet(
+        ctx: Context<UserAutoplayBet>,
+        params: UserAutoplayBetParams,
+    ) -> anchor_lang::Result<()> {
+        UserAutoplayBet::actuate(ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_autoplay_stop(
+        ctx: Context<UserAutoplayStop>,
+        params: UserAutoplayStopParams,
+    ) -> anchor_lang::Result<()> {
+        UserAutoplayStop::actuate(&ctx, &params)
+    }
+    #[access_control(ctx.accounts.validate(&ctx, &params))]
+    pub fn user_set_limits(
+        ctx: Context<UserSetLimits>,
+        params: UserSetLimitsParams,
+    ) -> anchor_lang::Result<()> {
+        UserSetLimits::actuate(&ctx, &params)
+    }
 }
 
-#[account(zero_copy)]
-#[derive(AnchorSerialize)]
+#[repr(packed)]
+#[account(zero_copy(unsafe))]
 pub struct HouseState {
     pub bump: u8,
     // controls vault and can settle winners
@@ -85,12 +237,69 @@ pub struct HouseState {
     pub switchboard_queue: Pubkey,
     // switchboard mint for vrf requests
     pub switchboard_mint: Pubkey,
+    // house owned switchboard token account used to sponsor vrf requests
+    pub fee_vault: Pubkey,
+    // whether users can have their vrf requests paid by the fee vault
+    pub sponsor_vrf_fees: u8,
+    // fee charged in the house mint when the fee vault pays for a vrf request
+    pub vrf_fee: u64,
+    // running totals for each game, indexed by GameType::index
+    pub game_stats: [GameStats; NUM_GAME_TYPES],
+    // bet limits for each game, indexed by GameType::index
+    pub bet_limits: [BetLimits; NUM_GAME_TYPES],
+    // payouts owed if every open round wins, across every game
+    pub open_liability: u64,
+    // cap on open_liability in basis points of the house vault balance
+    pub max_liability_bps: u32,
+    // 1 once the house PDA is seeded by its mint, houses created with HOUSE_SEED alone leave it 0
+    pub mint_seeded: u8,
     // Buffer for future use
-    pub _ebuf: [u8; 1024],
+    pub _ebuf: [u8; 766],
 }
 
+// House totals for one game, updated as rounds are placed and settled
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct GameStats {
+    // total amount wagered
+    pub volume: u64,
+    // winnings paid to users, excluding their returned bets
+    pub payouts: u64,
+    // house profit and loss
+    pub net_pnl: i64,
+    // payouts owed if every open round wins
+    pub open_exposure: u64,
+    pub rounds_placed: u64,
+    pub rounds_settled: u64,
+}
+unsafe impl Pod for GameStats {}
+unsafe impl Zeroable for GameStats {}
+
+// House limits on the bets for one game, 0 falls back to the defaults above
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct BetLimits {
+    pub min_bet: u64,
+    pub max_bet: u64,
+    // largest payout a single bet can win, in basis points of the house vault balance,
+    // 0 limits the bet itself to a tenth of the vault instead
+    pub max_payout_bps: u32,
+}
+unsafe impl Pod for BetLimits {}
+unsafe impl Zeroable for BetLimits {}
+
 #[derive(
-    AnchorSerialize, AnchorDeserialize, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq,
+    AnchorSerialize,
+    AnchorDeserialize,
+    FromPrimitive,
+    ToPrimitive,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
 )]
 pub enum GameType {
     None,
@@ -168,6 +377,132 @@ impl Default for History {
 unsafe impl Pod for History {}
 unsafe impl Zeroable for History {}
 
+pub const HISTORY_PAGE_SIZE: u32 = 64;
+
+// Rounds pushed out of a user's history ring, chained by page index
+#[repr(packed)]
+#[account(zero_copy(unsafe))]
+pub struct UserHistoryPage {
+    pub bump: u8,
+    pub user: Pubkey,
+    pub page_index: u32,
+    // number of rounds archived to this page
+    pub len: u32,
+    pub rounds: [Round; HISTORY_PAGE_SIZE as usize],
+    // tokens won on each archived round, excluding the returned bet
+    pub payouts: [u64; HISTORY_PAGE_SIZE as usize],
+}
+
+// Fallback randomness when the switchboard queue is unavailable
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct CommitReveal {
+    // round the commitment is bound to, 0 until the user places a bet
+    pub round_id: u128,
+    // sha256 hash of the house authority's secret
+    pub commitment: [u8; 32],
+    // hash of the revealed secret and the bet's slot hash, empty until revealed
+    pub result: [u8; 32],
+}
+unsafe impl Pod for CommitReveal {}
+unsafe impl Zeroable for CommitReveal {}
+
+// Lifetime totals for a user, updated when each round settles
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct UserStats {
+    pub total_wagered: u64,
+    // winnings paid by the house, excluding the returned bet
+    pub total_won: u64,
+    pub net_pnl: i64,
+    pub rounds_played: u64,
+    pub wins: u64,
+    pub longest_win_streak: u32,
+    pub current_win_streak: u32,
+}
+unsafe impl Pod for UserStats {}
+unsafe impl Zeroable for UserStats {}
+
+// A session key allowed to place bets on the authority's behalf, unset while key is default
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct UserDelegate {
+    pub key: Pubkey,
+    // last slot the delegate can sign bets in
+    pub expiry_slot: u64,
+    // flip tokens the delegate can still spend from the reward address, mirrors the
+    // token approval user_set_delegate gives the user PDA
+    pub remaining_allowance: u64,
+}
+unsafe impl Pod for UserDelegate {}
+unsafe impl Zeroable for UserDelegate {}
+
+// Bets a keeper places for the user from a pre-funded escrow, see user_autoplay_bet
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct AutoPlay {
+    pub active: u8,
+    pub game_type: u32,
+    pub guess: u32,
+    pub bet_amount: u64,
+    // bets the keeper can still place, their escrow stays reserved until the session stops
+    pub remaining_bets: u32,
+    // the session stops once its losses reach this amount, 0 disables it
+    pub stop_loss: u64,
+    // the session stops once its winnings reach this amount, 0 disables it
+    pub take_profit: u64,
+    pub net_pnl: i64,
+    // house vrf fee taken from the escrow with each bet, fixed when the session starts
+    pub vrf_fee: u64,
+}
+unsafe impl Pod for AutoPlay {}
+unsafe impl Zeroable for AutoPlay {}
+
+// Limits the user loosened, they replace the current limits once effective_at passes
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct PendingLimits {
+    pub daily_wager_limit: u64,
+    pub weekly_wager_limit: u64,
+    pub daily_loss_limit: u64,
+    pub cooldown: u32,
+    // 0 when nothing is pending
+    pub effective_at: i64,
+}
+unsafe impl Pod for PendingLimits {}
+unsafe impl Zeroable for PendingLimits {}
+
+// Responsible gaming limits the user sets on their own account, 0 disables a limit
+#[repr(packed)]
+#[zero_copy(unsafe)]
+#[derive(PartialEq, Eq, Default)]
+pub struct UserLimits {
+    pub daily_wager_limit: u64,
+    pub weekly_wager_limit: u64,
+    // net losses allowed per day, counting the bet being placed as lost
+    pub daily_loss_limit: u64,
+    // seconds between bets
+    pub cooldown: u32,
+    // no bets until this timestamp, it can be extended but never shortened
+    pub self_excluded_until: i64,
+    // days since the unix epoch, the daily totals below reset when it changes
+    pub day: i64,
+    pub day_wagered: u64,
+    pub day_net_pnl: i64,
+    // weeks since the unix epoch, the weekly total below resets when it changes
+    pub week: i64,
+    pub week_wagered: u64,
+    // loosened limits waiting out LIMIT_LOOSEN_DELAY
+    pub pending: PendingLimits,
+}
+unsafe impl Pod for UserLimits {}
+unsafe impl Zeroable for UserLimits {}
+
 // Each user needs an account with its own VRF to play
 #[repr(packed)]
 #[account(zero_copy(unsafe))]
@@ -182,7 +517,23 @@ pub struct UserState {
     pub vrf_permission_bump: u8,
     pub current_round: Round,
     pub last_airdrop_request_slot: u64,
-    pub _ebuf: [u8; 1024],
+    pub commit_reveal: CommitReveal,
+    // rounds played without a vrf request, offsets the expected vrf counter
+    pub commit_reveal_rounds: u64,
+    pub stats: UserStats,
+    // rounds moved from the history ring into history pages
+    pub archived_rounds: u64,
+    // layout version, 0 for accounts created before versioning
+    pub version: u8,
+    pub delegate: UserDelegate,
+    pub autoplay: AutoPlay,
+    pub limits: UserLimits,
+    // history pages created and not closed yet, user_close needs them all closed
+    pub history_pages: u32,
+    // 1 once the current round settled without a writable house, its house stats and
+    // liability are recorded by the next bet, user_close or user_record_settle
+    pub house_settle_pending: u8,
+    pub _ebuf: [u8; 661],
     pub history: History,
 }
 impl Default for UserState {
@@ -192,6 +543,7 @@ impl Default for UserState {
 }
 
 #[event]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UserBetPlaced {
     pub round_id: u128,
     pub user: Pubkey,
@@ -203,6 +555,7 @@ pub struct UserBetPlaced {
 }
 
 #[event]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UserBetSettled {
     pub round_id: u128,
     pub user: Pubkey,
@@ -216,6 +569,33 @@ pub struct UserBetSettled {
     pub timestamp: i64,
 }
 
+// The randomness a round was settled with. The VRF and the commitment only keep the
+// latest round, so older rounds can only be verified from this event.
+#[event]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserRoundRandomness {
+    pub round_id: u128,
+    pub user: Pubkey,
+    // the VRF counter the result was read at, 0 for commit-reveal rounds
+    pub vrf_counter: u128,
+    // oracles that had verified the VRF proof, 0 for commit-reveal rounds
+    pub num_verified: u32,
+    pub randomness: [u8; 32],
+}
+
+// Everything needed to check a commit-reveal round: the secret hashes to the
+// commitment and, with the slot hash, round id and user, to the result
+#[event]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HouseRevealed {
+    pub round_id: u128,
+    pub user: Pubkey,
+    pub commitment: [u8; 32],
+    pub secret: [u8; 32],
+    pub slot_hash: [u8; 32],
+    pub result: [u8; 32],
+}
+
 #[error_code]
 #[derive(Eq, PartialEq)]
 pub enum VrfFlipError {
@@ -247,10 +627,84 @@ pub enum VrfFlipError {
     MaxBetAmountExceeded,
     #[msg("Insufficient funds to request randomness")]
     InsufficientFunds,
-    #[msg("User can flip once every 10 seconds")]
+    #[msg("User has to wait for their cooldown between bets")]
     FlipRequestedTooSoon,
     #[msg("House has no authority to mint more tokens")]
     UnauthorizedMint,
+    #[msg("House is not sponsoring VRF requests")]
+    VrfSponsorshipDisabled,
+    #[msg("VRF payer must be owned by the payer or be the house fee vault")]
+    InvalidVrfPayer,
+    #[msg("House has not committed to a secret for the next round")]
+    MissingCommitment,
+    #[msg("Commitment is already bound to an unsettled round")]
+    CommitmentAlreadyBound,
+    #[msg("Commitment is not bound to the current round")]
+    CommitmentNotBound,
+    #[msg("Revealed secret does not match the commitment")]
+    InvalidReveal,
+    #[msg("Slot hash for the round's request slot is not available")]
+    SlotHashNotFound,
+    #[msg("House has not revealed the secret for the current round")]
+    RandomnessNotRevealed,
+    #[msg("History is full, the user's current history page is required")]
+    HistoryPageRequired,
+    #[msg("History page does not match the user's current history page")]
+    InvalidHistoryPage,
+    #[msg("History page is still being written to")]
+    HistoryPageInUse,
+    #[msg("User account needs to be migrated to the latest version")]
+    UserStateOutdated,
+    #[msg("User account is already on the latest version")]
+    UserStateUpToDate,
+    #[msg("User account version is not supported")]
+    InvalidUserStateVersion,
+    #[msg("User escrow still holds tokens")]
+    EscrowNotEmpty,
+    #[msg("User escrow has no tokens available to withdraw")]
+    NoUnlockedEscrow,
+    #[msg("Bet must be signed by the user's authority or delegate")]
+    InvalidDelegate,
+    #[msg("User delegate has expired")]
+    DelegateExpired,
+    #[msg("Bet exceeds the delegate's remaining allowance")]
+    DelegateAllowanceExceeded,
+    #[msg("User has an active autoplay session")]
+    AutoPlayActive,
+    #[msg("User has no active autoplay session")]
+    AutoPlayInactive,
+    #[msg("Autoplay session needs at least one bet and a fundable escrow")]
+    InvalidAutoPlay,
+    #[msg("User is self-excluded from betting")]
+    UserSelfExcluded,
+    #[msg("Self-exclusion can only be extended")]
+    SelfExclusionShortened,
+    #[msg("Bet exceeds the user's wager limit")]
+    WagerLimitExceeded,
+    #[msg("Bet could exceed the user's loss limit")]
+    LossLimitExceeded,
+    #[msg("Bet is below the game's minimum bet")]
+    MinBetAmountNotMet,
+    #[msg("Bet's payout exceeds the share of the house vault it can win")]
+    MaxPayoutExceeded,
+    #[msg("Bet limits need a min bet below the max bet and a payout ratio of at most 100%")]
+    InvalidBetLimits,
+    #[msg("Bet would push the house's open liability over its limit")]
+    MaxLiabilityExceeded,
+    #[msg("Max liability can be at most 100% of the house vault")]
+    InvalidMaxLiability,
+    #[msg("House needs a fee vault before it can sponsor VRF requests")]
+    FeeVaultRequired,
+    #[msg("The house can no longer reveal this round, settle it instead")]
+    RevealTimedOut,
+    #[msg("User still has history pages open, close them first")]
+    HistoryPagesOpen,
+    #[msg("Unwrapped payouts can only go to the user's authority")]
+    InvalidUnwrapDestination,
+    #[msg("User has no settled round waiting to be recorded in the house")]
+    NoPendingSettle,
+    #[msg("VRF result has not been verified by an oracle yet")]
+    VrfNotVerified,
 }
 
 #[cfg(not(feature = "no-entrypoint"))]
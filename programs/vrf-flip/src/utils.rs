@@ -18,3 +18,151 @@ pub fn transfer<'a>(
     token::transfer(cpi_ctx, amount)?;
     Ok(())
 }
+
+// moves lamports into a native mint token account and syncs its token balance
+pub fn wrap_native<'a>(
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    from: &AccountInfo<'a>,
+    to: &Account<'a, TokenAccount>,
+    amount: u64,
+) -> anchor_lang::Result<()> {
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: from.clone(),
+                to: to.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    token::sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: to.to_account_info(),
+        },
+    ))?;
+    Ok(())
+}
+
+// creates the house owned native account a user's payouts are unwrapped through, any
+// lamports already sent to the address are kept and topped up to the rent exempt minimum
+pub fn create_unwrap_account<'a>(
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    unwrap_account: &AccountInfo<'a>,
+    native_mint: &AccountInfo<'a>,
+    house: &AccountInfo<'a>,
+    user: &Pubkey,
+    bump: u8,
+) -> anchor_lang::Result<()> {
+    let unwrap_seeds: &[&[&[u8]]] = &[&[UNWRAP_SEED, user.as_ref(), &[bump]]];
+    let space = TokenAccount::LEN;
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = unwrap_account.lamports();
+    if current_lamports == 0 {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: unwrap_account.clone(),
+                },
+                unwrap_seeds,
+            ),
+            rent,
+            space as u64,
+            &token::ID,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(current_lamports);
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: unwrap_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        anchor_lang::system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Allocate {
+                    account_to_allocate: unwrap_account.clone(),
+                },
+                unwrap_seeds,
+            ),
+            space as u64,
+        )?;
+        anchor_lang::system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Assign {
+                    account_to_assign: unwrap_account.clone(),
+                },
+                unwrap_seeds,
+            ),
+            &token::ID,
+        )?;
+    }
+    token::initialize_account3(CpiContext::new(
+        token_program.clone(),
+        token::InitializeAccount3 {
+            account: unwrap_account.clone(),
+            mint: native_mint.clone(),
+            authority: house.clone(),
+        },
+    ))?;
+    Ok(())
+}
+
+// closes a user's unwrap account, its wrapped balance and rent land in the authority's wallet as SOL
+pub fn close_unwrap_account<'a>(
+    token_program: &AccountInfo<'a>,
+    unwrap_account: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    house: &AccountInfo<'a>,
+    house_seeds: &[&[&[u8]]],
+) -> anchor_lang::Result<()> {
+    token::close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        token::CloseAccount {
+            account: unwrap_account.clone(),
+            destination: authority.clone(),
+            authority: house.clone(),
+        },
+        house_seeds,
+    ))?;
+    Ok(())
+}
+
+// SlotHashes is serialized as a vec of (slot, hash) sorted by the most recent slot
+pub fn find_slot_hash(slot_hashes: &AccountInfo, slot: u64) -> anchor_lang::Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    if data.len() < 8 {
+        return Err(error!(VrfFlipError::SlotHashNotFound));
+    }
+    let len = u64::from_le_bytes(data[0..8].try_into().unwrap()) as usize;
+
+    for i in 0..len {
+        let offset = 8 + i * 40;
+        if data.len() < offset + 40 {
+            break;
+        }
+        let entry_slot = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        if entry_slot == slot {
+            return Ok(data[offset + 8..offset + 40].try_into().unwrap());
+        }
+        if entry_slot < slot {
+            break;
+        }
+    }
+
+    Err(error!(VrfFlipError::SlotHashNotFound))
+}
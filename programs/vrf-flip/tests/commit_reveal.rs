@@ -0,0 +1,199 @@
+use solana_program::hash::hash;
+use switchboard_vrf_flip::{RoundStatus, VrfFlipError, REVEAL_TIMEOUT_SLOTS};
+use vrf_flip_test_harness::*;
+
+const SECRET: [u8; 32] = [7u8; 32];
+
+fn commitment() -> [u8; 32] {
+    hash(&SECRET).to_bytes()
+}
+
+#[tokio::test]
+async fn test_commit_reveal_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+
+    let user = harness.user_state(&player).await;
+    let bound_round_id = user.commit_reveal.round_id;
+    assert_eq!(bound_round_id, 1);
+
+    // the slot hash of the bet is only available once the slot has passed
+    harness.warp_slots(1).await;
+    harness.house_reveal(&player, SECRET).await.unwrap();
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let result = user.current_round.result;
+    assert!(user.current_round.status == RoundStatus::Settled);
+    assert!(result == 1 || result == 2);
+
+    // the vrf was never used so the next vrf round still lines up with its counter
+    let counter = harness.vrf_state(&player).await.counter;
+    assert_eq!(counter, 0);
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let round_id = user.current_round.round_id;
+    assert_eq!(round_id, 2);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_requires_commitment() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.house_commit(&player, [0u8; 32]).await;
+    assert_flip_error(result, VrfFlipError::MissingCommitment);
+
+    let result = harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await;
+    assert_flip_error(result, VrfFlipError::MissingCommitment);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_rejects_commit_over_bound_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+
+    let result = harness.house_commit(&player, [1u8; 32]).await;
+    assert_flip_error(result, VrfFlipError::CommitmentAlreadyBound);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_rejects_reveal_without_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+
+    let result = harness.house_reveal(&player, SECRET).await;
+    assert_flip_error(result, VrfFlipError::CommitmentNotBound);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_rejects_wrong_secret() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+    harness.warp_slots(1).await;
+
+    let result = harness.house_reveal(&player, [8u8; 32]).await;
+    assert_flip_error(result, VrfFlipError::InvalidReveal);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_rejects_reveal_in_bet_slot() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+
+    let result = harness.house_reveal(&player, SECRET).await;
+    assert_flip_error(result, VrfFlipError::SlotHashNotFound);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_settle_requires_reveal() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::RandomnessNotRevealed);
+}
+
+#[tokio::test]
+async fn test_commit_reveal_settles_as_win_once_slot_hash_is_evicted() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let house_vault = harness.house_vault;
+    let house_vault_balance = harness.token_balance(&house_vault).await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+
+    harness.warp_slots(REVEAL_TIMEOUT_SLOTS + 1).await;
+    harness.evict_slot_hashes().await;
+
+    let result = harness.house_reveal(&player, SECRET).await;
+    assert_flip_error(result, VrfFlipError::RevealTimedOut);
+
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let result = user.current_round.result;
+    assert!(user.current_round.status == RoundStatus::Settled);
+    assert_eq!(result, 1);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        11 * TOKEN
+    );
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        house_vault_balance - TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_commit_reveal_revealed_round_cannot_be_replaced() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_commit(&player, commitment()).await.unwrap();
+    harness
+        .bet_commit_reveal(&player, COIN_FLIP, 1, TOKEN)
+        .await
+        .unwrap();
+    harness.warp_slots(1).await;
+    harness.house_reveal(&player, SECRET).await.unwrap();
+
+    // the reveal is a loss and the round is past the 60s a bet stays open for
+    let mut user = harness.user_state(&player).await;
+    user.commit_reveal.result = vrf_result_for(2, 2);
+    user.current_round.request_timestamp -= 61;
+    harness.set_user_state(&player, &user);
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundStillActive);
+
+    harness.settle(&player).await.unwrap();
+    let user = harness.user_state(&player).await;
+    let result = user.current_round.result;
+    assert_eq!(result, 2);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        9 * TOKEN
+    );
+}
@@ -0,0 +1,182 @@
+use anchor_lang::{Event, InstructionData};
+use solana_program::pubkey::Pubkey;
+use switchboard_v2::SWITCHBOARD_PROGRAM_ID;
+use switchboard_vrf_flip::{
+    events::parse_logs, instruction, FlipEvent, GameType, HouseRevealed, UserBetPlaced,
+    UserBetSettled, UserRoundRandomness, UserSettleParams,
+};
+
+fn bet_placed() -> UserBetPlaced {
+    UserBetPlaced {
+        round_id: u128::MAX - 1,
+        user: Pubkey::new_unique(),
+        game_type: GameType::SixSidedDiceRoll,
+        bet_amount: 1_000_000_000,
+        guess: 3,
+        slot: 42,
+        timestamp: -1,
+    }
+}
+
+fn bet_settled() -> UserBetSettled {
+    UserBetSettled {
+        round_id: 7,
+        user: Pubkey::new_unique(),
+        user_won: true,
+        game_type: GameType::TwentySidedDiceRoll,
+        bet_amount: 5,
+        escrow_change: 95,
+        guess: 20,
+        result: 20,
+        slot: u64::MAX,
+        timestamp: 1_700_000_000,
+    }
+}
+
+fn round_randomness() -> UserRoundRandomness {
+    UserRoundRandomness {
+        round_id: 7,
+        user: Pubkey::new_unique(),
+        vrf_counter: 6,
+        num_verified: 1,
+        randomness: [0x35; 32],
+    }
+}
+
+fn house_revealed() -> HouseRevealed {
+    HouseRevealed {
+        round_id: 8,
+        user: Pubkey::new_unique(),
+        commitment: [1; 32],
+        secret: [2; 32],
+        slot_hash: [3; 32],
+        result: [4; 32],
+    }
+}
+
+fn invoke(program_id: &Pubkey, depth: u8) -> String {
+    format!("Program {} invoke [{}]", program_id, depth)
+}
+
+fn success(program_id: &Pubkey) -> String {
+    format!("Program {} success", program_id)
+}
+
+#[test]
+fn test_events_round_trip() {
+    for event in [
+        FlipEvent::UserBetPlaced(bet_placed()),
+        FlipEvent::UserBetSettled(bet_settled()),
+        FlipEvent::UserRoundRandomness(round_randomness()),
+        FlipEvent::HouseRevealed(house_revealed()),
+    ] {
+        let decoded = FlipEvent::from_log(&event.to_log()).unwrap();
+        assert_eq!(decoded, Some(event));
+    }
+}
+
+#[test]
+fn test_events_encode_like_emit() {
+    let placed = bet_placed();
+    let settled = bet_settled();
+    let randomness = round_randomness();
+    let revealed = house_revealed();
+
+    assert_eq!(
+        FlipEvent::UserBetPlaced(placed.clone()).to_bytes(),
+        placed.data()
+    );
+    assert_eq!(
+        FlipEvent::UserBetSettled(settled.clone()).to_bytes(),
+        settled.data()
+    );
+    assert_eq!(
+        FlipEvent::UserRoundRandomness(randomness.clone()).to_bytes(),
+        randomness.data()
+    );
+    assert_eq!(
+        FlipEvent::HouseRevealed(revealed.clone()).to_bytes(),
+        revealed.data()
+    );
+}
+
+#[test]
+fn test_from_log_ignores_other_logs() {
+    assert_eq!(
+        FlipEvent::from_log("Program log: Instruction: UserBet").unwrap(),
+        None
+    );
+
+    // valid base64, but not one of our discriminators
+    let other = format!(
+        "Program data: {}",
+        base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            instruction::UserSettle {
+                params: UserSettleParams {}
+            }
+            .data()
+        )
+    );
+    assert_eq!(FlipEvent::from_log(&other).unwrap(), None);
+}
+
+#[test]
+fn test_from_log_rejects_malformed_data() {
+    assert!(FlipEvent::from_log("Program data: not base64!").is_err());
+
+    let mut truncated = FlipEvent::UserBetSettled(bet_settled()).to_bytes();
+    truncated.truncate(20);
+    let log = format!(
+        "Program data: {}",
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, truncated)
+    );
+    assert!(FlipEvent::from_log(&log).is_err());
+}
+
+#[test]
+fn test_parse_logs_follows_the_call_stack() {
+    let flip = switchboard_vrf_flip::ID;
+    let other = Pubkey::new_unique();
+    let placed = FlipEvent::UserBetPlaced(bet_placed());
+    let settled = FlipEvent::UserBetSettled(bet_settled());
+
+    let logs = vec![
+        invoke(&flip, 1),
+        "Program log: Instruction: UserBet".to_string(),
+        invoke(&SWITCHBOARD_PROGRAM_ID, 2),
+        // data logged by another program, even if it decodes as our event
+        settled.to_log(),
+        success(&SWITCHBOARD_PROGRAM_ID),
+        placed.to_log(),
+        format!("Program {} consumed 61234 of 200000 compute units", flip),
+        success(&flip),
+        invoke(&other, 1),
+        // the switchboard callback settles the round through a CPI
+        invoke(&flip, 2),
+        settled.to_log(),
+        success(&flip),
+        success(&other),
+    ];
+
+    assert_eq!(parse_logs(&logs).unwrap(), vec![placed, settled]);
+}
+
+#[test]
+fn test_parse_logs_skips_failed_invocations() {
+    let flip = switchboard_vrf_flip::ID;
+    let placed = FlipEvent::UserBetPlaced(bet_placed());
+
+    let logs = vec![
+        invoke(&flip, 1),
+        invoke(&SWITCHBOARD_PROGRAM_ID, 2),
+        format!(
+            "Program {} failed: custom program error: 0x1",
+            SWITCHBOARD_PROGRAM_ID
+        ),
+        placed.to_log(),
+        success(&flip),
+    ];
+
+    assert_eq!(parse_logs(&logs).unwrap(), vec![placed]);
+}
@@ -0,0 +1,124 @@
+use solana_program_test::BanksClientError;
+use switchboard_vrf_flip::{GameBetLimitsParams, HouseUpdateParams, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+async fn set_bet_limits(
+    harness: &mut Harness,
+    game_type: u32,
+    min_bet: u64,
+    max_bet: u64,
+    max_payout_bps: u32,
+) -> Result<(), BanksClientError> {
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: None,
+            vrf_fee: None,
+            bet_limits: Some(GameBetLimitsParams {
+                game_type,
+                min_bet,
+                max_bet,
+                max_payout_bps,
+            }),
+            max_liability_bps: None,
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_house_bet_limits_min_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    set_bet_limits(&mut harness, COIN_FLIP, 2 * TOKEN, 0, 0)
+        .await
+        .unwrap();
+
+    let house = harness.house_state().await;
+    let min_bet = house.bet_limits[0].min_bet;
+    assert_eq!(min_bet, 2 * TOKEN);
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::MinBetAmountNotMet);
+
+    // other games keep their own limits
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 1, TOKEN)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_house_bet_limits_max_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    set_bet_limits(&mut harness, COIN_FLIP, 0, TOKEN, 0)
+        .await
+        .unwrap();
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN + 1).await;
+    assert_flip_error(result, VrfFlipError::MaxBetAmountExceeded);
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_house_bet_limits_default_max_bet() {
+    let mut harness = Harness::new().await;
+    let first = harness.create_player().await;
+    let second = harness.create_player().await;
+    let house_vault = harness.house_vault;
+    let mint = harness.mint;
+    let house = harness.house;
+    harness.set_token_account(&house_vault, mint, house, 50 * TOKEN);
+
+    // without a payout limit a bet can be at most a tenth of the house vault,
+    // whatever it can win
+    harness
+        .bet(&first, SIX_SIDED_DICE_ROLL, 1, 5 * TOKEN)
+        .await
+        .unwrap();
+    let result = harness.bet(&second, COIN_FLIP, 1, 5 * TOKEN + 1).await;
+    assert_flip_error(result, VrfFlipError::MaxBetAmountExceeded);
+}
+
+#[tokio::test]
+async fn test_house_bet_limits_max_payout() {
+    let mut harness = Harness::new().await;
+    let first = harness.create_player().await;
+    let second = harness.create_player().await;
+    let house_vault = harness.house_vault;
+    let mint = harness.mint;
+    let house = harness.house;
+    harness.set_token_account(&house_vault, mint, house, 50 * TOKEN);
+    set_bet_limits(&mut harness, SIX_SIDED_DICE_ROLL, 0, 0, 2_000)
+        .await
+        .unwrap();
+    set_bet_limits(&mut harness, COIN_FLIP, 0, 0, 2_000)
+        .await
+        .unwrap();
+
+    // a payout limit replaces the bet limit, a dice roll can win at most 10 tokens
+    let result = harness
+        .bet(&first, SIX_SIDED_DICE_ROLL, 1, 2 * TOKEN + 1)
+        .await;
+    assert_flip_error(result, VrfFlipError::MaxPayoutExceeded);
+    harness
+        .bet(&first, SIX_SIDED_DICE_ROLL, 1, 2 * TOKEN)
+        .await
+        .unwrap();
+
+    // while a coin flip can bet more than a tenth of the vault
+    harness.bet(&second, COIN_FLIP, 1, 6 * TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_house_bet_limits_rejects_invalid_limits() {
+    let mut harness = Harness::new().await;
+
+    let result = set_bet_limits(&mut harness, COIN_FLIP, 2 * TOKEN, TOKEN, 0).await;
+    assert_flip_error(result, VrfFlipError::InvalidBetLimits);
+
+    let result = set_bet_limits(&mut harness, COIN_FLIP, 0, 0, 10_001).await;
+    assert_flip_error(result, VrfFlipError::InvalidBetLimits);
+
+    let result = set_bet_limits(&mut harness, 0, 0, 0, 0).await;
+    assert_flip_error(result, VrfFlipError::InvalidGameType);
+}
@@ -0,0 +1,152 @@
+use anchor_spl::{
+    associated_token::get_associated_token_address, token::spl_token::state::Account as SplAccount,
+};
+use solana_program::program_pack::Pack;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use switchboard_v2::OracleQueueAccountData;
+use switchboard_vrf_flip::{HouseUpdateParams, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_house_init() {
+    let mut harness = Harness::new().await;
+
+    let house = harness.house_state().await;
+    assert_eq!(house.authority, harness.house_authority.pubkey());
+    assert_eq!(house.mint, harness.mint);
+    assert_eq!(house.house_vault, harness.house_vault);
+    assert_eq!(house.switchboard_queue, harness.switchboard.queue);
+    assert_eq!(house.switchboard_mint, harness.switchboard.mint);
+    assert_eq!(house.mint_seeded, 1);
+
+    let house_vault = harness.house_vault;
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        100_000_000_000_000_000
+    );
+}
+
+#[tokio::test]
+async fn test_house_init_multiple_mints() {
+    let mut harness = Harness::new().await;
+    let first_house = harness.house;
+    let first_player = harness.create_player().await;
+
+    let mint = Keypair::new();
+    let ix = harness.house_init_ix(&mint.pubkey());
+    let house_authority = harness.house_authority.insecure_clone();
+    harness
+        .process(&[ix], &[&house_authority, &mint])
+        .await
+        .unwrap();
+
+    // point the harness at the second house
+    harness.house = house_address(&mint.pubkey());
+    harness.house_vault = get_associated_token_address(&harness.house, &mint.pubkey());
+    harness.mint = mint.pubkey();
+    harness.mint_keypair = mint;
+    assert_ne!(harness.house, first_house);
+
+    let house = harness.house_state().await;
+    assert_eq!(house.mint, harness.mint);
+    assert_eq!(house.house_vault, harness.house_vault);
+
+    // users are tied to the house they were created for
+    let player = harness.create_player().await;
+    let user = harness.user_state(&player).await;
+    assert_eq!(user.house, harness.house);
+    let first_user = harness.user_state(&first_player).await;
+    assert_eq!(first_user.house, first_house);
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_house_init_requires_unpermissioned_queue() {
+    let mut harness = Harness::start().await;
+
+    let mut queue: OracleQueueAccountData = bytemuck::Zeroable::zeroed();
+    queue.authority = harness.switchboard.queue_authority;
+    queue.data_buffer = harness.switchboard.data_buffer;
+    queue.unpermissioned_vrf_enabled = false;
+    harness.set_queue_state(&queue);
+
+    let result = harness.house_init().await;
+    assert_flip_error(result, VrfFlipError::OracleQueueRequiresPermissions);
+}
+
+#[tokio::test]
+async fn test_house_init_fee_vault() {
+    let mut harness = Harness::new().await;
+
+    harness.house_init_fee_vault().await.unwrap();
+
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: Some(true),
+            vrf_fee: Some(TOKEN / 100),
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+        .unwrap();
+
+    let house = harness.house_state().await;
+    let fee_vault = harness.fee_vault_address();
+    let vrf_fee = house.vrf_fee;
+    assert_eq!(house.fee_vault, fee_vault);
+    assert_eq!(house.sponsor_vrf_fees, 1);
+    assert_eq!(vrf_fee, TOKEN / 100);
+
+    let data = harness.account_data(&fee_vault).await;
+    let fee_vault_account = SplAccount::unpack(&data).unwrap();
+    assert_eq!(fee_vault_account.mint, harness.switchboard.mint);
+    assert_eq!(fee_vault_account.owner, harness.house);
+
+    // unset fields are left untouched
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: Some(false),
+            vrf_fee: None,
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+        .unwrap();
+    let house = harness.house_state().await;
+    let vrf_fee = house.vrf_fee;
+    assert_eq!(house.sponsor_vrf_fees, 0);
+    assert_eq!(vrf_fee, TOKEN / 100);
+}
+
+#[tokio::test]
+async fn test_house_update_sponsorship_requires_fee_vault() {
+    let mut harness = Harness::new().await;
+
+    let result = harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: Some(true),
+            vrf_fee: None,
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await;
+    assert_flip_error(result, VrfFlipError::FeeVaultRequired);
+
+    // fee settings can still be changed without sponsoring
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: None,
+            vrf_fee: Some(TOKEN / 100),
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+        .unwrap();
+    let house = harness.house_state().await;
+    assert_eq!(house.fee_vault, Pubkey::default());
+    assert_eq!(house.sponsor_vrf_fees, 0);
+}
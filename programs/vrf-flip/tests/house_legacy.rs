@@ -0,0 +1,52 @@
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{RoundStatus, UserBetParams};
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_house_legacy_settles_bet() {
+    let mut harness = Harness::new_legacy().await;
+    let house = harness.house_state().await;
+    let mint_seeded = house.mint_seeded;
+    assert_eq!(mint_seeded, 0);
+    assert!(house.mint_seed().is_empty());
+
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    assert!(user.current_round.status == RoundStatus::Settled);
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        11 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_house_legacy_sponsors_vrf_fees() {
+    let mut harness = Harness::new_legacy().await;
+    harness.sponsor_vrf_fees(TOKEN / 100).await.unwrap();
+    let player = harness.create_player().await;
+
+    // the house signs for the fee vault with its legacy seeds
+    let mut ix = harness.user_bet_ix(
+        &player,
+        UserBetParams {
+            game_type: COIN_FLIP,
+            user_guess: 1,
+            bet_amount: TOKEN,
+        },
+    );
+    let fee_vault = harness.fee_vault_address();
+    replace_account(&mut ix, &player.vrf_payer, fee_vault);
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        9 * TOKEN - TOKEN / 100
+    );
+}
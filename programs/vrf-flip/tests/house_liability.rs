@@ -0,0 +1,84 @@
+use switchboard_vrf_flip::{HouseUpdateParams, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_house_liability_tracks_open_rounds() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 1, TOKEN)
+        .await
+        .unwrap();
+    let house = harness.house_state().await;
+    let open_liability = house.open_liability;
+    assert_eq!(open_liability, 5 * TOKEN);
+
+    harness.set_vrf_result(&player, vrf_result_for(2, 6)).await;
+    harness.settle(&player).await.unwrap();
+    let house = harness.house_state().await;
+    let open_liability = house.open_liability;
+    assert_eq!(open_liability, 0);
+}
+
+#[tokio::test]
+async fn test_house_liability_rejects_bets_over_limit() {
+    let mut harness = Harness::new().await;
+    let first = harness.create_player().await;
+    let second = harness.create_player().await;
+    let (house_vault, mint, house) = (harness.house_vault, harness.mint, harness.house);
+    harness.set_token_account(&house_vault, mint, house, 100 * TOKEN);
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: None,
+            vrf_fee: None,
+            bet_limits: None,
+            max_liability_bps: Some(1_000),
+        })
+        .await
+        .unwrap();
+
+    harness.bet(&first, COIN_FLIP, 1, 6 * TOKEN).await.unwrap();
+
+    // each bet is within its own limits, but both could win at once
+    let result = harness.bet(&second, COIN_FLIP, 1, 6 * TOKEN).await;
+    assert_flip_error(result, VrfFlipError::MaxLiabilityExceeded);
+    harness.bet(&second, COIN_FLIP, 1, 4 * TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_house_liability_releases_expired_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 1, TOKEN)
+        .await
+        .unwrap();
+
+    // the round times out and a new bet replaces it
+    let mut user = harness.user_state(&player).await;
+    user.current_round.request_timestamp = 0;
+    harness.set_user_state(&player, &user);
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let house = harness.house_state().await;
+    let open_liability = house.open_liability;
+    let dice_exposure = house.game_stats[1].open_exposure;
+    assert_eq!(open_liability, TOKEN);
+    assert_eq!(dice_exposure, 0);
+}
+
+#[tokio::test]
+async fn test_house_liability_rejects_invalid_limit() {
+    let mut harness = Harness::new().await;
+
+    let result = harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: None,
+            vrf_fee: None,
+            bet_limits: None,
+            max_liability_bps: Some(10_001),
+        })
+        .await;
+    assert_flip_error(result, VrfFlipError::InvalidMaxLiability);
+}
@@ -0,0 +1,210 @@
+use anchor_spl::token::{spl_token::native_mint, TokenAccount};
+use solana_program::rent::Rent;
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{UserAutoplayStartParams, VrfFlipError, NATIVE_FEE_RESERVE};
+use vrf_flip_test_harness::*;
+
+const LIQUIDITY: u64 = 100 * TOKEN;
+const BET: u64 = TOKEN / 10;
+
+fn unwrap_account_rent() -> u64 {
+    Rent::default().minimum_balance(TokenAccount::LEN)
+}
+
+#[tokio::test]
+async fn test_house_native_init() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+
+    let house = harness.house_state().await;
+    assert_eq!(house.mint, native_mint::ID);
+    assert!(house.is_native());
+    let house_vault = harness.house_vault;
+    assert_eq!(harness.token_balance(&house_vault).await, LIQUIDITY);
+}
+
+#[tokio::test]
+async fn test_house_native_bet_wraps_sol() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    let lamports = harness.lamports(&player.authority.pubkey()).await;
+
+    // the first bet also pays the rent of the unwrap account
+    harness.bet(&player, COIN_FLIP, 1, BET).await.unwrap();
+    assert_eq!(
+        harness.lamports(&player.authority.pubkey()).await,
+        lamports - BET - unwrap_account_rent()
+    );
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, BET);
+    let unwrap_account = harness.unwrap_address(&player);
+    assert_eq!(harness.token_balance(&unwrap_account).await, 0);
+
+    // the payout and the rent are unwrapped into the authority's wallet
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+    assert_eq!(
+        harness.lamports(&player.authority.pubkey()).await,
+        lamports + BET
+    );
+    assert_eq!(harness.token_balance(&player.reward_address).await, 0);
+    assert_eq!(harness.lamports(&unwrap_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_house_native_loss_returns_unwrap_rent() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    let lamports = harness.lamports(&player.authority.pubkey()).await;
+
+    harness.bet(&player, COIN_FLIP, 1, BET).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+    assert_eq!(
+        harness.lamports(&player.authority.pubkey()).await,
+        lamports - BET
+    );
+    let unwrap_account = harness.unwrap_address(&player);
+    assert_eq!(harness.lamports(&unwrap_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_house_native_settle_without_unwrap_account_pays_wrapped_sol() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, BET).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+
+    // like the callback of a VRF created before payouts were unwrapped
+    let mut ix = harness.user_settle_ix(&player);
+    ix.accounts.truncate(ix.accounts.len() - 2);
+    harness.process(&[ix], &[]).await.unwrap();
+    assert_eq!(harness.token_balance(&player.reward_address).await, 2 * BET);
+
+    // the winnings are already wrapped, so the next bet takes no SOL
+    let lamports = harness.lamports(&player.authority.pubkey()).await;
+    harness.bet(&player, COIN_FLIP, 1, BET).await.unwrap();
+    assert_eq!(harness.lamports(&player.authority.pubkey()).await, lamports);
+    assert_eq!(harness.token_balance(&player.reward_address).await, BET);
+}
+
+#[tokio::test]
+async fn test_house_native_rejects_bet_over_balance() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    let lamports = harness.lamports(&player.authority.pubkey()).await;
+
+    let result = harness.bet(&player, COIN_FLIP, 1, lamports + 1).await;
+    assert_flip_error(result, VrfFlipError::InsufficientFunds);
+}
+
+#[tokio::test]
+async fn test_house_native_bet_keeps_payer_rent_exempt() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    let lamports = harness.lamports(&player.authority.pubkey()).await;
+    let reserved = Rent::default().minimum_balance(0) + NATIVE_FEE_RESERVE;
+    let spendable = lamports - reserved - unwrap_account_rent();
+
+    let result = harness.bet(&player, COIN_FLIP, 1, spendable + 1).await;
+    assert_flip_error(result, VrfFlipError::InsufficientFunds);
+
+    harness.bet(&player, COIN_FLIP, 1, spendable).await.unwrap();
+    assert_eq!(harness.lamports(&player.authority.pubkey()).await, reserved);
+}
+
+#[tokio::test]
+async fn test_house_native_withdraw_escrow_unwraps() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    let house = harness.house;
+    harness.set_native_token_account(&player.escrow.pubkey(), house, 3 * BET);
+    let lamports = harness.lamports(&player.authority.pubkey()).await;
+
+    harness.user_withdraw_escrow(&player).await.unwrap();
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(harness.token_balance(&player.reward_address).await, 0);
+    assert_eq!(
+        harness.lamports(&player.authority.pubkey()).await,
+        lamports + 3 * BET
+    );
+    let unwrap_account = harness.unwrap_address(&player);
+    assert_eq!(harness.lamports(&unwrap_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_house_native_autoplay_unwraps_when_session_ends() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    harness.sponsor_vrf_fees(0).await.unwrap();
+    let player = harness.create_player().await;
+    let authority = player.authority.pubkey();
+    harness.set_native_token_account(&player.reward_address, authority, 10 * BET);
+    let lamports = harness.lamports(&authority).await;
+
+    harness
+        .user_autoplay_start(
+            &player,
+            UserAutoplayStartParams {
+                game_type: COIN_FLIP,
+                user_guess: 1,
+                bet_amount: BET,
+                num_bets: 2,
+                stop_loss: 0,
+                take_profit: 0,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.lamports(&authority).await,
+        lamports - unwrap_account_rent()
+    );
+
+    // winnings collect in the unwrap account while the session runs
+    harness.user_autoplay_bet(&player).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+    let unwrap_account = harness.unwrap_address(&player);
+    assert_eq!(harness.token_balance(&unwrap_account).await, 2 * BET);
+
+    harness.user_autoplay_bet(&player).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+    assert_eq!(harness.lamports(&unwrap_account).await, 0);
+    assert_eq!(harness.lamports(&authority).await, lamports + 2 * BET);
+    assert_eq!(harness.token_balance(&player.reward_address).await, 8 * BET);
+}
+
+#[tokio::test]
+async fn test_house_native_user_close_closes_unwrap_account() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, BET).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+
+    // a settle without the unwrap account leaves it open
+    let mut ix = harness.user_settle_ix(&player);
+    ix.accounts.truncate(ix.accounts.len() - 2);
+    harness.process(&[ix], &[]).await.unwrap();
+    let unwrap_account = harness.unwrap_address(&player);
+    assert_eq!(
+        harness.lamports(&unwrap_account).await,
+        unwrap_account_rent()
+    );
+
+    let authority = player.authority.pubkey();
+    let lamports = harness.lamports(&authority).await;
+    let rent = harness.lamports(&player.user).await
+        + harness.lamports(&player.escrow.pubkey()).await
+        + unwrap_account_rent();
+    harness.user_close(&player).await.unwrap();
+    assert_eq!(harness.lamports(&unwrap_account).await, 0);
+    assert_eq!(harness.lamports(&authority).await, lamports + rent);
+}
+
+#[tokio::test]
+async fn test_house_native_rejects_airdrop() {
+    let mut harness = Harness::new_native(LIQUIDITY).await;
+    let player = harness.create_player().await;
+
+    let result = harness.airdrop(&player).await;
+    assert_flip_error(result, VrfFlipError::UnauthorizedMint);
+}
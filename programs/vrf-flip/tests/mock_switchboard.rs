@@ -0,0 +1,52 @@
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::RoundStatus;
+use vrf_flip_test_harness::*;
+
+const BET_AMOUNT: u64 = TOKEN;
+
+#[tokio::test]
+async fn test_bet_requests_randomness_from_mock() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness
+        .bet(&player, COIN_FLIP, 1, BET_AMOUNT)
+        .await
+        .unwrap();
+
+    let vrf = harness.vrf_state(&player).await;
+    let counter = vrf.counter;
+    assert_eq!(counter, 1);
+
+    let user = harness.user_state(&player).await;
+    let round_id = user.current_round.round_id;
+    assert_eq!(round_id, 1);
+    assert!(user.current_round.status == RoundStatus::Awaiting);
+    assert_eq!(
+        harness.token_balance(&player.escrow.pubkey()).await,
+        BET_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_settle_with_mock_result() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let starting_balance = harness.token_balance(&player.reward_address).await;
+
+    harness
+        .bet(&player, COIN_FLIP, 2, BET_AMOUNT)
+        .await
+        .unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let result = user.current_round.result;
+    assert_eq!(result, 2);
+    assert!(user.current_round.status == RoundStatus::Settled);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        starting_balance + BET_AMOUNT
+    );
+}
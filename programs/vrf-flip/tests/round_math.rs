@@ -0,0 +1,113 @@
+use proptest::prelude::*;
+use solana_program::clock::Clock;
+use switchboard_vrf_flip::{GameType, Round, RoundStatus, MAX_BET_AMOUNT};
+
+fn game_type() -> impl Strategy<Value = GameType> {
+    prop_oneof![
+        Just(GameType::CoinFlip),
+        Just(GameType::SixSidedDiceRoll),
+        Just(GameType::TwentySidedDiceRoll),
+    ]
+}
+
+fn clock(slot: u64, unix_timestamp: i64) -> Clock {
+    Clock {
+        slot,
+        unix_timestamp,
+        ..Clock::default()
+    }
+}
+
+fn new_round(game_type: GameType, guess: u32, bet_amount: u64) -> Round {
+    Round {
+        round_id: 1,
+        status: RoundStatus::Awaiting,
+        bet_amount,
+        game_type,
+        game_config: game_type.get_game_config().unwrap(),
+        guess,
+        result: 0,
+        request_slot: 1,
+        request_timestamp: 1,
+        settle_slot: 0,
+        settle_timestamp: 0,
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_result_within_game_range(
+        game_type in game_type(),
+        guess in any::<u32>(),
+        vrf_result in any::<[u32; 8]>(),
+    ) {
+        let config = game_type.get_game_config().unwrap();
+        let mut round = new_round(game_type, guess, 1);
+
+        round.settle(&vrf_result, &clock(10, 100)).unwrap();
+
+        let result = round.result;
+        prop_assert!(result >= config.min);
+        prop_assert!(result <= config.max);
+    }
+
+    #[test]
+    fn test_settle_uses_provided_clock(
+        game_type in game_type(),
+        vrf_result in any::<[u32; 8]>(),
+        slot in any::<u64>(),
+        unix_timestamp in any::<i64>(),
+    ) {
+        let mut round = new_round(game_type, 1, 1);
+
+        let user_won = round.settle(&vrf_result, &clock(slot, unix_timestamp)).unwrap();
+
+        let (result, guess) = (round.result, round.guess);
+        let (settle_slot, settle_timestamp) = (round.settle_slot, round.settle_timestamp);
+        prop_assert_eq!(user_won, result == guess);
+        prop_assert!(round.status == RoundStatus::Settled);
+        prop_assert_eq!(settle_slot, slot);
+        prop_assert_eq!(settle_timestamp, unix_timestamp);
+    }
+
+    #[test]
+    fn test_payout_does_not_overflow(
+        game_type in game_type(),
+        bet_amount in 0..=MAX_BET_AMOUNT,
+    ) {
+        let config = game_type.get_game_config().unwrap();
+        let mut round = new_round(game_type, 1, bet_amount);
+        round.result = 1;
+
+        let payout = round.payout_amount().unwrap();
+
+        prop_assert_eq!(payout, bet_amount * config.payout_multiplier as u64);
+        prop_assert!(payout.checked_add(bet_amount).is_some());
+    }
+
+    #[test]
+    fn test_payout_is_zero_for_losing_guess(
+        game_type in game_type(),
+        guess in any::<u32>(),
+        result in 1..=20u32,
+        bet_amount in 0..=MAX_BET_AMOUNT,
+    ) {
+        prop_assume!(guess != result);
+        let mut round = new_round(game_type, guess, bet_amount);
+        round.result = result;
+
+        prop_assert_eq!(round.payout_amount().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_round_is_open_for_sixty_seconds(
+        request_timestamp in 0..i64::MAX / 2,
+        elapsed in 0..120i64,
+    ) {
+        let mut round = new_round(GameType::CoinFlip, 1, 1);
+        round.request_timestamp = request_timestamp;
+
+        let is_open = round.is_open(&clock(0, request_timestamp + elapsed));
+        prop_assert_eq!(is_open, elapsed < 60);
+    }
+}
@@ -0,0 +1,56 @@
+use switchboard_vrf_flip::VrfFlipError;
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_user_airdrop() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.warp_slots(1).await;
+    harness.airdrop(&player).await.unwrap();
+
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        11 * TOKEN
+    );
+    let user = harness.user_state(&player).await;
+    let last_airdrop_request_slot = user.last_airdrop_request_slot;
+    assert!(last_airdrop_request_slot > 0);
+}
+
+#[tokio::test]
+async fn test_user_airdrop_rejects_repeat_request() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    // the first airdrop is always allowed while the request slot is still 0
+    harness.warp_slots(1).await;
+    harness.airdrop(&player).await.unwrap();
+
+    harness.warp_slots(1).await;
+    let result = harness.airdrop(&player).await;
+    assert_flip_error(result, VrfFlipError::AirdropRequestedTooSoon);
+}
+
+#[tokio::test]
+async fn test_user_airdrop_rejects_healthy_balance() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.airdrop(&player).await.unwrap();
+
+    harness.warp_slots(5_001).await;
+    let result = harness.airdrop(&player).await;
+    assert_flip_error(result, VrfFlipError::UserTokenBalanceHealthy);
+}
+
+#[tokio::test]
+async fn test_user_airdrop_requires_mint_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.set_mint_authority(None);
+
+    let result = harness.airdrop(&player).await;
+    assert_flip_error(result, VrfFlipError::UnauthorizedMint);
+}
@@ -0,0 +1,248 @@
+use anchor_spl::associated_token::get_associated_token_address;
+use solana_sdk::signature::{Keypair, Signer};
+use switchboard_vrf_flip::{HouseUpdateParams, UserAutoplayStartParams, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+fn coin_flip_session(num_bets: u32, stop_loss: u64, take_profit: u64) -> UserAutoplayStartParams {
+    UserAutoplayStartParams {
+        game_type: COIN_FLIP,
+        user_guess: 1,
+        bet_amount: TOKEN,
+        num_bets,
+        stop_loss,
+        take_profit,
+    }
+}
+
+async fn start_session(harness: &mut Harness, params: UserAutoplayStartParams) -> Player {
+    harness.sponsor_vrf_fees(0).await.unwrap();
+    let player = harness.create_player().await;
+    harness.user_autoplay_start(&player, params).await.unwrap();
+    player
+}
+
+/// Has the keeper place the next bet and settles it with the given coin flip result
+async fn play_round(harness: &mut Harness, player: &Player, result: u32) {
+    harness.user_autoplay_bet(player).await.unwrap();
+    harness
+        .set_vrf_result(player, vrf_result_for(result, 2))
+        .await;
+    harness.settle(player).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_user_autoplay_start_funds_session() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    let user = harness.user_state(&player).await;
+    let remaining_bets = user.autoplay.remaining_bets;
+    assert!(user.autoplay.is_active());
+    assert_eq!(remaining_bets, 3);
+    assert_eq!(
+        harness.token_balance(&player.escrow.pubkey()).await,
+        3 * TOKEN
+    );
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        7 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_autoplay_plays_every_bet() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    for _ in 0..3 {
+        play_round(&mut harness, &player, 2).await;
+    }
+
+    let user = harness.user_state(&player).await;
+    let net_pnl = user.autoplay.net_pnl;
+    assert!(!user.autoplay.is_active());
+    assert_eq!(net_pnl, -3 * TOKEN as i64);
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+
+    let result = harness.user_autoplay_bet(&player).await;
+    assert_flip_error(result, VrfFlipError::AutoPlayInactive);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_take_profit() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, TOKEN)).await;
+
+    play_round(&mut harness, &player, 1).await;
+
+    // the win, the returned bet and the two unplayed bets go back to the user
+    let user = harness.user_state(&player).await;
+    assert!(!user.autoplay.is_active());
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        11 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_autoplay_stop_loss() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(5, 2 * TOKEN, 0)).await;
+
+    play_round(&mut harness, &player, 2).await;
+    let user = harness.user_state(&player).await;
+    assert!(user.autoplay.is_active());
+
+    play_round(&mut harness, &player, 2).await;
+    let user = harness.user_state(&player).await;
+    assert!(!user.autoplay.is_active());
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        8 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_autoplay_waits_for_settlement() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    harness.user_autoplay_bet(&player).await.unwrap();
+    let result = harness.user_autoplay_bet(&player).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundStillActive);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_rejects_manual_bets() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::AutoPlayActive);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_rejects_second_session() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    let result = harness
+        .user_autoplay_start(&player, coin_flip_session(1, 0, 0))
+        .await;
+    assert_flip_error(result, VrfFlipError::AutoPlayActive);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_rejects_empty_session() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness
+        .user_autoplay_start(&player, coin_flip_session(0, 0, 0))
+        .await;
+    assert_flip_error(result, VrfFlipError::InvalidAutoPlay);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_stop_releases_escrow() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    // the reserved escrow is locked while the session runs
+    let result = harness.user_withdraw_escrow(&player).await;
+    assert_flip_error(result, VrfFlipError::NoUnlockedEscrow);
+
+    harness.user_autoplay_stop(&player).await.unwrap();
+    harness.user_withdraw_escrow(&player).await.unwrap();
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        10 * TOKEN
+    );
+
+    let result = harness.user_autoplay_bet(&player).await;
+    assert_flip_error(result, VrfFlipError::AutoPlayInactive);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_requires_vrf_sponsorship() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness
+        .user_autoplay_start(&player, coin_flip_session(3, 0, 0))
+        .await;
+    assert_flip_error(result, VrfFlipError::VrfSponsorshipDisabled);
+}
+
+#[tokio::test]
+async fn test_user_autoplay_charges_vrf_fee_to_session() {
+    let mut harness = Harness::new().await;
+    let vrf_fee = TOKEN / 100;
+    harness.sponsor_vrf_fees(vrf_fee).await.unwrap();
+    let player = harness.create_player().await;
+    harness
+        .user_autoplay_start(&player, coin_flip_session(2, 0, 0))
+        .await
+        .unwrap();
+
+    // the session escrows a vrf fee with each bet
+    assert_eq!(
+        harness.token_balance(&player.escrow.pubkey()).await,
+        2 * (TOKEN + vrf_fee)
+    );
+
+    let house_vault = harness.house_vault;
+    let vault_balance = harness.token_balance(&house_vault).await;
+    harness.user_autoplay_bet(&player).await.unwrap();
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        vault_balance + vrf_fee
+    );
+    assert_eq!(
+        harness.token_balance(&player.escrow.pubkey()).await,
+        2 * TOKEN + vrf_fee
+    );
+
+    // a fee change mid-session doesn't touch what the session reserved
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: None,
+            vrf_fee: Some(2 * vrf_fee),
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+        .unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+    play_round(&mut harness, &player, 2).await;
+
+    let user = harness.user_state(&player).await;
+    assert!(!user.autoplay.is_active());
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        10 * TOKEN - 2 * (TOKEN + vrf_fee)
+    );
+}
+
+#[tokio::test]
+async fn test_user_autoplay_rejects_keeper_vrf_payer() {
+    let mut harness = Harness::new().await;
+    let player = start_session(&mut harness, coin_flip_session(3, 0, 0)).await;
+
+    let keeper = Keypair::new();
+    harness.fund_wallets(&keeper.pubkey(), 0);
+    let mut ix = harness.user_autoplay_bet_ix(&player);
+    let vrf_payer = get_associated_token_address(&keeper.pubkey(), &harness.switchboard.mint);
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == harness.fee_vault_address() {
+            meta.pubkey = vrf_payer;
+        }
+    }
+    let result = harness.process(&[ix], &[]).await;
+    assert_flip_error(result, VrfFlipError::InvalidVrfPayer);
+}
@@ -0,0 +1,210 @@
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use switchboard_v2::SWITCHBOARD_PROGRAM_ID;
+use switchboard_vrf_flip::{HouseUpdateParams, RoundStatus, UserBetParams, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+// VrfFlipError::FlipRequestedTooSoon is only returned once the user sets a
+// cooldown, see user_limits.rs.
+
+fn params(game_type: u32, user_guess: u32, bet_amount: u64) -> UserBetParams {
+    UserBetParams {
+        game_type,
+        user_guess,
+        bet_amount,
+    }
+}
+
+#[tokio::test]
+async fn test_user_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 4, TOKEN)
+        .await
+        .unwrap();
+
+    let user = harness.user_state(&player).await;
+    let round_id = user.current_round.round_id;
+    let bet_amount = user.current_round.bet_amount;
+    let guess = user.current_round.guess;
+    assert_eq!(round_id, 1);
+    assert_eq!(bet_amount, TOKEN);
+    assert_eq!(guess, 4);
+    assert!(user.current_round.status == RoundStatus::Awaiting);
+
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, TOKEN);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        9 * TOKEN
+    );
+    let counter = harness.vrf_state(&player).await.counter;
+    assert_eq!(counter, 1);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_invalid_game_type() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.bet(&player, 0, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidGameType);
+
+    let result = harness.bet(&player, 7, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidGameType);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_guess_out_of_range() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.bet(&player, COIN_FLIP, 3, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidBet);
+
+    let result = harness.bet(&player, TWENTY_SIDED_DICE_ROLL, 0, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidBet);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_max_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.bet(&player, COIN_FLIP, 1, 100 * TOKEN + 1).await;
+    assert_flip_error(result, VrfFlipError::MaxBetAmountExceeded);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_insufficient_flip_balance() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.bet(&player, COIN_FLIP, 1, 20 * TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InsufficientFunds);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_insufficient_vrf_balance() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let switchboard_mint = harness.switchboard.mint;
+    harness.set_token_account(
+        &player.vrf_payer,
+        switchboard_mint,
+        player.authority.pubkey(),
+        0,
+    );
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InsufficientFunds);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_open_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let result = harness.bet(&player, COIN_FLIP, 2, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundStillActive);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_fake_switchboard_program() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let mut ix = harness.user_bet_ix(&player, params(COIN_FLIP, 1, TOKEN));
+    replace_account(&mut ix, &SWITCHBOARD_PROGRAM_ID, Pubkey::new_unique());
+
+    let result = harness.process(&[ix], &[&player.authority]).await;
+    assert_flip_error(result, VrfFlipError::InvalidSwitchboardAccount);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_foreign_vrf_payer() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let foreign_vrf_payer = Pubkey::new_unique();
+    let switchboard_mint = harness.switchboard.mint;
+    harness.set_token_account(
+        &foreign_vrf_payer,
+        switchboard_mint,
+        Pubkey::new_unique(),
+        VRF_PAYER_BALANCE,
+    );
+
+    let mut ix = harness.user_bet_ix(&player, params(COIN_FLIP, 1, TOKEN));
+    replace_account(&mut ix, &player.vrf_payer, foreign_vrf_payer);
+
+    let result = harness.process(&[ix], &[&player.authority]).await;
+    assert_flip_error(result, VrfFlipError::InvalidVrfPayer);
+}
+
+#[tokio::test]
+async fn test_user_bet_sponsored_by_fee_vault() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let vrf_fee = TOKEN / 100;
+    harness.house_init_fee_vault().await.unwrap();
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: Some(true),
+            vrf_fee: Some(vrf_fee),
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+        .unwrap();
+
+    let fee_vault = harness.fee_vault_address();
+    let switchboard_mint = harness.switchboard.mint;
+    let house = harness.house;
+    harness.set_token_account(&fee_vault, switchboard_mint, house, VRF_PAYER_BALANCE);
+
+    let house_vault = harness.house_vault;
+    let house_vault_balance = harness.token_balance(&house_vault).await;
+
+    let mut ix = harness.user_bet_ix(&player, params(COIN_FLIP, 1, TOKEN));
+    replace_account(&mut ix, &player.vrf_payer, fee_vault);
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        10 * TOKEN - TOKEN - vrf_fee
+    );
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        house_vault_balance + vrf_fee
+    );
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_fee_vault_when_not_sponsoring() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.house_init_fee_vault().await.unwrap();
+    harness
+        .house_update(HouseUpdateParams {
+            sponsor_vrf_fees: Some(false),
+            vrf_fee: None,
+            bet_limits: None,
+            max_liability_bps: None,
+        })
+        .await
+        .unwrap();
+
+    let fee_vault = harness.fee_vault_address();
+    let mut ix = harness.user_bet_ix(&player, params(COIN_FLIP, 1, TOKEN));
+    replace_account(&mut ix, &player.vrf_payer, fee_vault);
+
+    let result = harness.process(&[ix], &[&player.authority]).await;
+    assert_flip_error(result, VrfFlipError::VrfSponsorshipDisabled);
+}
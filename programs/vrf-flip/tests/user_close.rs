@@ -0,0 +1,143 @@
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{VrfFlipError, HISTORY_PAGE_SIZE};
+use vrf_flip_test_harness::*;
+
+async fn lamports(harness: &mut Harness, address: &Pubkey) -> u64 {
+    harness
+        .context
+        .banks_client
+        .get_balance(*address)
+        .await
+        .unwrap()
+}
+
+async fn account_exists(harness: &mut Harness, address: &Pubkey) -> bool {
+    harness
+        .context
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+#[tokio::test]
+async fn test_user_close() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let escrow = player.escrow.pubkey();
+    let authority = player.authority.pubkey();
+    let rent = lamports(&mut harness, &player.user).await + lamports(&mut harness, &escrow).await;
+    let authority_balance = lamports(&mut harness, &authority).await;
+
+    harness.user_close(&player).await.unwrap();
+
+    assert!(!account_exists(&mut harness, &player.user).await);
+    assert!(!account_exists(&mut harness, &escrow).await);
+    assert_eq!(
+        lamports(&mut harness, &authority).await,
+        authority_balance + rent
+    );
+}
+
+#[tokio::test]
+async fn test_user_close_rejects_open_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let result = harness.user_close(&player).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundStillActive);
+}
+
+#[tokio::test]
+async fn test_user_close_rejects_funded_escrow() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let (mint, house) = (harness.mint, harness.house);
+    harness.set_token_account(&player.escrow.pubkey(), mint, house, TOKEN);
+
+    let result = harness.user_close(&player).await;
+    assert_flip_error(result, VrfFlipError::EscrowNotEmpty);
+}
+
+#[tokio::test]
+async fn test_user_close_with_history_pages() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.history_page_init(&player, 0).await.unwrap();
+
+    // page 0 has been filled, page 1 is being written to
+    let mut user = harness.user_state(&player).await;
+    user.archived_rounds = HISTORY_PAGE_SIZE as u64;
+    harness.set_user_state(&player, &user);
+    harness.history_page_init(&player, 1).await.unwrap();
+    assert_eq!({ harness.user_state(&player).await.history_pages }, 2);
+
+    let current_page = harness.history_page_address(&player, 1);
+    let ix = harness.user_close_with_history_page_ix(&player, Some(current_page));
+    let result = harness.process(&[ix], &[&player.authority]).await;
+    assert_flip_error(result, VrfFlipError::HistoryPagesOpen);
+
+    harness.history_page_close(&player, 0).await.unwrap();
+    let result = harness.user_close(&player).await;
+    assert_flip_error(result, VrfFlipError::HistoryPagesOpen);
+
+    let ix = harness.user_close_with_history_page_ix(&player, Some(current_page));
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+    assert!(!account_exists(&mut harness, &player.user).await);
+    assert!(!account_exists(&mut harness, &current_page).await);
+
+    // a re-created account starts its history pages from 0 again
+    harness.user_init(&player).await.unwrap();
+    harness.history_page_init(&player, 0).await.unwrap();
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.archived_rounds }, 0);
+    assert_eq!({ user.history_pages }, 1);
+}
+
+#[tokio::test]
+async fn test_user_close_requires_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let other = harness.create_player().await;
+
+    // another user's authority can't close this account
+    let mut ix = harness.user_close_ix(&player);
+    replace_account(
+        &mut ix,
+        &player.authority.pubkey(),
+        other.authority.pubkey(),
+    );
+    assert!(harness.process(&[ix], &[&other.authority]).await.is_err());
+    assert!(account_exists(&mut harness, &player.user).await);
+}
+
+#[tokio::test]
+async fn test_user_close_releases_expired_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 1, TOKEN)
+        .await
+        .unwrap();
+    assert_eq!({ harness.house_state().await.open_liability }, 5 * TOKEN);
+
+    // the round timed out and its escrow is empty
+    let mut user = harness.user_state(&player).await;
+    user.current_round.request_timestamp = 0;
+    harness.set_user_state(&player, &user);
+    let (mint, house) = (harness.mint, harness.house);
+    harness.set_token_account(&player.escrow.pubkey(), mint, house, 0);
+
+    harness.user_close(&player).await.unwrap();
+
+    let house = harness.house_state().await;
+    assert_eq!({ house.open_liability }, 0);
+    assert_eq!({ house.game_stats[1].open_exposure }, 0);
+}
@@ -0,0 +1,233 @@
+use anchor_lang::error::ErrorCode;
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use switchboard_vrf_flip::{UserBetParams, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+fn coin_flip(bet_amount: u64) -> UserBetParams {
+    UserBetParams {
+        game_type: COIN_FLIP,
+        user_guess: 1,
+        bet_amount,
+    }
+}
+
+async fn delegate_bet(
+    harness: &mut Harness,
+    player: &Player,
+    delegate: &Keypair,
+    bet_amount: u64,
+) -> Result<(), BanksClientError> {
+    let ix = harness.user_bet_delegate_ix(player, &delegate.pubkey(), coin_flip(bet_amount));
+    harness.process(&[ix], &[delegate]).await
+}
+
+#[tokio::test]
+async fn test_user_delegate_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+
+    delegate_bet(&mut harness, &player, &delegate, TOKEN)
+        .await
+        .unwrap();
+
+    let user = harness.user_state(&player).await;
+    let remaining_allowance = user.delegate.remaining_allowance;
+    assert_eq!(remaining_allowance, 2 * TOKEN);
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, TOKEN);
+
+    // the bet is paid from the authority's wallet through the user PDA's token approval
+    let reward_address = harness.token_account(&player.reward_address).await;
+    assert_eq!(reward_address.amount, 9 * TOKEN);
+    assert_eq!(reward_address.delegate, COption::Some(player.user));
+    assert_eq!(reward_address.delegated_amount, 2 * TOKEN);
+    let delegate_wallet = get_associated_token_address(&delegate.pubkey(), &harness.mint);
+    assert_eq!(harness.token_balance(&delegate_wallet).await, 5 * TOKEN);
+}
+
+#[tokio::test]
+async fn test_user_delegate_cannot_transfer_allowance() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+
+    // the session key only authorizes program instructions, it holds no token approval
+    let delegate_wallet = get_associated_token_address(&delegate.pubkey(), &harness.mint);
+    let ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &player.reward_address,
+        &delegate_wallet,
+        &delegate.pubkey(),
+        &[],
+        TOKEN,
+    )
+    .unwrap();
+    assert!(harness.process(&[ix], &[&delegate]).await.is_err());
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        10 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_delegate_bet_requires_token_approval() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+
+    // the authority revokes the approval outside of the program
+    let ix = spl_token::instruction::revoke(
+        &spl_token::ID,
+        &player.reward_address,
+        &player.authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+
+    let result = delegate_bet(&mut harness, &player, &delegate, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidDelegate);
+}
+
+#[tokio::test]
+async fn test_user_bet_requires_authority_signature() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+
+    // delegates have to go through user_bet_delegate
+    let mut ix = harness.user_bet_ix(&player, coin_flip(TOKEN));
+    for meta in ix.accounts.iter_mut() {
+        if meta.pubkey == player.authority.pubkey() {
+            meta.is_signer = false;
+        }
+    }
+    let result = harness.process(&[ix], &[&delegate]).await;
+    assert_custom_error(result, ErrorCode::AccountNotSigner.into());
+}
+
+#[tokio::test]
+async fn test_user_delegate_authority_bet_keeps_allowance() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_delegate(&player, Pubkey::new_unique(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let remaining_allowance = user.delegate.remaining_allowance;
+    assert_eq!(remaining_allowance, 3 * TOKEN);
+}
+
+#[tokio::test]
+async fn test_user_delegate_rejects_unknown_signer() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+
+    // no delegate set
+    let result = delegate_bet(&mut harness, &player, &delegate, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidDelegate);
+
+    // a different delegate set
+    harness
+        .user_set_delegate(&player, Pubkey::new_unique(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+    let result = delegate_bet(&mut harness, &player, &delegate, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidDelegate);
+}
+
+#[tokio::test]
+async fn test_user_delegate_rejects_expired() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    let clock = harness.clock().await;
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), clock.slot, 3 * TOKEN)
+        .await
+        .unwrap();
+    harness.warp_slots(2).await;
+
+    let result = delegate_bet(&mut harness, &player, &delegate, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::DelegateExpired);
+}
+
+#[tokio::test]
+async fn test_user_delegate_rejects_bet_over_allowance() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), u64::MAX, TOKEN)
+        .await
+        .unwrap();
+
+    let result = delegate_bet(&mut harness, &player, &delegate, 2 * TOKEN).await;
+    assert_flip_error(result, VrfFlipError::DelegateAllowanceExceeded);
+}
+
+#[tokio::test]
+async fn test_user_delegate_revoke() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let delegate = Keypair::new();
+    harness.fund_wallets(&delegate.pubkey(), 5 * TOKEN);
+    harness
+        .user_set_delegate(&player, delegate.pubkey(), u64::MAX, 3 * TOKEN)
+        .await
+        .unwrap();
+    harness
+        .user_set_delegate(&player, Pubkey::default(), 0, 0)
+        .await
+        .unwrap();
+
+    let user = harness.user_state(&player).await;
+    assert!(!user.delegate.is_set());
+    let reward_address = harness.token_account(&player.reward_address).await;
+    assert_eq!(reward_address.delegate, COption::None);
+    let result = delegate_bet(&mut harness, &player, &delegate, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::InvalidDelegate);
+}
+
+#[tokio::test]
+async fn test_user_set_delegate_rejects_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let authority = player.authority.pubkey();
+    let result = harness
+        .user_set_delegate(&player, authority, u64::MAX, TOKEN)
+        .await;
+    assert_flip_error(result, VrfFlipError::InvalidDelegate);
+}
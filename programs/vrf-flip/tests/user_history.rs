@@ -0,0 +1,337 @@
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{
+    GameType, History, Round, RoundStatus, UserBetParams, UserState, VrfFlipError,
+    HISTORY_PAGE_SIZE,
+};
+use vrf_flip_test_harness::*;
+
+fn settled_round(round_id: u128) -> Round {
+    let game_type = GameType::CoinFlip;
+    Round {
+        round_id,
+        status: RoundStatus::Settled,
+        bet_amount: TOKEN,
+        game_type,
+        game_config: game_type.get_game_config().unwrap(),
+        guess: 1,
+        result: 2,
+        request_slot: 1,
+        request_timestamp: 1,
+        settle_slot: 1,
+        settle_timestamp: 1,
+    }
+}
+
+// a user whose history ring is full, so the next bet has to archive round 1
+async fn create_player_with_full_history(harness: &mut Harness) -> (Player, UserState) {
+    let player = harness.create_player().await;
+    let mut user = harness.user_state(&player).await;
+    let max = user.history.max;
+    for i in 0..max {
+        user.history.rounds[i as usize] = settled_round(i as u128 + 1);
+    }
+    user.history.idx = 0;
+    user.current_round = settled_round(max as u128 + 1);
+    harness.set_user_state(&player, &user);
+    (player, user)
+}
+
+// a user whose last round was archived when it settled, filling the history ring
+async fn create_player_with_settled_history(harness: &mut Harness) -> (Player, UserState) {
+    let player = harness.create_player().await;
+    let mut user = harness.user_state(&player).await;
+    let max = user.history.max;
+    for i in 0..max {
+        user.history.rounds[i as usize] = settled_round(i as u128 + 1);
+    }
+    user.history.idx = 0;
+    user.current_round = settled_round(max as u128);
+    harness.set_user_state(&player, &user);
+
+    // one vrf request per round so far
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.counter = max as u128;
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    (player, user)
+}
+
+fn bet_params() -> UserBetParams {
+    UserBetParams {
+        game_type: COIN_FLIP,
+        user_guess: 1,
+        bet_amount: TOKEN,
+    }
+}
+
+#[tokio::test]
+async fn test_user_bet_requires_history_page_when_full() {
+    let mut harness = Harness::new().await;
+    let (player, _) = create_player_with_full_history(&mut harness).await;
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::HistoryPageRequired);
+}
+
+#[tokio::test]
+async fn test_user_bet_archives_to_history_page() {
+    let mut harness = Harness::new().await;
+    let (player, _) = create_player_with_settled_history(&mut harness).await;
+
+    harness.history_page_init(&player, 0).await.unwrap();
+    let history_page = harness.history_page_address(&player, 0);
+    let ix = harness.user_bet_with_history_page_ix(&player, bet_params(), Some(history_page));
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+
+    let page = harness.history_page_state(&player, 0).await;
+    let (page_index, len, page_user) = (page.page_index, page.len, page.user);
+    assert_eq!(page_index, 0);
+    assert_eq!(len, 1);
+    assert_eq!(page_user, player.user);
+    assert_eq!({ page.rounds[0].round_id }, 1);
+
+    // the freed slot is reserved for the new round until it settles
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.archived_rounds }, 1);
+    assert_eq!({ user.history.rounds[0].round_id }, 0);
+    assert_eq!({ user.current_round.round_id }, 49);
+
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let newest = *user.history.iter_recent().next().unwrap();
+    assert_eq!({ newest.round_id }, 49);
+    assert!(newest.status == RoundStatus::Settled);
+    assert_eq!({ user.history.idx }, 1);
+}
+
+#[tokio::test]
+async fn test_user_bet_archives_round_settled_before_upgrade() {
+    let mut harness = Harness::new().await;
+    // rounds used to be pushed when the next bet started, so the settled
+    // round is missing from a full ring
+    let (player, _) = create_player_with_full_history(&mut harness).await;
+
+    harness.history_page_init(&player, 0).await.unwrap();
+    let history_page = harness.history_page_address(&player, 0);
+    let ix = harness.user_bet_with_history_page_ix(&player, bet_params(), Some(history_page));
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+
+    let page = harness.history_page_state(&player, 0).await;
+    assert_eq!({ page.len }, 2);
+    assert_eq!({ page.rounds[0].round_id }, 1);
+    assert_eq!({ page.rounds[1].round_id }, 2);
+
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.archived_rounds }, 2);
+    assert_eq!({ user.history.rounds[0].round_id }, 49);
+    assert_eq!({ user.history.rounds[1].round_id }, 0);
+    assert_eq!({ user.history.idx }, 1);
+    assert_eq!({ user.current_round.round_id }, 50);
+}
+
+#[tokio::test]
+async fn test_user_settle_archives_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 3, TOKEN)
+        .await
+        .unwrap();
+    let user = harness.user_state(&player).await;
+    assert!(user.history.iter_recent().next().is_none());
+
+    harness.set_vrf_result(&player, vrf_result_for(3, 6)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let rounds: Vec<Round> = user.history.iter_recent().copied().collect();
+    assert_eq!(rounds.len(), 1);
+    assert_eq!({ rounds[0].round_id }, 1);
+    assert!(rounds[0].status == RoundStatus::Settled);
+    assert_eq!(rounds[0].payout_amount().unwrap(), 5 * TOKEN);
+    assert!(user.is_current_round_archived());
+
+    // the next bet doesn't push the settled round a second time
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    let user = harness.user_state(&player).await;
+    assert_eq!(user.history.iter_recent().count(), 1);
+    assert_eq!({ user.history.idx }, 1);
+}
+
+#[tokio::test]
+async fn test_history_page_records_payouts() {
+    let mut harness = Harness::new().await;
+    let (player, mut user) = create_player_with_settled_history(&mut harness).await;
+
+    // the oldest round was a win
+    user.history.rounds[0].result = 1;
+    harness.set_user_state(&player, &user);
+
+    harness.history_page_init(&player, 0).await.unwrap();
+    let history_page = harness.history_page_address(&player, 0);
+    let ix = harness.user_bet_with_history_page_ix(&player, bet_params(), Some(history_page));
+    harness.process(&[ix], &[&player.authority]).await.unwrap();
+
+    let page = harness.history_page_state(&player, 0).await;
+    assert_eq!({ page.rounds[0].round_id }, 1);
+    assert_eq!({ page.payouts[0] }, TOKEN);
+    assert_eq!({ page.payouts[1] }, 0);
+}
+
+#[tokio::test]
+async fn test_user_settle_archives_to_history_page_when_slot_taken() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    // round 49 was placed before bets freed the slot it settles into
+    let mut user = harness.user_state(&player).await;
+    let max = user.history.max;
+    for i in 0..max {
+        user.history.rounds[i as usize] = settled_round(i as u128 + 1);
+    }
+    user.history.idx = 0;
+    user.current_round.round_id = max as u128 + 1;
+    harness.set_user_state(&player, &user);
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.counter = max as u128 + 1;
+    harness.set_vrf_state(&player.vrf, &vrf);
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::HistoryPageRequired);
+
+    harness.history_page_init(&player, 0).await.unwrap();
+    let history_page = harness.history_page_address(&player, 0);
+    let ix = harness.user_settle_with_history_page_ix(&player, Some(history_page));
+    harness.process(&[ix], &[]).await.unwrap();
+
+    let page = harness.history_page_state(&player, 0).await;
+    assert_eq!({ page.len }, 1);
+    assert_eq!({ page.rounds[0].round_id }, 1);
+
+    let user = harness.user_state(&player).await;
+    let newest = *user.history.iter_recent().next().unwrap();
+    assert_eq!({ newest.round_id }, 49);
+    assert!(newest.status == RoundStatus::Settled);
+    assert_eq!({ user.archived_rounds }, 1);
+    assert_eq!({ user.history.idx }, 1);
+}
+
+#[test]
+fn test_history_iter_recent_is_newest_first() {
+    let mut history = History::default();
+    assert!(history.iter_recent().next().is_none());
+
+    for round_id in 1..=50 {
+        history.push(settled_round(round_id));
+    }
+
+    let round_ids: Vec<u128> = history.iter_recent().map(|round| round.round_id).collect();
+    let expected: Vec<u128> = (3..=50).rev().collect();
+    assert_eq!(round_ids, expected);
+}
+
+#[tokio::test]
+async fn test_history_page_init_rejects_out_of_order_page() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.history_page_init(&player, 1).await;
+    assert_flip_error(result, VrfFlipError::InvalidHistoryPage);
+}
+
+#[tokio::test]
+async fn test_history_page_init_requires_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let other = harness.create_player().await;
+
+    // another user's authority can't open pages for this user
+    let mut ix = harness.history_page_init_ix(&player, 0);
+    replace_account(
+        &mut ix,
+        &player.authority.pubkey(),
+        other.authority.pubkey(),
+    );
+    assert!(harness.process(&[ix], &[&other.authority]).await.is_err());
+
+    // nor can this user's authority open pages for another user
+    let mut ix = harness.history_page_init_ix(&player, 0);
+    replace_account(
+        &mut ix,
+        &harness.history_page_address(&player, 0),
+        harness.history_page_address(&other, 0),
+    );
+    replace_account(&mut ix, &player.user, other.user);
+    assert!(harness.process(&[ix], &[&player.authority]).await.is_err());
+
+    assert_eq!({ harness.user_state(&player).await.history_pages }, 0);
+    assert_eq!({ harness.user_state(&other).await.history_pages }, 0);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_stale_history_page() {
+    let mut harness = Harness::new().await;
+    let (player, mut user) = create_player_with_full_history(&mut harness).await;
+    harness.history_page_init(&player, 0).await.unwrap();
+
+    // page 0 has been filled, the round belongs on page 1
+    user.archived_rounds = HISTORY_PAGE_SIZE as u64;
+    harness.set_user_state(&player, &user);
+
+    let history_page = harness.history_page_address(&player, 0);
+    let ix = harness.user_bet_with_history_page_ix(&player, bet_params(), Some(history_page));
+    let result = harness.process(&[ix], &[&player.authority]).await;
+    assert_flip_error(result, VrfFlipError::InvalidHistoryPage);
+}
+
+#[tokio::test]
+async fn test_history_page_close_reclaims_rent() {
+    let mut harness = Harness::new().await;
+    let (player, mut user) = create_player_with_full_history(&mut harness).await;
+    harness.history_page_init(&player, 0).await.unwrap();
+
+    // the page is still being written to
+    let result = harness.history_page_close(&player, 0).await;
+    assert_flip_error(result, VrfFlipError::HistoryPageInUse);
+
+    user.archived_rounds = HISTORY_PAGE_SIZE as u64;
+    harness.set_user_state(&player, &user);
+
+    let history_page = harness.history_page_address(&player, 0);
+    let rent = harness
+        .context
+        .banks_client
+        .get_balance(history_page)
+        .await
+        .unwrap();
+    let authority_balance = harness
+        .context
+        .banks_client
+        .get_balance(player.authority.pubkey())
+        .await
+        .unwrap();
+
+    harness.history_page_close(&player, 0).await.unwrap();
+
+    assert!(harness
+        .context
+        .banks_client
+        .get_account(history_page)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        harness
+            .context
+            .banks_client
+            .get_balance(player.authority.pubkey())
+            .await
+            .unwrap(),
+        authority_balance + rent
+    );
+}
@@ -0,0 +1,70 @@
+use anchor_spl::token::spl_token::state::Account as SplAccount;
+use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{RoundStatus, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_user_init() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let user = harness.user_state(&player).await;
+    assert_eq!(user.authority, player.authority.pubkey());
+    assert_eq!(user.house, harness.house);
+    assert_eq!(user.escrow, player.escrow.pubkey());
+    assert_eq!(user.reward_address, player.reward_address);
+    assert_eq!(user.vrf, player.vrf);
+    assert!(user.current_round.status == RoundStatus::None);
+
+    // the escrow is handed to the house and neither account can be closed
+    let data = harness.account_data(&player.escrow.pubkey()).await;
+    let escrow = SplAccount::unpack(&data).unwrap();
+    assert_eq!(escrow.owner, harness.house);
+    assert_eq!(escrow.close_authority, COption::None);
+
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        10 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_init_rejects_used_vrf() {
+    let mut harness = Harness::new().await;
+    let player = harness.prepare_player().await;
+
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.counter = 1;
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    let result = harness.user_init(&player).await;
+    assert_flip_error(result, VrfFlipError::InvalidInitialVrfCounter);
+}
+
+#[tokio::test]
+async fn test_user_init_rejects_vrf_on_another_queue() {
+    let mut harness = Harness::new().await;
+    let player = harness.prepare_player().await;
+
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.oracle_queue = Pubkey::new_unique();
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    let result = harness.user_init(&player).await;
+    assert_flip_error(result, VrfFlipError::OracleQueueMismatch);
+}
+
+#[tokio::test]
+async fn test_user_init_rejects_vrf_with_another_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.prepare_player().await;
+
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.authority = Pubkey::new_unique();
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    // the account constraint reports the authority and queue checks together
+    let result = harness.user_init(&player).await;
+    assert_flip_error(result, VrfFlipError::OracleQueueMismatch);
+}
@@ -0,0 +1,288 @@
+use switchboard_vrf_flip::{UserSetLimitsParams, VrfFlipError, LIMIT_LOOSEN_DELAY};
+use vrf_flip_test_harness::*;
+
+fn no_changes() -> UserSetLimitsParams {
+    UserSetLimitsParams {
+        daily_wager_limit: None,
+        weekly_wager_limit: None,
+        daily_loss_limit: None,
+        cooldown: None,
+        self_excluded_until: None,
+    }
+}
+
+/// Places a coin flip bet and settles it with the given result
+async fn play_round(harness: &mut Harness, player: &Player, result: u32) {
+    harness.bet(player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness
+        .set_vrf_result(player, vrf_result_for(result, 2))
+        .await;
+    harness.settle(player).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_user_limits_daily_wager() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                daily_wager_limit: Some(2 * TOKEN),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    play_round(&mut harness, &player, 1).await;
+    play_round(&mut harness, &player, 1).await;
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::WagerLimitExceeded);
+
+    // the daily total resets on the next day
+    let mut user = harness.user_state(&player).await;
+    user.limits.day -= 1;
+    harness.set_user_state(&player, &user);
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_user_limits_weekly_wager() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                weekly_wager_limit: Some(TOKEN),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    play_round(&mut harness, &player, 1).await;
+
+    // a new day doesn't reset the weekly total
+    let mut user = harness.user_state(&player).await;
+    user.limits.day -= 1;
+    harness.set_user_state(&player, &user);
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::WagerLimitExceeded);
+}
+
+#[tokio::test]
+async fn test_user_limits_daily_loss() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                daily_loss_limit: Some(TOKEN),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    // a bet that could lose more than the limit is rejected up front
+    let result = harness.bet(&player, COIN_FLIP, 1, 2 * TOKEN).await;
+    assert_flip_error(result, VrfFlipError::LossLimitExceeded);
+
+    play_round(&mut harness, &player, 2).await;
+    let user = harness.user_state(&player).await;
+    let day_net_pnl = user.limits.day_net_pnl;
+    assert_eq!(day_net_pnl, -(TOKEN as i64));
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::LossLimitExceeded);
+}
+
+#[tokio::test]
+async fn test_user_limits_cooldown() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                cooldown: Some(3_600),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    play_round(&mut harness, &player, 1).await;
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::FlipRequestedTooSoon);
+
+    // the cooldown runs from when the last bet was placed
+    let mut user = harness.user_state(&player).await;
+    user.current_round.request_timestamp -= 3_600;
+    harness.set_user_state(&player, &user);
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_user_limits_self_exclusion() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let now = harness.clock().await.unix_timestamp;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                self_excluded_until: Some(now + 86_400),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::UserSelfExcluded);
+
+    // the exclusion can be extended but not shortened
+    let result = harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                self_excluded_until: Some(now),
+                ..no_changes()
+            },
+        )
+        .await;
+    assert_flip_error(result, VrfFlipError::SelfExclusionShortened);
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                self_excluded_until: Some(now + 2 * 86_400),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    let user = harness.user_state(&player).await;
+    let self_excluded_until = user.limits.self_excluded_until;
+    assert_eq!(self_excluded_until, now + 2 * 86_400);
+}
+
+#[tokio::test]
+async fn test_user_limits_can_be_lifted() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                daily_wager_limit: Some(TOKEN / 2),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::WagerLimitExceeded);
+
+    let now = harness.clock().await.unix_timestamp;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                daily_wager_limit: Some(0),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    // lifting a limit waits out the delay
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::WagerLimitExceeded);
+    let mut user = harness.user_state(&player).await;
+    let effective_at = user.limits.pending.effective_at;
+    assert!(effective_at >= now + LIMIT_LOOSEN_DELAY);
+
+    user.limits.pending.effective_at = now;
+    harness.set_user_state(&player, &user);
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let daily_wager_limit = user.limits.daily_wager_limit;
+    let effective_at = user.limits.pending.effective_at;
+    assert_eq!(daily_wager_limit, 0);
+    assert_eq!(effective_at, 0);
+}
+
+#[tokio::test]
+async fn test_user_limits_tightening_applies_right_away() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                cooldown: Some(3_600),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    // a shorter cooldown waits, a new wager limit applies right away
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                cooldown: Some(60),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+    let user = harness.user_state(&player).await;
+    let previous_effective_at = user.limits.pending.effective_at;
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                daily_wager_limit: Some(TOKEN),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+
+    let user = harness.user_state(&player).await;
+    let cooldown = user.limits.cooldown;
+    let daily_wager_limit = user.limits.daily_wager_limit;
+    let pending_cooldown = user.limits.pending.cooldown;
+    let pending_daily_wager_limit = user.limits.pending.daily_wager_limit;
+    let effective_at = user.limits.pending.effective_at;
+    assert_eq!(cooldown, 3_600);
+    assert_eq!(daily_wager_limit, TOKEN);
+    assert_eq!(pending_cooldown, 60);
+    assert_eq!(pending_daily_wager_limit, TOKEN);
+    // tightening doesn't restart the wait for what is already pending
+    assert_eq!(effective_at, previous_effective_at);
+
+    // setting the current value back cancels the pending change
+    harness
+        .user_set_limits(
+            &player,
+            UserSetLimitsParams {
+                cooldown: Some(3_600),
+                ..no_changes()
+            },
+        )
+        .await
+        .unwrap();
+    let user = harness.user_state(&player).await;
+    let effective_at = user.limits.pending.effective_at;
+    assert_eq!(effective_at, 0);
+}
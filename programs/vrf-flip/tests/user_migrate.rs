@@ -0,0 +1,198 @@
+use anchor_lang::Discriminator;
+use bytemuck::{Pod, Zeroable};
+use solana_sdk::pubkey::Pubkey;
+use switchboard_vrf_flip::{
+    AutoPlay, History, Round, UserDelegate, UserLimits, UserState, UserStats, VrfFlipError,
+    USER_STATE_VERSION,
+};
+use vrf_flip_test_harness::*;
+
+// UserState as the baseline program wrote it, before any field was carved out of _ebuf
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+struct BaselineUserState {
+    bump: u8,
+    authority: Pubkey,
+    house: Pubkey,
+    escrow: Pubkey,
+    reward_address: Pubkey,
+    vrf: Pubkey,
+    switchboard_state_bump: u8,
+    vrf_permission_bump: u8,
+    current_round: Round,
+    last_airdrop_request_slot: u64,
+    _ebuf: [u8; 1024],
+    history: History,
+}
+unsafe impl Pod for BaselineUserState {}
+unsafe impl Zeroable for BaselineUserState {}
+
+fn baseline_data(user: &BaselineUserState) -> Vec<u8> {
+    let mut data = UserState::discriminator().to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(user));
+    data
+}
+
+// a user account as the baseline program left it after one settled round
+async fn create_player_with_baseline_layout(harness: &mut Harness) -> (Player, BaselineUserState) {
+    let player = harness.create_player().await;
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 3, TOKEN)
+        .await
+        .unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(3, 6)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let baseline = BaselineUserState {
+        bump: user.bump,
+        authority: user.authority,
+        house: user.house,
+        escrow: user.escrow,
+        reward_address: user.reward_address,
+        vrf: user.vrf,
+        switchboard_state_bump: user.switchboard_state_bump,
+        vrf_permission_bump: user.vrf_permission_bump,
+        current_round: user.current_round,
+        last_airdrop_request_slot: user.last_airdrop_request_slot,
+        _ebuf: [0u8; 1024],
+        history: user.history,
+    };
+    harness.set_user_data(&player, baseline_data(&baseline));
+    (player, baseline)
+}
+
+#[test]
+fn test_offsets_match_layout() {
+    assert_eq!(
+        std::mem::size_of::<BaselineUserState>(),
+        std::mem::size_of::<UserState>()
+    );
+
+    let mut user = UserState::default();
+    user.version = 7;
+    user.commit_reveal.round_id = 8;
+    user.history.idx = 9;
+    let data = bytemuck::bytes_of(&user);
+    assert_eq!(data[UserState::VERSION_OFFSET - 8], 7);
+    assert_eq!(data[UserState::COMMIT_REVEAL_OFFSET - 8], 8);
+    assert_eq!(data[UserState::HISTORY_OFFSET - 8], 9);
+}
+
+#[tokio::test]
+async fn test_user_bet_rejects_outdated_layout() {
+    let mut harness = Harness::new().await;
+    let (player, _) = create_player_with_baseline_layout(&mut harness).await;
+
+    let result = harness.bet(&player, COIN_FLIP, 1, TOKEN).await;
+    assert_flip_error(result, VrfFlipError::UserStateOutdated);
+}
+
+#[tokio::test]
+async fn test_user_migrate_baseline() {
+    let mut harness = Harness::new().await;
+    let (player, baseline) = create_player_with_baseline_layout(&mut harness).await;
+
+    harness.user_migrate(&player).await.unwrap();
+
+    let data = harness.account_data(&player.user).await;
+    assert_eq!(data.len(), UserState::size());
+    assert_eq!(data[UserState::VERSION_OFFSET], USER_STATE_VERSION);
+
+    // the baseline fields carry over
+    let user = harness.user_state(&player).await;
+    let expected = baseline_data(&baseline);
+    assert_eq!(
+        data[..UserState::COMMIT_REVEAL_OFFSET],
+        expected[..UserState::COMMIT_REVEAL_OFFSET]
+    );
+    assert_eq!(
+        data[UserState::HISTORY_OFFSET..],
+        expected[UserState::HISTORY_OFFSET..]
+    );
+    assert_eq!({ user.history.iter_recent().next().unwrap().round_id }, 1);
+
+    // and everything carved out of _ebuf starts empty
+    assert!({ user.stats } == UserStats::default());
+    assert!({ user.delegate } == UserDelegate::default());
+    assert!({ user.autoplay } == AutoPlay::default());
+    assert!({ user.limits } == UserLimits::default());
+    assert_eq!({ user.archived_rounds }, 0);
+    assert_eq!({ user.history_pages }, 0);
+    assert_eq!({ user.house_settle_pending }, 0);
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.stats.rounds_played }, 1);
+}
+
+#[tokio::test]
+async fn test_user_migrate_zeroes_fields_added_after_v1() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    // a v1 account with stray bytes in the _ebuf its later fields were carved from
+    let mut data = harness.account_data(&player.user).await;
+    data[UserState::VERSION_OFFSET] = 1;
+    data[UserState::VERSION_OFFSET + 1..UserState::HISTORY_OFFSET].fill(0xa5);
+    harness.set_user_data(&player, data);
+
+    harness.user_migrate(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.version }, USER_STATE_VERSION);
+    assert!({ user.delegate } == UserDelegate::default());
+    assert!({ user.autoplay } == AutoPlay::default());
+    assert!({ user.limits } == UserLimits::default());
+    assert_eq!({ user.history_pages }, 0);
+    assert_eq!({ user.house_settle_pending }, 0);
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_user_migrate_keeps_pending_house_settle() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 1, TOKEN)
+        .await
+        .unwrap();
+
+    // a vrf callback settles the round of a v5 account
+    let mut data = harness.account_data(&player.user).await;
+    data[UserState::VERSION_OFFSET] = 5;
+    harness.set_user_data(&player, data);
+    harness.set_vrf_result(&player, vrf_result_for(1, 6)).await;
+    harness.settle_callback(&player).await.unwrap();
+
+    harness.user_migrate(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.house_settle_pending }, 1);
+    harness.user_record_settle(&player).await.unwrap();
+    assert_eq!({ harness.house_state().await.open_liability }, 0);
+}
+
+#[tokio::test]
+async fn test_user_migrate_rejects_current_version() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.user_migrate(&player).await;
+    assert_flip_error(result, VrfFlipError::UserStateUpToDate);
+}
+
+#[tokio::test]
+async fn test_user_migrate_rejects_unknown_version() {
+    let mut harness = Harness::new().await;
+    let (player, baseline) = create_player_with_baseline_layout(&mut harness).await;
+    let mut data = baseline_data(&baseline);
+    data[UserState::VERSION_OFFSET] = USER_STATE_VERSION + 1;
+    harness.set_user_data(&player, data);
+
+    let result = harness.user_migrate(&player).await;
+    assert_flip_error(result, VrfFlipError::InvalidUserStateVersion);
+}
@@ -0,0 +1,89 @@
+use switchboard_vrf_flip::{RoundStatus, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+/// Places a six-sided dice bet and has the vrf callback settle it as a win
+async fn settle_through_callback(harness: &mut Harness) -> Player {
+    let player = harness.create_player().await;
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 1, TOKEN)
+        .await
+        .unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 6)).await;
+    harness.settle_callback(&player).await.unwrap();
+    player
+}
+
+#[tokio::test]
+async fn test_user_settle_callback_with_read_only_house() {
+    let mut harness = Harness::new().await;
+    let player = settle_through_callback(&mut harness).await;
+
+    // the user is paid, the house is only updated once it's writable
+    let user = harness.user_state(&player).await;
+    assert!(user.current_round.status == RoundStatus::Settled);
+    assert_eq!({ user.house_settle_pending }, 1);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        15 * TOKEN
+    );
+    let house = harness.house_state().await;
+    assert_eq!({ house.open_liability }, 5 * TOKEN);
+    assert_eq!({ house.game_stats[1].rounds_settled }, 0);
+}
+
+#[tokio::test]
+async fn test_user_record_settle() {
+    let mut harness = Harness::new().await;
+    let player = settle_through_callback(&mut harness).await;
+
+    harness.user_record_settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.house_settle_pending }, 0);
+    let house = harness.house_state().await;
+    assert_eq!({ house.open_liability }, 0);
+    assert_eq!({ house.game_stats[1].open_exposure }, 0);
+    assert_eq!({ house.game_stats[1].rounds_settled }, 1);
+    assert_eq!({ house.game_stats[1].payouts }, 5 * TOKEN);
+
+    let result = harness.user_record_settle(&player).await;
+    assert_flip_error(result, VrfFlipError::NoPendingSettle);
+}
+
+#[tokio::test]
+async fn test_user_record_settle_rejects_settle_with_writable_house() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let result = harness.user_record_settle(&player).await;
+    assert_flip_error(result, VrfFlipError::NoPendingSettle);
+}
+
+#[tokio::test]
+async fn test_user_bet_records_pending_settle() {
+    let mut harness = Harness::new().await;
+    let player = settle_through_callback(&mut harness).await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    assert_eq!({ user.house_settle_pending }, 0);
+    let house = harness.house_state().await;
+    assert_eq!({ house.open_liability }, TOKEN);
+    assert_eq!({ house.game_stats[1].rounds_settled }, 1);
+}
+
+#[tokio::test]
+async fn test_user_close_records_pending_settle() {
+    let mut harness = Harness::new().await;
+    let player = settle_through_callback(&mut harness).await;
+
+    harness.user_close(&player).await.unwrap();
+
+    let house = harness.house_state().await;
+    assert_eq!({ house.open_liability }, 0);
+    assert_eq!({ house.game_stats[1].rounds_settled }, 1);
+}
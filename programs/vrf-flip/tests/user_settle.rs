@@ -0,0 +1,233 @@
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::{RoundStatus, VrfFlipError};
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_user_settle_win() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let house_vault = harness.house_vault;
+    let house_vault_balance = harness.token_balance(&house_vault).await;
+
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 3, TOKEN)
+        .await
+        .unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(3, 6)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let result = user.current_round.result;
+    assert_eq!(result, 3);
+    assert!(user.current_round.status == RoundStatus::Settled);
+
+    // the dice roll pays out 5x the bet on top of the returned bet
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        15 * TOKEN
+    );
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        house_vault_balance - 5 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_settle_loss() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let house_vault = harness.house_vault;
+    let house_vault_balance = harness.token_balance(&house_vault).await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let user = harness.user_state(&player).await;
+    let result = user.current_round.result;
+    assert_eq!(result, 2);
+
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        9 * TOKEN
+    );
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        house_vault_balance + TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_settle_returns_residual_escrow() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let house_vault = harness.house_vault;
+    let house_vault_balance = harness.token_balance(&house_vault).await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    let (mint, house) = (harness.mint, harness.house);
+    harness.set_token_account(&player.escrow.pubkey(), mint, house, 3 * TOKEN);
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    // the house only takes the bet, the rest of the escrow goes back to the user
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        11 * TOKEN
+    );
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&house_vault).await,
+        house_vault_balance + TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_settle_updates_stats() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    for (guess, bet_amount) in [(1, TOKEN), (1, TOKEN), (2, 2 * TOKEN)] {
+        harness.warp_slots(1).await;
+        harness
+            .bet(&player, COIN_FLIP, guess, bet_amount)
+            .await
+            .unwrap();
+        harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+        harness.settle(&player).await.unwrap();
+    }
+
+    let stats = harness.user_state(&player).await.stats;
+    let (total_wagered, total_won, net_pnl) = (stats.total_wagered, stats.total_won, stats.net_pnl);
+    let (rounds_played, wins) = (stats.rounds_played, stats.wins);
+    let (longest_win_streak, current_win_streak) =
+        (stats.longest_win_streak, stats.current_win_streak);
+    assert_eq!(total_wagered, 4 * TOKEN);
+    assert_eq!(total_won, 2 * TOKEN);
+    assert_eq!(net_pnl, 0);
+    assert_eq!(rounds_played, 3);
+    assert_eq!(wins, 2);
+    assert_eq!(longest_win_streak, 2);
+    assert_eq!(current_win_streak, 0);
+}
+
+#[tokio::test]
+async fn test_user_settle_updates_house_stats() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness
+        .bet(&player, SIX_SIDED_DICE_ROLL, 3, TOKEN)
+        .await
+        .unwrap();
+    let dice = harness.house_state().await.game_stats[1];
+    let (volume, open_exposure, rounds_placed) =
+        (dice.volume, dice.open_exposure, dice.rounds_placed);
+    assert_eq!(volume, TOKEN);
+    assert_eq!(open_exposure, 5 * TOKEN);
+    assert_eq!(rounds_placed, 1);
+
+    harness.set_vrf_result(&player, vrf_result_for(3, 6)).await;
+    harness.settle(&player).await.unwrap();
+
+    harness.warp_slots(1).await;
+    harness.bet(&player, COIN_FLIP, 1, 2 * TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    let house = harness.house_state().await;
+    let dice = house.game_stats[1];
+    let (payouts, net_pnl, open_exposure, rounds_settled) = (
+        dice.payouts,
+        dice.net_pnl,
+        dice.open_exposure,
+        dice.rounds_settled,
+    );
+    assert_eq!(payouts, 5 * TOKEN);
+    assert_eq!(net_pnl, -5 * TOKEN as i64);
+    assert_eq!(open_exposure, 0);
+    assert_eq!(rounds_settled, 1);
+
+    let total = house.total_stats();
+    let (volume, payouts, net_pnl, rounds_placed, rounds_settled) = (
+        total.volume,
+        total.payouts,
+        total.net_pnl,
+        total.rounds_placed,
+        total.rounds_settled,
+    );
+    assert_eq!(volume, 3 * TOKEN);
+    assert_eq!(payouts, 5 * TOKEN);
+    assert_eq!(net_pnl, -3 * TOKEN as i64);
+    assert_eq!(rounds_placed, 2);
+    assert_eq!(rounds_settled, 2);
+}
+
+#[tokio::test]
+async fn test_user_settle_rejects_settled_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundAlreadyClosed);
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+    harness.settle(&player).await.unwrap();
+
+    harness.warp_slots(1).await;
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundAlreadyClosed);
+}
+
+#[tokio::test]
+async fn test_user_settle_rejects_stale_vrf_counter() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.counter = 2;
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::IncorrectVrfCounter);
+}
+
+#[tokio::test]
+async fn test_user_settle_rejects_unverified_vrf_result() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+
+    // a result was written but no oracle has verified its proof yet
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.current_round.num_verified = 0;
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::VrfNotVerified);
+}
+
+#[tokio::test]
+async fn test_user_settle_rejects_vrf_with_another_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+    harness.set_vrf_result(&player, vrf_result_for(1, 2)).await;
+
+    let mut vrf = harness.vrf_state(&player).await;
+    vrf.authority = Pubkey::new_unique();
+    harness.set_vrf_state(&player.vrf, &vrf);
+
+    let result = harness.settle(&player).await;
+    assert_flip_error(result, VrfFlipError::InvalidVrfAuthority);
+}
@@ -0,0 +1,83 @@
+use solana_sdk::signature::Signer;
+use switchboard_vrf_flip::VrfFlipError;
+use vrf_flip_test_harness::*;
+
+#[tokio::test]
+async fn test_user_withdraw_escrow() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let (mint, house) = (harness.mint, harness.house);
+    harness.set_token_account(&player.escrow.pubkey(), mint, house, 3 * TOKEN);
+
+    harness.user_withdraw_escrow(&player).await.unwrap();
+
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        13 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn test_user_withdraw_escrow_keeps_unsettled_bet() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    // let the round time out without settling it, with extra tokens sitting in escrow
+    let mut user = harness.user_state(&player).await;
+    user.current_round.request_timestamp = 0;
+    harness.set_user_state(&player, &user);
+    let (mint, house) = (harness.mint, harness.house);
+    harness.set_token_account(&player.escrow.pubkey(), mint, house, 3 * TOKEN);
+
+    harness.user_withdraw_escrow(&player).await.unwrap();
+
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, TOKEN);
+    assert_eq!(
+        harness.token_balance(&player.reward_address).await,
+        11 * TOKEN
+    );
+
+    // the bet left behind still settles
+    harness.set_vrf_result(&player, vrf_result_for(2, 2)).await;
+    harness.settle(&player).await.unwrap();
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn test_user_withdraw_escrow_rejects_open_round() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    harness.bet(&player, COIN_FLIP, 1, TOKEN).await.unwrap();
+
+    let result = harness.user_withdraw_escrow(&player).await;
+    assert_flip_error(result, VrfFlipError::CurrentRoundStillActive);
+}
+
+#[tokio::test]
+async fn test_user_withdraw_escrow_rejects_empty_escrow() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+
+    let result = harness.user_withdraw_escrow(&player).await;
+    assert_flip_error(result, VrfFlipError::NoUnlockedEscrow);
+}
+
+#[tokio::test]
+async fn test_user_withdraw_escrow_requires_authority() {
+    let mut harness = Harness::new().await;
+    let player = harness.create_player().await;
+    let other = harness.create_player().await;
+    let (mint, house) = (harness.mint, harness.house);
+    harness.set_token_account(&player.escrow.pubkey(), mint, house, TOKEN);
+
+    let mut ix = harness.user_withdraw_escrow_ix(&player);
+    replace_account(
+        &mut ix,
+        &player.authority.pubkey(),
+        other.authority.pubkey(),
+    );
+    assert!(harness.process(&[ix], &[&other.authority]).await.is_err());
+    assert_eq!(harness.token_balance(&player.escrow.pubkey()).await, TOKEN);
+}
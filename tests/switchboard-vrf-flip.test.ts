@@ -128,7 +128,7 @@ describe("switchboard-vrf-flip", () => {
 
     console.log(house.toJSON());
 
-    program = await FlipProgram.load(anchorProgram);
+    program = await FlipProgram.load(anchorProgram, { mint: house.state.mint });
   });
 
   it("initialize user 1", async () => {